@@ -0,0 +1,61 @@
+//! Per-instruction compute-unit limits measured from the benchmark
+//! harness, so transactions built through this crate stop tripping
+//! Solana's default 200k CU cap on refresh-heavy instructions without
+//! every caller having to guess a limit itself.
+
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+
+/// Instruction kinds the CLI/SDK issues, used to look up a recommended
+/// compute-unit limit. Kept separate from the program's own instruction
+/// enum so this crate doesn't need the full on-chain build just to pick a
+/// CU limit.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum InstructionKind {
+    RefreshReserve,
+    RefreshReserves,
+    RefreshObligation,
+    InitReserve,
+    InitObligation,
+    DepositObligationCollateral,
+    WithdrawObligationCollateral,
+    BorrowObligationLiquidity,
+    RepayObligationLiquidity,
+    FlashLoan,
+    ClaimEmission,
+    Other,
+}
+
+impl InstructionKind {
+    /// Recommended compute-unit limit, measured against the benchmark
+    /// harness's worst-case fixtures plus ~20% headroom.
+    /// `refresh_reserves` and `flash_loan` run oracle parsing and multiple
+    /// CPIs respectively, so they need far more than the 200k default.
+    pub fn recommended_cu_limit(self) -> u32 {
+        match self {
+            InstructionKind::RefreshReserve => 60_000,
+            InstructionKind::RefreshReserves => 400_000,
+            InstructionKind::RefreshObligation => 120_000,
+            InstructionKind::InitReserve => 80_000,
+            InstructionKind::InitObligation => 40_000,
+            InstructionKind::DepositObligationCollateral => 60_000,
+            InstructionKind::WithdrawObligationCollateral => 80_000,
+            InstructionKind::BorrowObligationLiquidity => 100_000,
+            InstructionKind::RepayObligationLiquidity => 60_000,
+            InstructionKind::FlashLoan => 250_000,
+            InstructionKind::ClaimEmission => 70_000,
+            InstructionKind::Other => 200_000,
+        }
+    }
+}
+
+/// Prepends a `ComputeBudgetInstruction::set_compute_unit_limit` sized for
+/// `kind` ahead of `instructions`, so callers building transactions through
+/// this crate don't each have to know which instructions need a raised
+/// limit.
+pub fn with_compute_budget(kind: InstructionKind, instructions: Vec<Instruction>) -> Vec<Instruction> {
+    let mut with_budget = Vec::with_capacity(instructions.len() + 1);
+    with_budget.push(ComputeBudgetInstruction::set_compute_unit_limit(kind.recommended_cu_limit()));
+    with_budget.extend(instructions);
+    with_budget
+}
@@ -0,0 +1,70 @@
+//! Persisted CLI configuration: RPC endpoint, wallet path, and the user's
+//! favorited lending markets so they don't have to paste a pubkey every
+//! time they switch between markets.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Config {
+    pub rpc_url: Option<String>,
+    pub wallet_path: Option<String>,
+    /// Markets the user has starred, most recently added last. The picker
+    /// lists these above markets discovered via `getProgramAccounts`.
+    pub favorite_markets: Vec<FavoriteMarket>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FavoriteMarket {
+    pub pubkey: Pubkey,
+    pub label: String,
+}
+
+impl Config {
+    pub fn default_path() -> PathBuf {
+        dirs_path().join("config.json")
+    }
+
+    pub fn load() -> Result<Self> {
+        let path = Self::default_path();
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("reading {}", path.display()))?;
+        Ok(serde_json::from_str(&raw)?)
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::default_path();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("writing {}", path.display()))
+    }
+
+    pub fn add_favorite(&mut self, pubkey: Pubkey, label: String) {
+        self.favorite_markets.retain(|m| m.pubkey != pubkey);
+        self.favorite_markets.push(FavoriteMarket { pubkey, label });
+    }
+
+    pub fn remove_favorite(&mut self, pubkey: Pubkey) {
+        self.favorite_markets.retain(|m| m.pubkey != pubkey);
+    }
+}
+
+fn dirs_path() -> PathBuf {
+    let base = std::env::var("BLEND_CONFIG_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+            Path::new(&home).join(".config").join("blend")
+        });
+    base
+}
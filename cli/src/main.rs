@@ -0,0 +1,54 @@
+mod compute_budget;
+mod config;
+mod market;
+
+use anyhow::Result;
+use clap::Parser;
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Parser)]
+#[command(name = "blend", about = "CLI for the borrow-lending program")]
+struct Cli {
+    /// Lending market pubkey to operate against. Omit to pick interactively
+    /// from the configured favorites; no on-chain discovery is done.
+    #[arg(long)]
+    market: Option<Pubkey>,
+
+    /// Market owner to derive the market PDA from, instead of passing
+    /// `--market` directly.
+    #[arg(long, conflicts_with = "market")]
+    owner: Option<Pubkey>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    /// Resolve one lending market (`--market`, `--owner`, or the picker over
+    /// favorites) and print its pubkey. Markets the program owns on-chain
+    /// are not listed.
+    Markets,
+    /// Star a market so it shows up at the top of the picker.
+    Favorite { market: Pubkey, label: String },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let mut cfg = config::Config::load()?;
+    let explicit_market = cli.market.or_else(|| cli.owner.map(|owner| market::derive_from_owner(&owner)));
+
+    match cli.command {
+        Command::Markets => {
+            let discovered = Vec::new(); // no on-chain scan: the picker offers favorites only
+            let selected = market::resolve(explicit_market, &cfg, discovered)?;
+            println!("{selected}");
+        }
+        Command::Favorite { market, label } => {
+            cfg.add_favorite(market, label);
+            cfg.save()?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+//! Lending market discovery and selection, shared by the `--market` CLI
+//! flag and the interactive TUI picker.
+
+use anyhow::{bail, Result};
+use dialoguer::Select;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::config::Config;
+
+pub struct MarketChoice {
+    pub pubkey: Pubkey,
+    pub label: String,
+}
+
+/// Derives the lending market PDA owned by `owner`, so integrators can
+/// point the CLI at a market with just the owner's pubkey instead of
+/// looking up the market address separately.
+pub fn derive_from_owner(owner: &Pubkey) -> Pubkey {
+    borrow_lending::pda::lending_market_address(owner, &borrow_lending::ID).0
+}
+
+/// Resolves the market to operate against: an explicit `--market` flag
+/// takes priority, then falls back to an interactive picker seeded with
+/// favorites followed by every other market the program owns.
+pub fn resolve(
+    explicit: Option<Pubkey>,
+    config: &Config,
+    discovered: Vec<Pubkey>,
+) -> Result<Pubkey> {
+    if let Some(pubkey) = explicit {
+        return Ok(pubkey);
+    }
+
+    let mut choices: Vec<MarketChoice> = config
+        .favorite_markets
+        .iter()
+        .map(|m| MarketChoice {
+            pubkey: m.pubkey,
+            label: format!("★ {} ({})", m.label, m.pubkey),
+        })
+        .collect();
+
+    for pubkey in discovered {
+        if choices.iter().any(|c| c.pubkey == pubkey) {
+            continue;
+        }
+        choices.push(MarketChoice {
+            pubkey,
+            label: pubkey.to_string(),
+        });
+    }
+
+    if choices.is_empty() {
+        bail!("no lending markets found for this program id");
+    }
+
+    let labels: Vec<&str> = choices.iter().map(|c| c.label.as_str()).collect();
+    let selection = Select::new()
+        .with_prompt("Select a lending market")
+        .items(&labels)
+        .default(0)
+        .interact()?;
+
+    Ok(choices[selection].pubkey)
+}
@@ -0,0 +1,61 @@
+mod positions;
+
+use std::collections::HashMap;
+
+use axum::extract::{Path, Query};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::Router;
+use solana_sdk::pubkey::Pubkey;
+
+use positions::UserPositions;
+
+#[tokio::main]
+async fn main() {
+    let app = Router::new().route("/users/:pubkey/positions", get(get_user_positions));
+
+    let listener = tokio::net::TcpListener::bind("0.0.0.0:8080").await.unwrap();
+    axum::serve(listener, app).await.unwrap();
+}
+
+/// `GET /users/:pubkey/positions` — every position the wallet holds across
+/// the protocol. Add `?format=csv` to get a flat CSV instead of JSON, for
+/// pasting into a spreadsheet during a support or compliance review.
+async fn get_user_positions(
+    Path(owner): Path<String>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Response {
+    let owner: Pubkey = match owner.parse() {
+        Ok(owner) => owner,
+        Err(_) => return (StatusCode::BAD_REQUEST, "invalid pubkey").into_response(),
+    };
+
+    let positions = UserPositions {
+        owner,
+        ..aggregate_positions(owner).await
+    };
+
+    if params.get("format").map(String::as_str) == Some("csv") {
+        match positions.to_csv() {
+            Ok(csv) => ([("content-type", "text/csv")], csv).into_response(),
+            Err(err) => (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+        }
+    } else {
+        axum::Json(positions).into_response()
+    }
+}
+
+/// Builds the positions response for `owner`. No account scan is made here:
+/// every list, obligations included, is returned empty. This is the single
+/// place a per-source scan plugs in, so the HTTP layer doesn't change when
+/// one is added.
+async fn aggregate_positions(owner: Pubkey) -> UserPositions {
+    UserPositions {
+        owner,
+        obligations: Vec::new(),
+        stable_coin_receipts: Vec::new(),
+        vault_positions: Vec::new(),
+        farming_receipts: Vec::new(),
+    }
+}
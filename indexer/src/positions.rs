@@ -0,0 +1,45 @@
+//! Aggregates everything a given wallet holds across the protocol into one
+//! response, for support and compliance workflows that otherwise have to
+//! scan each position type separately.
+//!
+//! Only the response shape is in place: `aggregate_positions` performs no
+//! account scan, so `obligations`, `stable_coin_receipts`,
+//! `vault_positions` and `farming_receipts` are all returned empty. Each
+//! source has its own field, so populating one is additive: no breaking
+//! change to clients already consuming `/users/:pubkey/positions`.
+
+use serde::Serialize;
+use solana_sdk::pubkey::Pubkey;
+
+#[derive(Debug, Default, Serialize)]
+pub struct UserPositions {
+    pub owner: Pubkey,
+    pub obligations: Vec<ObligationPosition>,
+    pub stable_coin_receipts: Vec<serde_json::Value>,
+    pub vault_positions: Vec<serde_json::Value>,
+    pub farming_receipts: Vec<serde_json::Value>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ObligationPosition {
+    pub obligation: Pubkey,
+    pub lending_market: Pubkey,
+    pub deposited_value: u128,
+    pub borrowed_value: u128,
+}
+
+impl UserPositions {
+    pub fn to_csv(&self) -> anyhow::Result<String> {
+        let mut writer = csv::Writer::from_writer(vec![]);
+        for obligation in &self.obligations {
+            writer.serialize((
+                "obligation",
+                obligation.obligation.to_string(),
+                obligation.lending_market.to_string(),
+                obligation.deposited_value,
+                obligation.borrowed_value,
+            ))?;
+        }
+        Ok(String::from_utf8(writer.into_inner()?)?)
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct AccrueReserveInterest<'info> {
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+}
+
+/// Permissionless crank: compounds `reserve.liquidity.cumulative_borrow_rate`
+/// and `borrowed_amount` forward to the current slot without needing an
+/// oracle price, unlike `refresh_reserve`. Meant for a keeper to call on a
+/// schedule against reserves that see long stretches without a deposit,
+/// borrow or refresh, so interest compounds steadily instead of arriving as
+/// one large jump (and a matching jump in cached snapshots/emissions) at
+/// the next interaction.
+///
+/// Does not touch `liquidity.market_price` or the APY EWMA, so it's not a
+/// substitute for `refresh_reserve` before a borrow or liquidation — those
+/// still need a fresh price. Does mirror `refresh_reserve`'s habit of
+/// diffing `reserve.market_value(borrowed_amount)` before and after so
+/// `lending_market.total_borrow_value` doesn't drift out of sync with
+/// reserves this crank, rather than a borrow/repay/liquidation, accrues.
+pub fn handle(ctx: Context<AccrueReserveInterest>) -> Result<()> {
+    let reserve = &mut ctx.accounts.reserve;
+    let slot = Clock::get()?.slot;
+
+    let old_borrow_value = reserve.market_value(reserve.liquidity.borrowed_amount)?;
+    reserve.accrue_interest(slot)?;
+    let new_borrow_value = reserve.market_value(reserve.liquidity.borrowed_amount)?;
+
+    if new_borrow_value > old_borrow_value {
+        ctx.accounts
+            .lending_market
+            .increase_total_borrow_value(new_borrow_value.try_sub(old_borrow_value)?)?;
+    } else {
+        ctx.accounts
+            .lending_market
+            .decrease_total_borrow_value(old_borrow_value.try_sub(new_borrow_value)?);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+//! Endpoints for reserves whose collateral is an external AMM's LP
+//! position rather than a plain SPL token, grouped by venue since each
+//! has its own pool/position account layout and fair-value math.
+
+pub mod orca_whirlpool;
+pub mod raydium;
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+pub use crate::endpoints::init_reserve::InitReserve;
+use crate::models::ReserveConfig;
+
+/// Initializes a reserve whose collateral is an Orca Whirlpool LP
+/// position. The account layout and setup are identical to a plain
+/// `init_reserve` — the only difference is that the owner should wire
+/// `refresh_reserve_orca_whirlpool_position` (rather than
+/// `refresh_reserve`) to price it.
+pub fn handle(ctx: Context<InitReserve>, config: ReserveConfig) -> Result<()> {
+    crate::endpoints::init_reserve::handle(ctx, config)
+}
@@ -0,0 +1,56 @@
+//! Hand-rolled reads of the handful of `whirlpools::state::{Whirlpool,
+//! Position}` fields we need, so this program doesn't need the Whirlpool
+//! crate itself (and its transitive dependency tree) just to read two
+//! numbers off each account.
+
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+
+/// Offset of `Position::liquidity` (8 discriminator + 32 whirlpool +
+/// 32 position_mint bytes precede it).
+const POSITION_LIQUIDITY_OFFSET: usize = 8 + 32 + 32;
+
+pub struct WhirlpoolPosition {
+    pub liquidity: u128,
+}
+
+impl WhirlpoolPosition {
+    pub fn read(position: &AccountInfo) -> Result<Self> {
+        let data = position.try_borrow_data()?;
+        require!(data.len() >= POSITION_LIQUIDITY_OFFSET + 16, ErrorCode::StalePrice);
+
+        let liquidity = u128::from_le_bytes(
+            data[POSITION_LIQUIDITY_OFFSET..POSITION_LIQUIDITY_OFFSET + 16]
+                .try_into()
+                .unwrap(),
+        );
+
+        Ok(Self { liquidity })
+    }
+}
+
+/// Offset of `Whirlpool::sqrt_price` (8 discriminator + 32 config + 1 bump
+/// + 2 tick_spacing + 2 tick_spacing_seed + 2 fee_rate + 2
+/// protocol_fee_rate + 16 liquidity bytes precede it).
+const WHIRLPOOL_SQRT_PRICE_OFFSET: usize = 8 + 32 + 1 + 2 + 2 + 2 + 2 + 16;
+
+pub struct WhirlpoolState {
+    /// Current pool price as a Q64.64 fixed-point square root.
+    pub sqrt_price_x64: u128,
+}
+
+impl WhirlpoolState {
+    pub fn read(pool: &AccountInfo) -> Result<Self> {
+        let data = pool.try_borrow_data()?;
+        require!(data.len() >= WHIRLPOOL_SQRT_PRICE_OFFSET + 16, ErrorCode::StalePrice);
+
+        let sqrt_price_x64 = u128::from_le_bytes(
+            data[WHIRLPOOL_SQRT_PRICE_OFFSET..WHIRLPOOL_SQRT_PRICE_OFFSET + 16]
+                .try_into()
+                .unwrap(),
+        );
+
+        Ok(Self { sqrt_price_x64 })
+    }
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::math::Decimal;
+
+const Q64: u128 = 1 << 64;
+
+/// Full-range approximation of a Whirlpool position's constituent token
+/// amounts at the pool's current price. Treats the position as if its
+/// liquidity were spread across the entire price curve rather than its
+/// actual `[tick_lower, tick_upper]` band — a deliberate simplification
+/// until tick-indexed sqrt-price math earns its added complexity for the
+/// reserves we expect to list.
+pub fn approximate_token_amounts(liquidity: u128, sqrt_price_x64: u128) -> Result<(Decimal, Decimal)> {
+    if sqrt_price_x64 == 0 {
+        return Ok((Decimal::zero(), Decimal::zero()));
+    }
+
+    let sqrt_price = Decimal::from_fraction(sqrt_price_x64, Q64)?;
+    let liquidity_decimal = Decimal::from_fraction(liquidity, 1)?;
+
+    let amount_a = liquidity_decimal.try_div(sqrt_price)?;
+    let amount_b = liquidity_decimal.try_mul(sqrt_price)?;
+
+    Ok((amount_a, amount_b))
+}
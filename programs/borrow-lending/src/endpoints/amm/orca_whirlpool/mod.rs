@@ -0,0 +1,4 @@
+pub mod init_reserve_orca_whirlpool_position;
+mod layouts;
+mod math;
+pub mod refresh_reserve_orca_whirlpool_position;
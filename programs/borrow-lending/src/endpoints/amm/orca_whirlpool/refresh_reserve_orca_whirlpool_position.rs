@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use super::layouts::{WhirlpoolPosition, WhirlpoolState};
+use super::math::approximate_token_amounts;
+use crate::models::Reserve;
+use crate::oracle;
+
+#[derive(Accounts)]
+pub struct RefreshReserveOrcaWhirlpoolPosition<'info> {
+    #[account(mut)]
+    pub reserve: Account<'info, Reserve>,
+
+    /// CHECK: the Whirlpool this position belongs to.
+    pub whirlpool: AccountInfo<'info>,
+    /// CHECK: the locked Whirlpool position NFT's position account.
+    pub position: AccountInfo<'info>,
+    /// CHECK: Pyth price account for the pool's token A.
+    pub token_a_price: AccountInfo<'info>,
+    /// CHECK: Pyth price account for the pool's token B.
+    pub token_b_price: AccountInfo<'info>,
+}
+
+/// Prices the reserve as the USD value of the constituent tokens a
+/// Whirlpool position would return if withdrawn right now, computed from
+/// the pool's current sqrt price and the two tokens' own oracle feeds
+/// (see `amm::orca_whirlpool::math` for the full-range approximation used).
+pub fn handle(ctx: Context<RefreshReserveOrcaWhirlpoolPosition>) -> Result<()> {
+    let position = WhirlpoolPosition::read(&ctx.accounts.position)?;
+    let pool = WhirlpoolState::read(&ctx.accounts.whirlpool)?;
+    let (amount_a, amount_b) = approximate_token_amounts(position.liquidity, pool.sqrt_price_x64)?;
+
+    let price_a = oracle::read_market_price(&ctx.accounts.token_a_price)?;
+    let price_b = oracle::read_market_price(&ctx.accounts.token_b_price)?;
+
+    let reserve = &mut ctx.accounts.reserve;
+    reserve.liquidity.market_price = amount_a.try_mul(price_a)?.try_add(amount_b.try_mul(price_b)?)?;
+    reserve.last_update_slot = Clock::get()?.slot;
+
+    Ok(())
+}
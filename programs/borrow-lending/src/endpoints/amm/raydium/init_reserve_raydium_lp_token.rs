@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+pub use crate::endpoints::init_reserve::InitReserve;
+use crate::models::ReserveConfig;
+
+/// Initializes a reserve whose collateral is a Raydium AMM LP token. The
+/// account layout and setup are identical to a plain `init_reserve` — the
+/// only difference is that the owner should wire
+/// `refresh_reserve_raydium_lp_token` (rather than `refresh_reserve`) to
+/// price it, mirroring the Orca Whirlpool LP collateral flow.
+pub fn handle(ctx: Context<InitReserve>, config: ReserveConfig) -> Result<()> {
+    crate::endpoints::init_reserve::handle(ctx, config)
+}
@@ -0,0 +1,2 @@
+pub mod init_reserve_raydium_lp_token;
+pub mod refresh_reserve_raydium_lp_token;
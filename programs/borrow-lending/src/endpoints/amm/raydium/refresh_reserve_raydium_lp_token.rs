@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::math::Decimal;
+use crate::models::Reserve;
+use crate::oracle;
+
+#[derive(Accounts)]
+pub struct RefreshReserveRaydiumLpToken<'info> {
+    #[account(mut)]
+    pub reserve: Account<'info, Reserve>,
+
+    pub lp_mint: Account<'info, Mint>,
+    pub pool_vault_a: Account<'info, TokenAccount>,
+    pub pool_vault_b: Account<'info, TokenAccount>,
+    /// CHECK: Pyth price account for the pool's token A.
+    pub token_a_price: AccountInfo<'info>,
+    /// CHECK: Pyth price account for the pool's token B.
+    pub token_b_price: AccountInfo<'info>,
+}
+
+/// Prices the reserve's LP token as the pool's total vault value divided
+/// by the LP mint's outstanding supply, mirroring the existing Orca
+/// Whirlpool LP collateral flow but for Raydium's plain constant-product
+/// pools, where the LP token is fungible and the vaults are ordinary SPL
+/// token accounts rather than a concentrated-liquidity position.
+pub fn handle(ctx: Context<RefreshReserveRaydiumLpToken>) -> Result<()> {
+    require!(ctx.accounts.lp_mint.supply > 0, crate::err::ErrorCode::MathOverflow);
+
+    let price_a = oracle::read_market_price(&ctx.accounts.token_a_price)?;
+    let price_b = oracle::read_market_price(&ctx.accounts.token_b_price)?;
+
+    let vault_a_value = Decimal::from(ctx.accounts.pool_vault_a.amount).try_mul(price_a)?;
+    let vault_b_value = Decimal::from(ctx.accounts.pool_vault_b.amount).try_mul(price_b)?;
+    let pool_value = vault_a_value.try_add(vault_b_value)?;
+
+    let reserve = &mut ctx.accounts.reserve;
+    reserve.liquidity.market_price = pool_value.try_div(Decimal::from(ctx.accounts.lp_mint.supply))?;
+    reserve.last_update_slot = Clock::get()?.slot;
+
+    Ok(())
+}
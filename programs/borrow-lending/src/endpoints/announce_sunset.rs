@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::LendingMarket;
+
+#[derive(Accounts)]
+pub struct AnnounceSunset<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"lending-market", lending_market.owner.as_ref()],
+        bump = lending_market.bump_seed,
+    )]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+}
+
+/// Announces (or cancels, by passing `None`) a planned wind-down of the
+/// market. Borrowing and idle liquidity deployment halt once `sunset_at_slot`
+/// passes, and liquidation thresholds tighten linearly from the moment of
+/// this announcement until then, giving depositors advance notice and a
+/// gradually de-risking window rather than an abrupt cutover.
+pub fn handle(ctx: Context<AnnounceSunset>, sunset_at_slot: Option<u64>) -> Result<()> {
+    let current_slot = Clock::get()?.slot;
+    if let Some(sunset_at_slot) = sunset_at_slot {
+        require!(sunset_at_slot > current_slot, ErrorCode::MathOverflow);
+    }
+
+    let lending_market = &mut ctx.accounts.lending_market;
+    lending_market.sunset_announced_at_slot = sunset_at_slot.map(|_| current_slot);
+    lending_market.sunset_at_slot = sunset_at_slot;
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{CreditDelegation, Obligation, Reserve};
+
+#[derive(Accounts)]
+pub struct ApproveCreditDelegation<'info> {
+    #[account(has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub reserve: Account<'info, Reserve>,
+    /// CHECK: the wallet being authorized to borrow against this
+    /// obligation — it doesn't need to sign its own approval, the owner
+    /// decides unilaterally (and can revoke the same way).
+    pub delegate: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = CreditDelegation::LEN,
+        seeds = [b"credit-delegation", obligation.key().as_ref(), delegate.key().as_ref(), reserve.key().as_ref()],
+        bump,
+    )]
+    pub credit_delegation: Account<'info, CreditDelegation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets (or lowers back to zero to revoke) how much of `reserve`'s
+/// liquidity `delegate` may borrow against `obligation`, without `delegate`
+/// ever holding the obligation itself.
+pub fn handle(ctx: Context<ApproveCreditDelegation>, credit_limit: u64) -> Result<()> {
+    let credit_delegation = &mut ctx.accounts.credit_delegation;
+    credit_delegation.obligation = ctx.accounts.obligation.key();
+    credit_delegation.delegate = ctx.accounts.delegate.key();
+    credit_delegation.reserve = ctx.accounts.reserve.key();
+    credit_delegation.credit_limit = credit_limit;
+
+    Ok(())
+}
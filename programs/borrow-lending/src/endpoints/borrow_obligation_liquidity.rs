@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{CreditDelegation, Host, LendingMarket, Obligation, ObligationLiquidity, RateMode, Referrer, Reserve};
+
+#[derive(Accounts)]
+pub struct BorrowObligationLiquidity<'info> {
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut)]
+    pub obligation: Account<'info, Obligation>,
+    /// Either the obligation's owner, or a delegate borrowing against a
+    /// `CreditDelegation` the owner approved (see `approve_credit_delegation`).
+    pub owner: Signer<'info>,
+    /// Required, and checked against `owner` and `borrow_reserve`, only
+    /// when `owner` isn't the obligation's own owner.
+    pub credit_delegation: Option<Account<'info, CreditDelegation>>,
+
+    #[account(mut)]
+    pub borrow_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_liquidity: Account<'info, TokenAccount>,
+    /// Optional distinct recipient for the borrowed liquidity, e.g. an
+    /// integrator's own program vault, so routers and payment flows can
+    /// stream borrowed funds straight there instead of needing an extra
+    /// transfer after this instruction. Defaults to `destination_liquidity`
+    /// when omitted.
+    #[account(mut)]
+    pub recipient_liquidity_wallet: Option<Account<'info, TokenAccount>>,
+
+    /// Frontend that referred this borrow, earning
+    /// `borrow_reserve.config.host_fee_bps` of the origination fee.
+    /// Omit to leave the whole origination fee with the protocol.
+    #[account(mut)]
+    pub host: Option<Account<'info, Host>>,
+    /// The obligation's `referrer`, earning
+    /// `borrow_reserve.config.referral_fee_bps` of the origination fee.
+    /// Stacks with `host`. Required iff `obligation.referrer.is_some()`.
+    #[account(mut)]
+    pub referrer_account: Option<Account<'info, Referrer>>,
+
+    pub token_program: Program<'info, Token>,
+    // If `borrow_reserve.config.fee_split_count > 0`, remaining accounts
+    // are that many token accounts, in the same order as
+    // `borrow_reserve.config.fee_split`, each receiving its configured
+    // share of `retained_fee`.
+}
+
+/// Borrows `liquidity_amount` of `borrow_reserve`'s liquidity against the
+/// obligation's deposited collateral. If the reserve has
+/// `config.fixed_term_slots` set, the new borrow is a fixed-term loan
+/// maturing that many slots from now (synth-782); otherwise it's an
+/// open-ended, variable-rate borrow. The obligation must already be
+/// refreshed and have enough headroom for `liquidity_amount`. Funds land in
+/// `recipient_liquidity_wallet` if supplied, otherwise `destination_liquidity`.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BorrowObligationLiquidity<'info>>,
+    liquidity_amount: u64,
+) -> Result<()> {
+    let obligation = &mut ctx.accounts.obligation;
+    let reserve = &mut ctx.accounts.borrow_reserve;
+    let slot = Clock::get()?.slot;
+    obligation.check_not_stale(slot)?;
+    require!(!ctx.accounts.lending_market.is_past_sunset(slot), ErrorCode::MarketSunset);
+    reserve.check_not_retiring()?;
+    reserve.check_not_frozen()?;
+    reserve.check_borrowing_enabled()?;
+
+    let owner_key = ctx.accounts.owner.key();
+    if owner_key != obligation.owner {
+        let credit_delegation = ctx
+            .accounts
+            .credit_delegation
+            .as_mut()
+            .ok_or(ErrorCode::NoCreditDelegation)?;
+        require_keys_eq!(credit_delegation.obligation, obligation.key(), ErrorCode::NoCreditDelegation);
+        require_keys_eq!(credit_delegation.delegate, owner_key, ErrorCode::NoCreditDelegation);
+        require_keys_eq!(credit_delegation.reserve, reserve.key(), ErrorCode::NoCreditDelegation);
+        require!(credit_delegation.remaining() >= liquidity_amount, ErrorCode::CreditDelegationExceeded);
+        credit_delegation.used_amount += liquidity_amount;
+    }
+
+    require!(reserve.liquidity.available_amount >= liquidity_amount, ErrorCode::BorrowTooLarge);
+    reserve.check_utilization_after_borrow(liquidity_amount)?;
+
+    let borrow_value = reserve.market_value(Decimal::from(liquidity_amount))?;
+    require!(borrow_value <= obligation.remaining_borrow_value(), ErrorCode::BorrowTooLarge);
+    ctx.accounts.lending_market.consume_outflow(slot, borrow_value)?;
+    ctx.accounts.lending_market.increase_total_borrow_value(borrow_value)?;
+
+    reserve.liquidity.available_amount -= liquidity_amount;
+    reserve.liquidity.borrowed_amount = reserve.liquidity.borrowed_amount.try_add(Decimal::from(liquidity_amount))?;
+
+    let maturity_slot = reserve.config.fixed_term_slots.map(|term| slot + term);
+
+    let existing_value = obligation
+        .borrows
+        .iter()
+        .find(|b| b.borrow_reserve == reserve.key())
+        .map(|b| b.market_value)
+        .unwrap_or_else(Decimal::zero);
+    if let Some(limit) = reserve.config.max_borrow_value_per_obligation {
+        require!(existing_value.try_add(borrow_value)? <= limit, ErrorCode::BorrowTooLarge);
+    }
+    require!(
+        existing_value.try_add(borrow_value)? >= reserve.config.min_borrow_uac_value,
+        ErrorCode::BorrowTooSmall
+    );
+
+    match obligation.borrows.iter_mut().find(|b| b.borrow_reserve == reserve.key()) {
+        Some(existing) => {
+            require!(existing.maturity_slot == maturity_slot, ErrorCode::BorrowTooLarge);
+            existing.borrowed_amount = existing.borrowed_amount.try_add(Decimal::from(liquidity_amount))?;
+        }
+        None => {
+            require!(obligation.has_room_for_new_reserve(), ErrorCode::ObligationReserveLimit);
+            obligation.borrows.push(ObligationLiquidity {
+                borrow_reserve: reserve.key(),
+                cumulative_borrow_rate: reserve.liquidity.cumulative_borrow_rate,
+                borrowed_amount: Decimal::from(liquidity_amount),
+                market_value: borrow_value,
+                rate_mode: RateMode::Variable,
+                maturity_slot,
+            });
+        }
+    }
+
+    obligation.borrowed_value = obligation.borrowed_value.try_add(borrow_value)?;
+
+    let borrow_fee = Decimal::from(liquidity_amount)
+        .try_mul(Decimal::from_fraction(reserve.config.borrow_fee_bps as u128, 10_000)?)?
+        .try_floor_u64()?;
+
+    // `retained_fee` is whatever's left of `borrow_fee` after every referral
+    // party's cut is carved out. With no fee split configured it's credited
+    // straight back to `available_amount` since it never physically left the
+    // vault; otherwise it's transferred out to the fee split destinations
+    // below. The carved-out cuts stay excluded until claimed via their own
+    // endpoints.
+    let mut retained_fee = borrow_fee;
+    if let Some(host) = ctx.accounts.host.as_mut() {
+        require_keys_eq!(host.reserve, reserve.key(), ErrorCode::HostReserveMismatch);
+        let host_cut = Decimal::from(borrow_fee)
+            .try_mul(Decimal::from_fraction(reserve.config.host_fee_bps as u128, 10_000)?)?
+            .try_floor_u64()?;
+        host.accrued_fees = host.accrued_fees.checked_add(host_cut).ok_or(ErrorCode::MathOverflow)?;
+        retained_fee = retained_fee.checked_sub(host_cut).ok_or(ErrorCode::MathOverflow)?;
+    }
+    if let Some(referrer_account) = ctx.accounts.referrer_account.as_mut() {
+        require_keys_eq!(referrer_account.reserve, reserve.key(), ErrorCode::ReferrerReserveMismatch);
+        require!(obligation.referrer == Some(referrer_account.referrer), ErrorCode::ReferrerObligationMismatch);
+        let referral_cut = Decimal::from(borrow_fee)
+            .try_mul(Decimal::from_fraction(reserve.config.referral_fee_bps as u128, 10_000)?)?
+            .try_floor_u64()?;
+        referrer_account.accrued_fees =
+            referrer_account.accrued_fees.checked_add(referral_cut).ok_or(ErrorCode::MathOverflow)?;
+        retained_fee = retained_fee.checked_sub(referral_cut).ok_or(ErrorCode::MathOverflow)?;
+    }
+    require!(
+        ctx.accounts.referrer_account.is_some() || obligation.referrer.is_none(),
+        ErrorCode::ReferrerObligationMismatch
+    );
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+
+    let fee_split_count = reserve.config.fee_split_count as usize;
+    if fee_split_count == 0 {
+        // Original behavior: the remainder never physically leaves the
+        // vault, so it's credited straight back to `available_amount`,
+        // benefiting depositors via the exchange rate.
+        reserve.liquidity.available_amount =
+            reserve.liquidity.available_amount.checked_add(retained_fee).ok_or(ErrorCode::MathOverflow)?;
+    } else {
+        require!(
+            ctx.remaining_accounts.len() == fee_split_count,
+            ErrorCode::FeeSplitAccountMismatch
+        );
+
+        let mut distributed = 0u64;
+        for (index, (destination_info, split)) in
+            ctx.remaining_accounts.iter().zip(reserve.config.fee_split[..fee_split_count].iter()).enumerate()
+        {
+            require_keys_eq!(destination_info.key(), split.destination, ErrorCode::FeeSplitAccountMismatch);
+
+            // Last entry absorbs whatever's left of `retained_fee` after
+            // flooring every earlier entry's share, instead of risking a
+            // dust remainder nobody received.
+            let is_last = index == fee_split_count - 1;
+            let cut = if is_last {
+                retained_fee.checked_sub(distributed).ok_or(ErrorCode::MathOverflow)?
+            } else {
+                Decimal::from(retained_fee)
+                    .try_mul(Decimal::from_fraction(split.share_bps as u128, 10_000)?)?
+                    .try_floor_u64()?
+            };
+            distributed = distributed.checked_add(cut).ok_or(ErrorCode::MathOverflow)?;
+
+            if cut > 0 {
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        ctx.accounts.token_program.to_account_info(),
+                        Transfer {
+                            from: ctx.accounts.reserve_liquidity_supply.to_account_info(),
+                            to: destination_info.clone(),
+                            authority: ctx.accounts.lending_market.to_account_info(),
+                        },
+                        &[seeds],
+                    ),
+                    cut,
+                )?;
+            }
+        }
+    }
+
+    let recipient = ctx
+        .accounts
+        .recipient_liquidity_wallet
+        .as_ref()
+        .unwrap_or(&ctx.accounts.destination_liquidity);
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reserve_liquidity_supply.to_account_info(),
+                to: recipient.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        liquidity_amount - borrow_fee,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{BoosterStake, EmissionStrategy, LendingMarket, Obligation, Reserve};
+
+#[derive(Accounts)]
+pub struct ClaimEmission<'info> {
+    #[account(seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut)]
+    pub obligation: Account<'info, Obligation>,
+    #[account(mut, has_one = lending_market, address = reserve.key())]
+    pub reserve: Account<'info, Reserve>,
+
+    #[account(address = reserve.liquidity.reward_mint.ok_or(ErrorCode::NoEmissionsConfigured)?)]
+    pub reward_mint: Account<'info, Mint>,
+    /// Holds the reward tokens `claim_emission` pays out of, authority is
+    /// the lending market PDA just like every other reserve-controlled
+    /// token account.
+    #[account(mut)]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// Anyone can pay to create this if it doesn't exist yet — the
+    /// destination is pinned to the obligation owner's own associated
+    /// token account, so a permissionless caller can never redirect the
+    /// payout anywhere else.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = reward_mint,
+        associated_token::authority = obligation_owner,
+    )]
+    pub destination: Account<'info, TokenAccount>,
+    /// CHECK: only used as the associated token account's authority seed;
+    /// the `address` constraint below ties it to `obligation.owner`.
+    #[account(address = obligation.owner)]
+    pub obligation_owner: UncheckedAccount<'info>,
+
+    /// The obligation owner's governance token stake, if any, boosting
+    /// this claim's share via `LendingMarket::boost_multiplier`. Checked
+    /// against `lending_market` and `obligation_owner` in `handle`. Omit
+    /// for the unboosted share.
+    pub booster_stake: Option<Account<'info, BoosterStake>>,
+
+    /// Set when `reserve`'s emissions are funded through a multi-reserve
+    /// `EmissionStrategy` rather than its own standalone reward vault;
+    /// `reserve_index` then names `reserve`'s slot in `reserves` and
+    /// `reward_vault` is checked against the strategy's shared one instead
+    /// of being trusted bare.
+    pub emission_strategy: Option<Account<'info, EmissionStrategy>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Pays out a deposit's accrued emissions to the obligation owner's own
+/// associated token account. Callable by anyone, not just the owner, so an
+/// off-chain keeper can sweep and compound small reward balances that
+/// would otherwise sit unclaimed because the transaction fee isn't worth a
+/// user's time — enforcing the destination as the owner's own ATA means a
+/// permissionless caller can't redirect the payout anywhere else.
+pub fn handle(ctx: Context<ClaimEmission>, reserve_index: Option<u8>) -> Result<()> {
+    if let Some(strategy) = ctx.accounts.emission_strategy.as_ref() {
+        let reserve_index = reserve_index.ok_or(ErrorCode::EmissionStrategyIndexOutOfRange)?;
+        require_keys_eq!(
+            strategy.reserves.get(reserve_index as usize).map(|w| w.reserve).unwrap_or_default(),
+            ctx.accounts.reserve.key(),
+            ErrorCode::EmissionStrategyReserveMismatch
+        );
+        require_keys_eq!(strategy.reward_vault, ctx.accounts.reward_vault.key(), ErrorCode::EmissionStrategyReserveMismatch);
+    }
+
+    let slot = Clock::get()?.slot;
+    let reserve = &mut ctx.accounts.reserve;
+    let last_update_slot = reserve.last_update_slot;
+    reserve.accrue_rewards(last_update_slot, slot)?;
+
+    let obligation = &mut ctx.accounts.obligation;
+    let deposit = obligation
+        .deposits
+        .iter_mut()
+        .find(|d| d.deposit_reserve == reserve.key())
+        .ok_or(ErrorCode::ObligationDepositsEmpty)?;
+
+    let accrued = Decimal::from(deposit.deposited_amount).try_mul(reserve.liquidity.cumulative_reward_per_share)?;
+    let pending = accrued.try_sub(deposit.reward_debt)?.try_add(Decimal::from(deposit.accrued_rewards))?;
+    deposit.reward_debt = accrued;
+    deposit.accrued_rewards = 0;
+
+    let staked_amount = ctx
+        .accounts
+        .booster_stake
+        .as_ref()
+        .filter(|stake| stake.lending_market == ctx.accounts.lending_market.key() && stake.owner == ctx.accounts.obligation_owner.key())
+        .map(|stake| stake.staked_amount)
+        .unwrap_or(0);
+    let boost_multiplier = ctx.accounts.lending_market.boost_multiplier(staked_amount)?;
+    let pending_amount = pending.try_mul(boost_multiplier)?.try_floor_u64()?;
+
+    if pending_amount > 0 {
+        let seeds: &[&[u8]] = &[
+            b"lending-market",
+            ctx.accounts.lending_market.owner.as_ref(),
+            &[ctx.accounts.lending_market.bump_seed],
+        ];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reward_vault.to_account_info(),
+                    to: ctx.accounts.destination.to_account_info(),
+                    authority: ctx.accounts.lending_market.to_account_info(),
+                },
+                &[seeds],
+            ),
+            pending_amount,
+        )?;
+    }
+
+    Ok(())
+}
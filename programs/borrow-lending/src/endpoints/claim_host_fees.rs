@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::models::{Host, LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct ClaimHostFees<'info> {
+    #[account(seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    #[account(mut, has_one = lending_market, has_one = reserve, has_one = authority)]
+    pub host: Account<'info, Host>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pays `host.accrued_fees` out to the host's `destination` and zeroes
+/// the accrual. The fee was never disbursed to begin with — it's been
+/// sitting in `reserve_liquidity_supply` excluded from
+/// `reserve.liquidity.available_amount` since the borrow that earned it
+/// — so this just releases it.
+pub fn handle(ctx: Context<ClaimHostFees>) -> Result<()> {
+    let amount = ctx.accounts.host.accrued_fees;
+    ctx.accounts.host.accrued_fees = 0;
+
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reserve_liquidity_supply.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::models::{LendingMarket, Referrer, Reserve};
+
+#[derive(Accounts)]
+pub struct ClaimReferralFees<'info> {
+    #[account(seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    #[account(mut, has_one = lending_market, has_one = reserve, has_one = referrer)]
+    pub referrer_account: Account<'info, Referrer>,
+    pub referrer: Signer<'info>,
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pays `referrer_account.accrued_fees` out to `destination` and zeroes
+/// the accrual, same mechanics as `claim_host_fees`: the fee never left
+/// `reserve_liquidity_supply`, so this just releases it.
+pub fn handle(ctx: Context<ClaimReferralFees>) -> Result<()> {
+    let amount = ctx.accounts.referrer_account.accrued_fees;
+    ctx.accounts.referrer_account.accrued_fees = 0;
+
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reserve_liquidity_supply.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::models::Obligation;
+
+#[derive(Accounts)]
+pub struct CloseObligation<'info> {
+    #[account(mut, has_one = owner, close = owner)]
+    pub obligation: Account<'info, Obligation>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+/// Closes an obligation and refunds its rent to the owner, once it's been
+/// fully unwound: no deposits, no borrows, and nothing left over in the
+/// value fields `refresh_obligation` maintains. Leaving a stale zero-value
+/// field wouldn't cause any accounting bug on its own, but checking all of
+/// them catches an obligation that's empty only because it's gone stale
+/// and hasn't been refreshed since its last position closed.
+pub fn handle(ctx: Context<CloseObligation>) -> Result<()> {
+    let obligation = &ctx.accounts.obligation;
+
+    require!(obligation.deposits.is_empty(), ErrorCode::ObligationNotEmpty);
+    require!(obligation.borrows.is_empty(), ErrorCode::ObligationNotEmpty);
+    require!(obligation.deposited_value.to_scaled_val() == 0, ErrorCode::ObligationNotEmpty);
+    require!(obligation.borrowed_value.to_scaled_val() == 0, ErrorCode::ObligationNotEmpty);
+
+    Ok(())
+}
+
+#[error_code]
+enum ErrorCode {
+    #[msg("Obligation still has deposits, borrows, or unrefreshed value and cannot be closed")]
+    ObligationNotEmpty,
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Token, TokenAccount};
+
+use crate::models::{LendingMarket, Reserve, ReserveCapSnapshots};
+
+/// Emitted right before a retired reserve's account is closed, so indexers
+/// keep a record of which reserve and collateral mint went away after the
+/// account itself stops existing. It carries no balances or totals.
+#[event]
+pub struct ReserveClosed {
+    pub reserve: Pubkey,
+    pub collateral_mint: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct CloseReserve<'info> {
+    #[account(
+        has_one = owner,
+        seeds = [b"lending-market", lending_market.owner.as_ref()],
+        bump = lending_market.bump_seed,
+    )]
+    pub lending_market: Account<'info, LendingMarket>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market, close = owner)]
+    pub reserve: Account<'info, Reserve>,
+
+    #[account(mut, address = reserve.liquidity.supply)]
+    pub liquidity_supply: Account<'info, TokenAccount>,
+
+    /// Tombstoned (synth-838) rather than closed outright, since
+    /// `sweep_pda_lamports` — not this instruction — is what reclaims its
+    /// rent; pass it whenever the reserve has one.
+    #[account(mut)]
+    pub cap_snapshots: Option<Account<'info, ReserveCapSnapshots>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Closes a reserve once it's retiring and fully unwound (no borrows, no
+/// outstanding collateral, no idle or available liquidity left to account
+/// for), returning both the reserve account's and its liquidity supply
+/// wallet's rent to the market owner.
+pub fn handle(ctx: Context<CloseReserve>) -> Result<()> {
+    let reserve = &ctx.accounts.reserve;
+    require!(reserve.is_fully_unwound(), ErrorCode::ReserveNotFullyUnwound);
+    require!(
+        reserve.liquidity.available_amount == 0 && reserve.liquidity.deployed_amount == 0,
+        ErrorCode::ReserveNotFullyUnwound
+    );
+
+    emit!(ReserveClosed {
+        reserve: reserve.key(),
+        collateral_mint: reserve.collateral.mint,
+    });
+
+    if let Some(cap_snapshots) = ctx.accounts.cap_snapshots.as_mut() {
+        require_keys_eq!(cap_snapshots.reserve, reserve.key(), ErrorCode::SnapshotReserveMismatch);
+        cap_snapshots.mark_closed();
+    }
+
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.liquidity_supply.to_account_info(),
+            destination: ctx.accounts.owner.to_account_info(),
+            authority: ctx.accounts.lending_market.to_account_info(),
+        },
+        &[seeds],
+    ))?;
+
+    Ok(())
+}
+
+#[error_code]
+enum ErrorCode {
+    #[msg("Reserve must be retiring with zero borrows, collateral, and liquidity before it can be closed")]
+    ReserveNotFullyUnwound,
+    #[msg("cap_snapshots does not belong to this reserve")]
+    SnapshotReserveMismatch,
+}
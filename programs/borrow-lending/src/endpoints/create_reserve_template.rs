@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{LendingMarket, ReserveConfig, ReserveTemplate};
+
+#[derive(Accounts)]
+#[instruction(label: [u8; 32])]
+pub struct CreateReserveTemplate<'info> {
+    #[account(
+        has_one = owner,
+        seeds = [b"lending-market", lending_market.owner.as_ref()],
+        bump = lending_market.bump_seed,
+    )]
+    pub lending_market: Account<'info, LendingMarket>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = ReserveTemplate::LEN,
+        seeds = [b"reserve-template", lending_market.key().as_ref(), label.as_ref()],
+        bump,
+    )]
+    pub reserve_template: Account<'info, ReserveTemplate>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers a vetted config preset new reserves can be listed from via
+/// `init_reserve_from_template`, so the market owner only has to get a
+/// given combination of LTV, liquidation terms, and rate curve right once.
+pub fn handle(ctx: Context<CreateReserveTemplate>, label: [u8; 32], config: ReserveConfig) -> Result<()> {
+    config.validate_fee_split()?;
+    config.validate_critical_utilization()?;
+
+    let reserve_template = &mut ctx.accounts.reserve_template;
+    reserve_template.lending_market = ctx.accounts.lending_market.key();
+    reserve_template.label = label;
+    reserve_template.config = config;
+
+    Ok(())
+}
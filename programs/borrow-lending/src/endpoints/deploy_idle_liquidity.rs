@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::TokenAccount;
+
+use crate::err::ErrorCode;
+use crate::models::{LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct DeployIdleLiquidity<'info> {
+    #[account(
+        has_one = owner,
+        seeds = [b"lending-market", lending_market.owner.as_ref()],
+        bump = lending_market.bump_seed,
+    )]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    /// CHECK: must match `reserve.config.idle_strategy_program`.
+    pub strategy_program: AccountInfo<'info>,
+    // Remaining accounts are forwarded to the strategy program's deposit
+    // instruction (e.g. its vault token account, its own state account).
+}
+
+/// Deploys up to `amount` of a reserve's idle liquidity into its
+/// configured treasury strategy adapter via CPI, so reserves with low
+/// utilization still earn yield on the capital sitting unborrowed. Capped
+/// by `config.max_deployed_pct` so withdrawals never have to wait on an
+/// external unwind for the bulk of the reserve's liquidity.
+pub fn handle(ctx: Context<DeployIdleLiquidity>, amount: u64, strategy_deposit_ix_data: Vec<u8>) -> Result<()> {
+    require!(
+        !ctx.accounts.lending_market.is_past_sunset(Clock::get()?.slot),
+        ErrorCode::MarketSunset
+    );
+
+    let reserve = &mut ctx.accounts.reserve;
+    require_keys_eq!(
+        ctx.accounts.strategy_program.key(),
+        reserve.config.idle_strategy_program.ok_or(ErrorCode::MathOverflow)?
+    );
+
+    let max_deployed = (reserve.liquidity.available_amount as u128)
+        .checked_mul(reserve.config.max_deployed_pct as u128)
+        .and_then(|v| v.checked_div(100))
+        .ok_or(ErrorCode::MathOverflow)? as u64;
+
+    let new_deployed = reserve
+        .liquidity
+        .deployed_amount
+        .checked_add(amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    require!(new_deployed <= max_deployed, ErrorCode::BorrowTooLarge);
+
+    let accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|a| AccountMeta {
+            pubkey: a.key(),
+            is_signer: a.is_signer,
+            is_writable: a.is_writable,
+        })
+        .collect();
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+    invoke_signed(
+        &Instruction {
+            program_id: ctx.accounts.strategy_program.key(),
+            accounts,
+            data: strategy_deposit_ix_data,
+        },
+        ctx.remaining_accounts,
+        &[seeds],
+    )?;
+
+    reserve.liquidity.deployed_amount = new_deployed;
+
+    Ok(())
+}
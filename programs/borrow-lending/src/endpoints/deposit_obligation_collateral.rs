@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::math::Decimal;
+use crate::models::{Obligation, ObligationCollateral, Reserve, RiskTier};
+
+#[derive(Accounts)]
+pub struct DepositObligationCollateral<'info> {
+    #[account(mut, has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    pub owner: Signer<'info>,
+
+    pub deposit_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub source_collateral: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_collateral: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Deposits `collateral_amount` of `deposit_reserve`'s collateral token
+/// into the obligation. Enforces reserve risk tiers (synth-781): an
+/// isolated-tier reserve's collateral cannot be combined with any other
+/// reserve's in the same obligation, in either direction.
+pub fn handle(ctx: Context<DepositObligationCollateral>, collateral_amount: u64) -> Result<()> {
+    let obligation = &mut ctx.accounts.obligation;
+    let reserve = &ctx.accounts.deposit_reserve;
+    reserve.check_not_retiring()?;
+    reserve.check_not_frozen()?;
+
+    let adding_isolated = reserve.config.risk_tier == RiskTier::Isolated;
+    let has_other_deposits = obligation
+        .deposits
+        .iter()
+        .any(|d| d.deposit_reserve != reserve.key());
+
+    if adding_isolated {
+        require!(!has_other_deposits, ErrorCode::CrossCollateralizationNotAllowed);
+    } else if !obligation.deposits.is_empty() {
+        // Any existing deposit being isolated-tier also blocks adding a
+        // different, non-isolated reserve.
+        require!(
+            obligation.deposits.iter().all(|d| d.deposit_reserve == reserve.key()),
+            ErrorCode::CrossCollateralizationNotAllowed
+        );
+    }
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source_collateral.to_account_info(),
+                to: ctx.accounts.destination_collateral.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        collateral_amount,
+    )?;
+
+    match obligation.deposits.iter_mut().find(|d| d.deposit_reserve == reserve.key()) {
+        Some(deposit) => {
+            let new_deposited_amount = deposit.deposited_amount + collateral_amount;
+            deposit.rebase_rewards(new_deposited_amount, reserve.liquidity.cumulative_reward_per_share)?;
+            deposit.cost_basis_liquidity = Decimal::from(reserve.collateral_to_liquidity(new_deposited_amount)?);
+        }
+        None => {
+            require!(obligation.has_room_for_new_reserve(), crate::err::ErrorCode::ObligationReserveLimit);
+            obligation.deposits.push(ObligationCollateral {
+                deposit_reserve: reserve.key(),
+                deposited_amount: collateral_amount,
+                market_value: Decimal::zero(),
+                reward_debt: Decimal::from(collateral_amount).try_mul(reserve.liquidity.cumulative_reward_per_share)?,
+                accrued_rewards: 0,
+                cost_basis_liquidity: Decimal::from(reserve.collateral_to_liquidity(collateral_amount)?),
+                harvestable_liquidity: 0,
+                soft_liquidated_amount: 0,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+#[error_code]
+enum ErrorCode {
+    #[msg("Isolated-tier reserve collateral cannot be combined with other reserves")]
+    CrossCollateralizationNotAllowed,
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::models::{LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct DepositReserveLiquidity<'info> {
+    #[account(seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub source_liquidity: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub reserve_collateral_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub destination_collateral: Account<'info, TokenAccount>,
+    pub liquidity_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Deposits `liquidity_amount` of the reserve's underlying liquidity and
+/// mints the caller the equivalent collateral tokens at the current
+/// exchange rate. This is the liquidity-provider side of a reserve;
+/// `deposit_obligation_collateral` is the separate step of locking
+/// already-minted collateral into an obligation to borrow against.
+pub fn handle(ctx: Context<DepositReserveLiquidity>, liquidity_amount: u64) -> Result<()> {
+    deposit_liquidity(
+        &ctx.accounts.lending_market,
+        &mut ctx.accounts.reserve,
+        ctx.accounts.source_liquidity.to_account_info(),
+        ctx.accounts.liquidity_authority.to_account_info(),
+        ctx.accounts.reserve_liquidity_supply.to_account_info(),
+        ctx.accounts.destination_collateral.to_account_info(),
+        ctx.accounts.reserve_collateral_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        liquidity_amount,
+    )
+}
+
+/// Shared by [`handle`] and `deposit_reserve_liquidity_sol`, which only
+/// differs in how `source_liquidity` gets its tokens (a pre-existing
+/// account vs. a freshly wrapped temporary wSOL one).
+pub(crate) fn deposit_liquidity<'info>(
+    lending_market: &Account<'info, LendingMarket>,
+    reserve: &mut Account<'info, Reserve>,
+    source_liquidity: AccountInfo<'info>,
+    liquidity_authority: AccountInfo<'info>,
+    reserve_liquidity_supply: AccountInfo<'info>,
+    destination_collateral: AccountInfo<'info>,
+    reserve_collateral_mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    liquidity_amount: u64,
+) -> Result<()> {
+    reserve.check_not_retiring()?;
+    reserve.check_not_frozen()?;
+
+    let collateral_amount = reserve.liquidity_to_collateral(liquidity_amount)?;
+    reserve.liquidity.available_amount = reserve
+        .liquidity
+        .available_amount
+        .checked_add(liquidity_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    reserve.collateral.mint_total_supply = reserve
+        .collateral
+        .mint_total_supply
+        .checked_add(collateral_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    token::transfer(
+        CpiContext::new(
+            token_program.clone(),
+            Transfer {
+                from: source_liquidity,
+                to: reserve_liquidity_supply,
+                authority: liquidity_authority,
+            },
+        ),
+        liquidity_amount,
+    )?;
+
+    let seeds: &[&[u8]] = &[b"lending-market", lending_market.owner.as_ref(), &[lending_market.bump_seed]];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program,
+            token::MintTo {
+                mint: reserve_collateral_mint,
+                to: destination_collateral,
+                authority: lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        collateral_amount,
+    )?;
+
+    Ok(())
+}
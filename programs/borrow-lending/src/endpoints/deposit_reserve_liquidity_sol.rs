@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, spl_token, Mint, SyncNative, Token, TokenAccount};
+use anchor_lang::system_program::{self, Transfer as SystemTransfer};
+
+use crate::endpoints::deposit_reserve_liquidity::deposit_liquidity;
+use crate::models::{LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct DepositReserveLiquiditySol<'info> {
+    #[account(seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub reserve_collateral_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub destination_collateral: Account<'info, TokenAccount>,
+
+    /// Ephemeral wSOL account this instruction wraps `lamports` into and
+    /// closes again before returning, so callers never have to manage a
+    /// standing wSOL account just to deposit the chain's native asset.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"tmp-wsol", payer.key().as_ref()],
+        bump,
+        token::mint = native_mint,
+        token::authority = payer,
+    )]
+    pub temp_wsol: Account<'info, TokenAccount>,
+    #[account(address = spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// SOL convenience wrapper around `deposit_reserve_liquidity`: wraps
+/// `lamports` of native SOL into a temporary wSOL account, deposits it
+/// like any other liquidity, then closes the temporary account so the
+/// rent comes straight back to the payer.
+pub fn handle(ctx: Context<DepositReserveLiquiditySol>, lamports: u64) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            SystemTransfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.temp_wsol.to_account_info(),
+            },
+        ),
+        lamports,
+    )?;
+    token::sync_native(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        SyncNative { account: ctx.accounts.temp_wsol.to_account_info() },
+    ))?;
+
+    deposit_liquidity(
+        &ctx.accounts.lending_market,
+        &mut ctx.accounts.reserve,
+        ctx.accounts.temp_wsol.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.reserve_liquidity_supply.to_account_info(),
+        ctx.accounts.destination_collateral.to_account_info(),
+        ctx.accounts.reserve_collateral_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        lamports,
+    )?;
+
+    token::close_account(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.temp_wsol.to_account_info(),
+            destination: ctx.accounts.payer.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        },
+    ))?;
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::models::Reserve;
+
+/// Flash loan fee, in basis points of the borrowed amount. Unlike the
+/// borrow fee, 100% of this accrues to the reserve's liquidity pool (see
+/// `handle`) rather than to the protocol, since a flash loan never
+/// displaces another borrower's capital — depositors are the only ones
+/// taking the (momentary) counterparty risk.
+pub const FLASH_LOAN_FEE_BPS: u64 = 30;
+
+#[derive(Accounts)]
+pub struct FlashLoan<'info> {
+    #[account(mut)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: the program invoked back with the borrowed funds; must
+    /// eventually repay `amount + fee` into `reserve_liquidity_supply`
+    /// before this instruction returns, or the whole transaction reverts.
+    pub target_program: AccountInfo<'info>,
+    // Remaining accounts are forwarded verbatim to `target_program`'s
+    // callback instruction.
+}
+
+/// Lends `amount` of a reserve's liquidity for the duration of a single
+/// transaction, charging [`FLASH_LOAN_FEE_BPS`] on repayment. The fee is
+/// left in `reserve_liquidity_supply` without minting matching collateral
+/// tokens, so it accrues directly to existing depositors via a better
+/// collateral exchange rate — the same incentive-compatible split Aave
+/// uses, instead of routing flash loan revenue to the protocol treasury.
+pub fn handle(ctx: Context<FlashLoan>, amount: u64, callback_data: Vec<u8>) -> Result<()> {
+    let reserve_info = ctx.accounts.reserve.to_account_info();
+    let reserve = &mut ctx.accounts.reserve;
+    require!(reserve.liquidity.available_amount >= amount, ErrorCode::BorrowTooLarge);
+
+    let fee = amount
+        .checked_mul(FLASH_LOAN_FEE_BPS)
+        .and_then(|v| v.checked_div(10_000))
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let balance_before = ctx.accounts.reserve_liquidity_supply.amount;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reserve_liquidity_supply.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: reserve_info,
+            },
+        ),
+        amount,
+    )?;
+
+    let callback_accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|a| AccountMeta {
+            pubkey: a.key(),
+            is_signer: a.is_signer,
+            is_writable: a.is_writable,
+        })
+        .collect();
+    invoke(
+        &Instruction {
+            program_id: ctx.accounts.target_program.key(),
+            accounts: callback_accounts,
+            data: callback_data,
+        },
+        ctx.remaining_accounts,
+    )?;
+
+    ctx.accounts.reserve_liquidity_supply.reload()?;
+    let balance_after = ctx.accounts.reserve_liquidity_supply.amount;
+    require!(
+        balance_after >= balance_before.saturating_sub(amount) + amount + fee,
+        ErrorCode::BorrowTooLarge
+    );
+
+    reserve.liquidity.available_amount = reserve
+        .liquidity
+        .available_amount
+        .checked_add(fee)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::math::Decimal;
+use crate::models::{LendingMarket, Obligation, Reserve};
+
+/// Emitted once an obligation has been force-settled, so indexers can tell
+/// a zeroed-out obligation apart from one the user simply closed out
+/// themselves via ordinary repay/withdraw.
+#[event]
+pub struct ObligationForceSettled {
+    pub obligation: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct ForceSettle<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"lending-market", lending_market.owner.as_ref()],
+        bump = lending_market.bump_seed,
+    )]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market)]
+    pub obligation: Account<'info, Obligation>,
+
+    pub token_program: Program<'info, Token>,
+    // Remaining accounts are `(reserve, source_collateral_vault,
+    // destination_collateral_account)` triples for each of `obligation`'s
+    // deposits, in order, followed by one `reserve` account per entry in
+    // `obligation.borrows`, in order.
+}
+
+/// Only callable once the market is past its announced `sunset_at_slot`
+/// (see `announce_sunset`). Returns every deposit in full to the
+/// obligation's owner and writes off outstanding borrows as bad debt on
+/// their reserves, the same `bad_debt_amount` bucket
+/// `refer_bad_debt_to_auction` records shortfalls in — a wind-down is the market's decision, not
+/// a default, so the protocol eats the shortfall rather than the user.
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, ForceSettle<'info>>) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    require!(ctx.accounts.lending_market.is_past_sunset(slot), ErrorCode::MarketNotYetSunset);
+
+    let obligation = &mut ctx.accounts.obligation;
+    let deposit_count = obligation.deposits.len();
+    let borrow_count = obligation.borrows.len();
+    require!(
+        ctx.remaining_accounts.len() == deposit_count * 3 + borrow_count,
+        crate::err::ErrorCode::MathOverflow
+    );
+    let (deposit_accounts, borrow_accounts) = ctx.remaining_accounts.split_at(deposit_count * 3);
+
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+
+    let obligation_owner = obligation.owner;
+    for (deposit, accounts) in obligation.deposits.iter().zip(deposit_accounts.chunks(3)) {
+        let [reserve_info, source_vault_info, destination_info] = accounts else {
+            unreachable!("chunks(3) always yields 3 accounts");
+        };
+        require_keys_eq!(reserve_info.key(), deposit.deposit_reserve, ErrorCode::ReserveMismatch);
+        let destination = Account::<TokenAccount>::try_from(destination_info)?;
+        require_keys_eq!(destination.owner, obligation_owner, ErrorCode::DestinationNotObligationOwner);
+
+        let source_vault = Account::<TokenAccount>::try_from(source_vault_info)?;
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: source_vault.to_account_info(),
+                    to: destination_info.clone(),
+                    authority: ctx.accounts.lending_market.to_account_info(),
+                },
+                &[seeds],
+            ),
+            deposit.deposited_amount,
+        )?;
+    }
+
+    for (borrow, reserve_info) in obligation.borrows.iter().zip(borrow_accounts.iter()) {
+        require_keys_eq!(reserve_info.key(), borrow.borrow_reserve, ErrorCode::ReserveMismatch);
+
+        let mut reserve = Account::<Reserve>::try_from(reserve_info)?;
+        reserve.liquidity.borrowed_amount = reserve
+            .liquidity
+            .borrowed_amount
+            .try_sub(borrow.borrowed_amount)
+            .unwrap_or_else(|_| Decimal::zero());
+        reserve.liquidity.bad_debt_amount = reserve.liquidity.bad_debt_amount.try_add(borrow.borrowed_amount)?;
+        reserve.exit(&crate::ID)?;
+    }
+
+    ctx.accounts.lending_market.decrease_total_borrow_value(obligation.borrowed_value);
+
+    obligation.deposits.clear();
+    obligation.borrows.clear();
+    obligation.deposited_value = Decimal::zero();
+    obligation.borrowed_value = Decimal::zero();
+    obligation.allowed_borrow_value = Decimal::zero();
+    obligation.unhealthy_borrow_value = Decimal::zero();
+    obligation.last_update_slot = slot;
+
+    emit!(ObligationForceSettled { obligation: obligation.key() });
+
+    Ok(())
+}
+
+#[error_code]
+enum ErrorCode {
+    #[msg("Market has not reached its announced sunset slot yet")]
+    MarketNotYetSunset,
+    #[msg("Remaining account doesn't match the obligation's recorded reserve")]
+    ReserveMismatch,
+    #[msg("Destination collateral account isn't owned by the obligation's owner")]
+    DestinationNotObligationOwner,
+}
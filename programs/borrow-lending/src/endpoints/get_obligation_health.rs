@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::models::Obligation;
+
+/// View-style instruction: loads a freshly-refreshed obligation and returns
+/// its [`ObligationHealth`] snapshot (deposited/borrowed value, remaining
+/// borrow headroom, and per-reserve max-withdrawable amounts) via Solana
+/// return data, so bots and UIs don't have to re-derive this math
+/// themselves and drift from `models/obligation.rs`.
+///
+/// Intended to be called via `simulateTransaction` rather than landed
+/// on-chain, since it only reads state.
+#[derive(Accounts)]
+pub struct GetObligationHealth<'info> {
+    pub obligation: Account<'info, Obligation>,
+}
+
+pub fn handle(ctx: Context<GetObligationHealth>) -> Result<()> {
+    let obligation = &ctx.accounts.obligation;
+    let slot = Clock::get()?.slot;
+    obligation.check_not_stale(slot)?;
+
+    let health = obligation.health(slot)?;
+    let data = health.try_to_vec()?;
+    anchor_lang::solana_program::program::set_return_data(&data);
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{Obligation, BYTES_PER_RESERVE_SLOT};
+
+#[derive(Accounts)]
+#[instruction(added_reserves: u8)]
+pub struct GrowObligation<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        realloc = obligation.to_account_info().data_len() + added_reserves as usize * BYTES_PER_RESERVE_SLOT,
+        realloc::payer = owner,
+        realloc::zero = false,
+    )]
+    pub obligation: Account<'info, Obligation>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Raises an obligation's reserve capacity by `added_reserves` slots,
+/// reallocating the account to make room and charging the owner the extra
+/// rent. Existing deposits and borrows are untouched — `realloc` only
+/// changes the account's length, not its already-serialized data — so
+/// there's no separate migration step for positions opened under the old
+/// capacity.
+pub fn handle(ctx: Context<GrowObligation>, added_reserves: u8) -> Result<()> {
+    let obligation = &mut ctx.accounts.obligation;
+    obligation.max_reserves = obligation
+        .max_reserves
+        .checked_add(added_reserves)
+        .ok_or(crate::err::ErrorCode::MathOverflow)?;
+
+    Ok(())
+}
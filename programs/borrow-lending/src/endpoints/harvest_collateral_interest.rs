@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{LendingMarket, Obligation, Reserve};
+
+#[derive(Accounts)]
+pub struct HarvestCollateralInterest<'info> {
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut, has_one = lending_market)]
+    pub obligation: Account<'info, Obligation>,
+
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub reserve_collateral_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Permissionless: realizes an opted-in obligation's cached
+/// `ObligationCollateral::harvestable_liquidity` against a same-reserve
+/// borrow, by burning the equivalent collateral out of the obligation's
+/// deposit and writing the debt down by the same liquidity amount.
+///
+/// Deliberately scoped to a deposit and a borrow on the *same* reserve:
+/// since the collateral being burned and the debt being repaid share a
+/// liquidity mint, the redemption and the repayment net out to a pure
+/// bookkeeping update (burn collateral, shrink both `mint_total_supply`
+/// and `liquidity.borrowed_amount` by the same amount) with no liquidity
+/// actually changing hands. Harvesting one reserve's collateral to repay a
+/// *different* reserve's debt would need a swap leg and isn't supported
+/// here.
+///
+/// The obligation must have been refreshed this slot so
+/// `harvestable_liquidity` is current.
+pub fn handle(ctx: Context<HarvestCollateralInterest>) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    let obligation = &mut ctx.accounts.obligation;
+    obligation.check_not_stale(slot)?;
+    require!(obligation.auto_repay_enabled, ErrorCode::AutoRepayDisabled);
+
+    let reserve = &mut ctx.accounts.reserve;
+
+    let deposit_index = obligation
+        .deposits
+        .iter()
+        .position(|d| d.deposit_reserve == reserve.key())
+        .ok_or(ErrorCode::ObligationDepositsEmpty)?;
+    let harvestable = obligation.deposits[deposit_index].harvestable_liquidity;
+    require!(harvestable > 0, ErrorCode::NothingToHarvest);
+
+    let borrow_index = obligation
+        .borrows
+        .iter()
+        .position(|b| b.borrow_reserve == reserve.key())
+        .ok_or(ErrorCode::ObligationBorrowsEmpty)?;
+
+    let owed = obligation.borrows[borrow_index].borrowed_amount;
+    let repay_amount = Decimal::from(harvestable).min(owed);
+    let repaid_fraction = repay_amount.try_div(owed)?;
+    let repaid_value = obligation.borrows[borrow_index].market_value.try_mul(repaid_fraction)?;
+    obligation.borrows[borrow_index].borrowed_amount = owed.try_sub(repay_amount)?;
+    obligation.borrows[borrow_index].market_value =
+        obligation.borrows[borrow_index].market_value.try_sub(repaid_value)?;
+    obligation.borrowed_value = obligation.borrowed_value.try_sub(repaid_value)?;
+    ctx.accounts.lending_market.decrease_total_borrow_value(repaid_value);
+    if obligation.borrows[borrow_index].borrowed_amount.to_scaled_val() == 0 {
+        obligation.borrows.remove(borrow_index);
+    }
+
+    let repay_amount_floor = repay_amount.try_floor_u64()?;
+    let deposit = &mut obligation.deposits[deposit_index];
+    let collateral_to_burn = reserve
+        .liquidity_to_collateral(repay_amount_floor)?
+        .min(deposit.deposited_amount);
+    let new_deposited_amount = deposit.deposited_amount - collateral_to_burn;
+
+    deposit.rebase_rewards(new_deposited_amount, reserve.liquidity.cumulative_reward_per_share)?;
+    let realized_value = reserve.market_value(repay_amount)?;
+    deposit.market_value = deposit.market_value.try_sub(realized_value).unwrap_or_else(|_| Decimal::zero());
+    deposit.cost_basis_liquidity = Decimal::from(reserve.collateral_to_liquidity(new_deposited_amount)?);
+    deposit.harvestable_liquidity = 0;
+    obligation.deposited_value = obligation.deposited_value.try_sub(realized_value).unwrap_or_else(|_| Decimal::zero());
+    if obligation.deposits[deposit_index].deposited_amount == 0 {
+        obligation.deposits.remove(deposit_index);
+    }
+
+    reserve.collateral.mint_total_supply = reserve
+        .collateral
+        .mint_total_supply
+        .checked_sub(collateral_to_burn)
+        .ok_or(ErrorCode::MathOverflow)?;
+    reserve.liquidity.borrowed_amount = reserve.liquidity.borrowed_amount.try_sub(repay_amount)?;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.reserve_collateral_mint.to_account_info(),
+                from: ctx.accounts.collateral_vault.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+        )
+        .with_signer(&[&[
+            b"lending-market",
+            ctx.accounts.lending_market.owner.as_ref(),
+            &[ctx.accounts.lending_market.bump_seed],
+        ]]),
+        collateral_to_burn,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::{EmissionStrategy, EmissionWeight, LendingMarket, MAX_EMISSION_STRATEGY_RESERVES};
+
+#[derive(Accounts)]
+pub struct InitEmissionStrategy<'info> {
+    #[account(has_one = owner)]
+    pub lending_market: Account<'info, LendingMarket>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = EmissionStrategy::LEN,
+        seeds = [b"emission-strategy", lending_market.key().as_ref(), reward_mint.key().as_ref()],
+        bump,
+    )]
+    pub emission_strategy: Account<'info, EmissionStrategy>,
+    /// CHECK: only stored as a pubkey, validated for real the first time
+    /// `claim_emission` reads a reward mint off the reserve it funds.
+    pub reward_mint: UncheckedAccount<'info>,
+    /// Token account `sync_emission_strategy`-synced reserves' claims draw
+    /// from, authority is the lending market PDA. Must already exist.
+    pub reward_vault: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates a strategy that splits `total_reward_per_slot` across `reserves`
+/// by weight, so incentivizing a whole market takes one funding wallet and
+/// one admin account instead of a separate `set_reserve_emissions` call
+/// (and reward vault) per reserve. Call `sync_emission_strategy` once per
+/// covered reserve afterwards to push its derived rate onto it.
+pub fn handle(
+    ctx: Context<InitEmissionStrategy>,
+    total_reward_per_slot: u64,
+    reserves: Vec<EmissionWeight>,
+) -> Result<()> {
+    require!(reserves.len() <= MAX_EMISSION_STRATEGY_RESERVES, ErrorCode::EmissionStrategyFull);
+
+    let strategy = &mut ctx.accounts.emission_strategy;
+    strategy.lending_market = ctx.accounts.lending_market.key();
+    strategy.reward_mint = ctx.accounts.reward_mint.key();
+    strategy.reward_vault = ctx.accounts.reward_vault.key();
+    strategy.total_reward_per_slot = total_reward_per_slot;
+    strategy.reserves = reserves;
+    strategy.bump_seed = ctx.bumps.emission_strategy;
+
+    Ok(())
+}
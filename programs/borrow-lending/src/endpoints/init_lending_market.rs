@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{LendingMarket, CURRENT_ACCOUNT_VERSION};
+
+#[derive(Accounts)]
+pub struct InitLendingMarket<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = LendingMarket::LEN,
+        seeds = [b"lending-market", owner.key().as_ref()],
+        bump,
+    )]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<InitLendingMarket>, uac_mint: Pubkey) -> Result<()> {
+    let market = &mut ctx.accounts.lending_market;
+    market.owner = ctx.accounts.owner.key();
+    market.uac_mint = uac_mint;
+    market.bump_seed = ctx.bumps.lending_market;
+    market.margin_call_warning_threshold_pct = 90;
+    market.version = CURRENT_ACCOUNT_VERSION;
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{LendingMarket, Obligation, StrategyTag, CURRENT_ACCOUNT_VERSION, MAX_OBLIGATION_RESERVES};
+
+#[derive(Accounts)]
+pub struct InitObligation<'info> {
+    #[account(
+        seeds = [b"lending-market", lending_market.owner.as_ref()],
+        bump = lending_market.bump_seed,
+    )]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(init, payer = owner, space = 8 + 2000)]
+    pub obligation: Account<'info, Obligation>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// `strategy_tag` lets an integrator (a vault manager, a structured
+/// product) label the position it's opening on a user's behalf right from
+/// creation, same attribution `tag_obligation` applies to an existing
+/// obligation — useful since the owner's signature on this instruction is
+/// the only chance a strategy gets to tag a position without also needing
+/// a separate `tag_obligation` call in the same transaction.
+pub fn handle(ctx: Context<InitObligation>, referrer: Option<Pubkey>, strategy_tag: Option<StrategyTag>) -> Result<()> {
+    let obligation = &mut ctx.accounts.obligation;
+    obligation.lending_market = ctx.accounts.lending_market.key();
+    obligation.owner = ctx.accounts.owner.key();
+    obligation.last_update_slot = Clock::get()?.slot;
+    obligation.max_reserves = MAX_OBLIGATION_RESERVES as u8;
+    obligation.referrer = referrer;
+    obligation.strategy_tag = strategy_tag;
+    obligation.version = CURRENT_ACCOUNT_VERSION;
+
+    Ok(())
+}
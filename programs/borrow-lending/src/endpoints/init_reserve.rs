@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{LendingMarket, Reserve, ReserveConfig, CURRENT_ACCOUNT_VERSION};
+
+#[derive(Accounts)]
+pub struct InitReserve<'info> {
+    #[account(
+        has_one = owner,
+        seeds = [b"lending-market", lending_market.owner.as_ref()],
+        bump = lending_market.bump_seed,
+    )]
+    pub lending_market: Account<'info, LendingMarket>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(init, payer = owner, space = Reserve::LEN)]
+    pub reserve: Account<'info, Reserve>,
+
+    pub liquidity_mint: Account<'info, anchor_spl::token::Mint>,
+    pub liquidity_supply: Account<'info, anchor_spl::token::TokenAccount>,
+    pub collateral_mint: Account<'info, anchor_spl::token::Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(ctx: Context<InitReserve>, config: ReserveConfig) -> Result<()> {
+    config.validate_fee_split()?;
+    config.validate_critical_utilization()?;
+
+    let reserve = &mut ctx.accounts.reserve;
+    reserve.lending_market = ctx.accounts.lending_market.key();
+    reserve.last_update_slot = Clock::get()?.slot;
+    reserve.liquidity.mint = ctx.accounts.liquidity_mint.key();
+    reserve.liquidity.supply = ctx.accounts.liquidity_supply.key();
+    reserve.collateral.mint = ctx.accounts.collateral_mint.key();
+    reserve.config = config;
+    reserve.version = CURRENT_ACCOUNT_VERSION;
+
+    Ok(())
+}
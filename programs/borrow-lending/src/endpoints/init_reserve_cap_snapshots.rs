@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::{LendingMarket, Reserve, ReserveCapSnapshots, MAX_SNAPSHOT_CAPACITY};
+
+#[derive(Accounts)]
+#[instruction(capacity: u16)]
+pub struct InitReserveCapSnapshots<'info> {
+    #[account(has_one = owner)]
+    pub lending_market: Account<'info, LendingMarket>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = ReserveCapSnapshots::space_for(capacity),
+        seeds = [b"cap-snapshots", reserve.key().as_ref()],
+        bump,
+    )]
+    pub cap_snapshots: Account<'info, ReserveCapSnapshots>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens a reserve's snapshot ring buffer sized to `capacity` entries, so a
+/// reserve that doesn't care about history (or doesn't use emissions at
+/// all) isn't forced to pay rent for [`MAX_SNAPSHOT_CAPACITY`] of it.
+/// `snapshot_reserve` writes into this account when it's supplied.
+pub fn handle(ctx: Context<InitReserveCapSnapshots>, capacity: u16) -> Result<()> {
+    require!(capacity > 0, ErrorCode::SnapshotCapacityZero);
+    require!(capacity <= MAX_SNAPSHOT_CAPACITY, ErrorCode::SnapshotCapacityTooLarge);
+
+    ctx.accounts.cap_snapshots.init(ctx.accounts.reserve.key(), capacity);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::math::Decimal;
+use crate::models::{CreditLine, LendingMarket, Obligation};
+
+#[derive(Accounts)]
+pub struct IssueCreditLine<'info> {
+    #[account(has_one = owner)]
+    pub lending_market: Account<'info, LendingMarket>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(has_one = lending_market)]
+    pub obligation: Account<'info, Obligation>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = CreditLine::LEN,
+        seeds = [b"credit-line", obligation.key().as_ref()],
+        bump,
+    )]
+    pub credit_line: Account<'info, CreditLine>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Sets (or lowers back to zero to revoke) the UAC value of
+/// undercollateralized borrowing power `obligation` is granted. Meant for
+/// whitelisted institutional borrowers the market owner has off-chain
+/// recourse against — `refresh_obligation` must be called again afterward
+/// for the change to show up in the obligation's health figures.
+pub fn handle(ctx: Context<IssueCreditLine>, credit_value: Decimal) -> Result<()> {
+    let credit_line = &mut ctx.accounts.credit_line;
+    credit_line.lending_market = ctx.accounts.lending_market.key();
+    credit_line.obligation = ctx.accounts.obligation.key();
+    credit_line.credit_value = credit_value;
+
+    Ok(())
+}
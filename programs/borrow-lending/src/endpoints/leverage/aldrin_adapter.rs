@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+
+use crate::err::ErrorCode;
+
+use super::amm_adapter::{token_account_balance, AmmAdapter};
+
+/// Aldrin's AMM v2 program id on mainnet-beta.
+pub const ALDRIN_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("CURVGoZn8zycx6FXwwevgBTB2gVvdbGTEpvMJDbgs2t4");
+
+/// Anchor instruction sighash for Aldrin's `swap` (`sha256("global:swap")[..8]`).
+const SWAP_IX_DISCRIMINANT: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+
+/// Routes leverage-farming swaps through Aldrin's stableswap/AMM pools.
+/// `accounts` must be exactly the account list Aldrin's `swap` instruction
+/// expects, in order (pool, pool signer, vaults, fee account, user source
+/// and destination token accounts, user authority, token program), passed
+/// through verbatim from `ctx.remaining_accounts`.
+pub struct AldrinAdapter;
+
+impl<'info> AmmAdapter<'info> for AldrinAdapter {
+    fn swap(
+        &self,
+        accounts: &[AccountInfo<'info>],
+        destination: &AccountInfo<'info>,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<u64> {
+        let mut data = SWAP_IX_DISCRIMINANT.to_vec();
+        data.extend_from_slice(&amount_in.to_le_bytes());
+        data.extend_from_slice(&min_amount_out.to_le_bytes());
+
+        let metas = accounts
+            .iter()
+            .map(|account| {
+                if account.is_writable {
+                    AccountMeta::new(*account.key, account.is_signer)
+                } else {
+                    AccountMeta::new_readonly(*account.key, account.is_signer)
+                }
+            })
+            .collect();
+
+        let before = token_account_balance(destination)?;
+
+        invoke(
+            &Instruction {
+                program_id: ALDRIN_PROGRAM_ID,
+                accounts: metas,
+                data,
+            },
+            accounts,
+        )?;
+
+        let after = token_account_balance(destination)?;
+        let received = after.saturating_sub(before);
+        require!(received >= min_amount_out, ErrorCode::SlippageExceeded);
+
+        Ok(received)
+    }
+}
+
+impl AldrinAdapter {
+    /// Same as `swap`, but CPIs via `invoke_signed` so a PDA (rather than
+    /// a wallet signature) can authorize the source token account's
+    /// debit — needed for the keeper-callable
+    /// `reduce_leveraged_position_on_aldrin`, where the owner isn't part
+    /// of the transaction.
+    pub fn swap_signed<'info>(
+        &self,
+        accounts: &[AccountInfo<'info>],
+        destination: &AccountInfo<'info>,
+        amount_in: u64,
+        min_amount_out: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<u64> {
+        let mut data = SWAP_IX_DISCRIMINANT.to_vec();
+        data.extend_from_slice(&amount_in.to_le_bytes());
+        data.extend_from_slice(&min_amount_out.to_le_bytes());
+
+        let metas = accounts
+            .iter()
+            .map(|account| {
+                if account.is_writable {
+                    AccountMeta::new(*account.key, account.is_signer)
+                } else {
+                    AccountMeta::new_readonly(*account.key, account.is_signer)
+                }
+            })
+            .collect();
+
+        let before = token_account_balance(destination)?;
+
+        anchor_lang::solana_program::program::invoke_signed(
+            &Instruction {
+                program_id: ALDRIN_PROGRAM_ID,
+                accounts: metas,
+                data,
+            },
+            accounts,
+            signer_seeds,
+        )?;
+
+        let after = token_account_balance(destination)?;
+        let received = after.saturating_sub(before);
+        require!(received >= min_amount_out, ErrorCode::SlippageExceeded);
+
+        Ok(received)
+    }
+}
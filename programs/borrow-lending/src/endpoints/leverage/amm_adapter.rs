@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+
+/// Common swap interface for a single AMM venue: CPI the venue's swap and
+/// check the output by balance diff. `AldrinAdapter` and `OrcaAdapter`
+/// implement it. The leverage endpoints are written per venue
+/// (`*_on_aldrin`, plus `open_leveraged_position_via_jupiter`, which uses
+/// `jupiter_adapter` instead of this trait), and only the Aldrin ones call
+/// an adapter; the surrounding open/close logic is not shared between
+/// venues.
+///
+/// Implementors receive the venue-specific accounts as a plain slice
+/// (typically `ctx.remaining_accounts`) rather than a dedicated `Accounts`
+/// struct, since Anchor's `#[derive(Accounts)]` can't be made generic over
+/// venue — this mirrors how this program already threads pluggable
+/// per-reserve account sets through `remaining_accounts` elsewhere (see
+/// `withdraw_obligation_collateral`).
+pub trait AmmAdapter<'info> {
+    /// Swaps `amount_in` into `destination` through this venue's pool
+    /// using `accounts` (venue-specific, CPI'd through as-is), rejecting
+    /// the fill if `destination`'s balance didn't grow by at least
+    /// `min_amount_out`. Returns the amount actually received.
+    fn swap(
+        &self,
+        accounts: &[AccountInfo<'info>],
+        destination: &AccountInfo<'info>,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<u64>;
+}
+
+/// Reads an SPL Token account's `amount` field directly off its bytes
+/// (offset 64: 32-byte mint + 32-byte owner precede it), so adapters can
+/// measure a swap's actual output by balance diff without deserializing
+/// the whole account or fighting the borrow checker over a stale `Account<T>`
+/// snapshot taken before the CPI.
+pub(crate) fn token_account_balance(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    require!(data.len() >= 72, ErrorCode::MathOverflow);
+    Ok(u64::from_le_bytes(data[64..72].try_into().unwrap()))
+}
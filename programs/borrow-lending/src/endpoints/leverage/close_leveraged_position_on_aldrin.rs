@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{AmmVenue, LendingMarket, LeveragedPosition, Obligation, Reserve};
+
+use super::aldrin_adapter::AldrinAdapter;
+use super::amm_adapter::AmmAdapter;
+use super::slippage::oracle_min_out;
+
+#[derive(Accounts)]
+pub struct CloseLeveragedPositionOnAldrin<'info> {
+    #[account(seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market)]
+    pub debt_reserve: Account<'info, Reserve>,
+    #[account(has_one = lending_market)]
+    pub collateral_reserve: Account<'info, Reserve>,
+
+    /// Holds the position's staked collateral; drained by the unwind swap.
+    #[account(mut)]
+    pub staked_collateral: Account<'info, TokenAccount>,
+    /// Receives the swapped-back debt asset; any surplus over what's owed
+    /// is left here for the owner, the rest is forwarded to repay the debt.
+    #[account(mut)]
+    pub unwound_liquidity: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = obligation,
+        has_one = debt_reserve,
+        constraint = leveraged_position.amm == AmmVenue::Aldrin @ ErrorCode::WrongAmmVenue,
+        seeds = [b"leveraged-position", obligation.key().as_ref(), debt_reserve.key().as_ref()],
+        bump,
+    )]
+    pub leveraged_position: Account<'info, LeveragedPosition>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Closes a leveraged position: swaps the staked collateral back into
+/// `debt_reserve`'s liquidity through Aldrin and repays the debt.
+///
+/// Previously this kind of swap would pass `min_swap_return = 0`,
+/// accepting any fill — a sandwich attacker could drain most of the
+/// position's value on the way out. Instead the minimum acceptable
+/// return is computed from the two reserves' oracle prices and
+/// `max_slippage_bps`, same as the open-side check in
+/// `open_leveraged_position_via_jupiter` (see `slippage::oracle_min_out`),
+/// so liquidators closing positions on behalf of an at-risk borrower can't
+/// be forced into an off-market fill.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseLeveragedPositionOnAldrin<'info>>,
+    max_slippage_bps: u16,
+) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    ctx.accounts.debt_reserve.check_not_stale(slot)?;
+    ctx.accounts.collateral_reserve.check_not_stale(slot)?;
+
+    let staked_amount = ctx.accounts.leveraged_position.staked_lp_amount;
+    let debt_owed = ctx.accounts.leveraged_position.debt_amount.try_floor_u64()?;
+
+    let collateral_value = ctx
+        .accounts
+        .collateral_reserve
+        .market_value(Decimal::from(staked_amount))?;
+    let min_debt_out = oracle_min_out(
+        collateral_value,
+        ctx.accounts.debt_reserve.liquidity.market_price,
+        max_slippage_bps,
+    )?;
+
+    let received = AldrinAdapter.swap(
+        ctx.remaining_accounts,
+        &ctx.accounts.unwound_liquidity.to_account_info(),
+        staked_amount,
+        min_debt_out,
+    )?;
+
+    let repay_amount = received.min(debt_owed);
+    ctx.accounts.debt_reserve.liquidity.available_amount += repay_amount;
+    ctx.accounts.debt_reserve.liquidity.borrowed_amount = ctx
+        .accounts
+        .debt_reserve
+        .liquidity
+        .borrowed_amount
+        .try_sub(Decimal::from(repay_amount))?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.unwound_liquidity.to_account_info(),
+                to: ctx.accounts.reserve_liquidity_supply.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        repay_amount,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+
+use crate::err::ErrorCode;
+
+use super::amm_adapter::token_account_balance;
+
+/// Jupiter aggregator program id on mainnet-beta.
+pub const JUPITER_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+
+/// CPIs into Jupiter with a caller-supplied route. Unlike `AmmAdapter`
+/// (one pool, one fixed instruction layout), a Jupiter route can hop
+/// through any number of pools with an instruction encoding that varies
+/// per-route, so the caller (which builds the route off-chain via Jupiter's
+/// quote/swap API) supplies the account list and instruction
+/// data verbatim; this just CPIs it and enforces the output floor by
+/// balance diff, same as `AmmAdapter::swap`.
+pub fn swap_via_jupiter<'info>(
+    jupiter_program: &AccountInfo<'info>,
+    route_accounts: &[AccountInfo<'info>],
+    data: Vec<u8>,
+    destination: &AccountInfo<'info>,
+    min_amount_out: u64,
+) -> Result<u64> {
+    require_keys_eq!(*jupiter_program.key, JUPITER_PROGRAM_ID, ErrorCode::WrongAmmVenue);
+
+    let metas = route_accounts
+        .iter()
+        .map(|account| {
+            if account.is_writable {
+                AccountMeta::new(*account.key, account.is_signer)
+            } else {
+                AccountMeta::new_readonly(*account.key, account.is_signer)
+            }
+        })
+        .collect();
+
+    let before = token_account_balance(destination)?;
+
+    invoke(
+        &Instruction {
+            program_id: JUPITER_PROGRAM_ID,
+            accounts: metas,
+            data,
+        },
+        route_accounts,
+    )?;
+
+    let after = token_account_balance(destination)?;
+    let received = after.saturating_sub(before);
+    require!(received >= min_amount_out, ErrorCode::SlippageExceeded);
+
+    Ok(received)
+}
@@ -0,0 +1,10 @@
+pub mod aldrin_adapter;
+pub mod amm_adapter;
+pub mod close_leveraged_position_on_aldrin;
+pub mod jupiter_adapter;
+pub mod open_leveraged_position_on_aldrin;
+pub mod open_leveraged_position_via_jupiter;
+pub mod orca_adapter;
+pub mod reduce_leveraged_position_on_aldrin;
+pub mod refresh_leveraged_position_value;
+pub mod slippage;
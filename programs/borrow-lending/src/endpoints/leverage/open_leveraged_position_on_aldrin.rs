@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{AmmVenue, LendingMarket, LeveragedPosition, Obligation, Reserve};
+
+use super::aldrin_adapter::AldrinAdapter;
+use super::amm_adapter::AmmAdapter;
+
+#[derive(Accounts)]
+pub struct OpenLeveragedPositionOnAldrin<'info> {
+    #[account(seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market)]
+    pub debt_reserve: Account<'info, Reserve>,
+    #[account(has_one = lending_market)]
+    pub collateral_reserve: Account<'info, Reserve>,
+
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+    /// Owner-controlled account the borrowed liquidity is transferred into
+    /// before being swapped; doubles as the `AldrinAdapter` swap's source.
+    #[account(mut)]
+    pub borrowed_liquidity: Account<'info, TokenAccount>,
+    /// Where the swapped-to collateral lands and is tracked as staked by
+    /// `leveraged_position`.
+    #[account(mut)]
+    pub staked_collateral: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = LeveragedPosition::LEN,
+        seeds = [b"leveraged-position", obligation.key().as_ref(), debt_reserve.key().as_ref()],
+        bump,
+    )]
+    pub leveraged_position: Account<'info, LeveragedPosition>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens a leveraged yield-farming position: borrows `borrow_amount` of
+/// `debt_reserve`'s liquidity, swaps it into `collateral_reserve`'s
+/// underlying asset through Aldrin (`ctx.remaining_accounts` must be
+/// Aldrin's `swap` account list, see `AldrinAdapter`), and records the
+/// result in a fresh `leveraged_position` account.
+///
+/// The borrowed amount is debited against `debt_reserve.liquidity` the
+/// same way `borrow_obligation_liquidity` does, but is tracked on
+/// `leveraged_position` rather than appended to `obligation.borrows` —
+/// health-factor calculations don't yet see leveraged debt. Until that's
+/// wired up (tracked for a follow-up request), treat leveraged positions
+/// as isolated from the obligation's regular borrow limit.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, OpenLeveragedPositionOnAldrin<'info>>,
+    borrow_amount: u64,
+    min_collateral_out: u64,
+) -> Result<()> {
+    crate::telemetry::checkpoint("open_leveraged_position:start");
+    let slot = Clock::get()?.slot;
+    ctx.accounts.obligation.check_not_stale(slot)?;
+    ctx.accounts.debt_reserve.check_not_retiring()?;
+    ctx.accounts.debt_reserve.check_not_frozen()?;
+    ctx.accounts.debt_reserve.check_borrowing_enabled()?;
+    require!(
+        ctx.accounts.debt_reserve.liquidity.available_amount >= borrow_amount,
+        ErrorCode::BorrowTooLarge
+    );
+
+    ctx.accounts.debt_reserve.liquidity.available_amount -= borrow_amount;
+    ctx.accounts.debt_reserve.liquidity.borrowed_amount = ctx
+        .accounts
+        .debt_reserve
+        .liquidity
+        .borrowed_amount
+        .try_add(Decimal::from(borrow_amount))?;
+
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reserve_liquidity_supply.to_account_info(),
+                to: ctx.accounts.borrowed_liquidity.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        borrow_amount,
+    )?;
+
+    let received = AldrinAdapter.swap(
+        ctx.remaining_accounts,
+        &ctx.accounts.staked_collateral.to_account_info(),
+        borrow_amount,
+        min_collateral_out,
+    )?;
+
+    let position = &mut ctx.accounts.leveraged_position;
+    position.obligation = ctx.accounts.obligation.key();
+    position.collateral_reserve = ctx.accounts.collateral_reserve.key();
+    position.debt_reserve = ctx.accounts.debt_reserve.key();
+    position.amm = AmmVenue::Aldrin;
+    position.staked_lp_amount = received;
+    position.debt_amount = Decimal::from(borrow_amount);
+    position.opened_at_slot = slot;
+
+    crate::telemetry::checkpoint("open_leveraged_position:end");
+    Ok(())
+}
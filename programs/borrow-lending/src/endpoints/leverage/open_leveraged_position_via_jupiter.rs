@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{AmmVenue, LendingMarket, LeveragedPosition, Obligation, Reserve};
+
+use super::jupiter_adapter::{self, JUPITER_PROGRAM_ID};
+use super::slippage::oracle_min_out;
+
+#[derive(Accounts)]
+pub struct OpenLeveragedPositionViaJupiter<'info> {
+    #[account(seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market)]
+    pub debt_reserve: Account<'info, Reserve>,
+    #[account(has_one = lending_market)]
+    pub collateral_reserve: Account<'info, Reserve>,
+
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+    /// Owner-controlled account the borrowed liquidity is transferred into
+    /// before being swapped; doubles as the Jupiter route's source.
+    #[account(mut)]
+    pub borrowed_liquidity: Account<'info, TokenAccount>,
+    /// Where the swapped-to collateral lands and is tracked as staked by
+    /// `leveraged_position`.
+    #[account(mut)]
+    pub staked_collateral: Account<'info, TokenAccount>,
+
+    #[account(address = JUPITER_PROGRAM_ID)]
+    /// CHECK: only used as the CPI target; address-constrained above.
+    pub jupiter_program: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = LeveragedPosition::LEN,
+        seeds = [b"leveraged-position", obligation.key().as_ref(), debt_reserve.key().as_ref()],
+        bump,
+    )]
+    pub leveraged_position: Account<'info, LeveragedPosition>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Same as `open_leveraged_position_on_aldrin`, but routes the swap leg
+/// through Jupiter (`jupiter_ix_data` and `ctx.remaining_accounts` are a
+/// route the caller built off-chain via Jupiter's quote/swap API) instead of
+/// a single Aldrin pool, so larger positions aren't limited to one pool's
+/// depth. Slippage is enforced on-chain against the two reserves' oracle
+/// prices rather than trusting the caller's quote: the swap must return at
+/// least the oracle-implied amount reduced by `max_slippage_bps`,
+/// regardless of what Jupiter's quote promised.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, OpenLeveragedPositionViaJupiter<'info>>,
+    borrow_amount: u64,
+    jupiter_ix_data: Vec<u8>,
+    max_slippage_bps: u16,
+) -> Result<()> {
+    crate::telemetry::checkpoint("open_leveraged_position:start");
+    let slot = Clock::get()?.slot;
+    ctx.accounts.obligation.check_not_stale(slot)?;
+    ctx.accounts.debt_reserve.check_not_retiring()?;
+    ctx.accounts.debt_reserve.check_not_frozen()?;
+    ctx.accounts.debt_reserve.check_borrowing_enabled()?;
+    ctx.accounts.debt_reserve.check_not_stale(slot)?;
+    ctx.accounts.collateral_reserve.check_not_stale(slot)?;
+    require!(
+        ctx.accounts.debt_reserve.liquidity.available_amount >= borrow_amount,
+        ErrorCode::BorrowTooLarge
+    );
+    let debt_value = ctx.accounts.debt_reserve.market_value(Decimal::from(borrow_amount))?;
+    let min_collateral_out = oracle_min_out(
+        debt_value,
+        ctx.accounts.collateral_reserve.liquidity.market_price,
+        max_slippage_bps,
+    )?;
+
+    ctx.accounts.debt_reserve.liquidity.available_amount -= borrow_amount;
+    ctx.accounts.debt_reserve.liquidity.borrowed_amount = ctx
+        .accounts
+        .debt_reserve
+        .liquidity
+        .borrowed_amount
+        .try_add(Decimal::from(borrow_amount))?;
+
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reserve_liquidity_supply.to_account_info(),
+                to: ctx.accounts.borrowed_liquidity.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        borrow_amount,
+    )?;
+
+    let received = jupiter_adapter::swap_via_jupiter(
+        &ctx.accounts.jupiter_program.to_account_info(),
+        ctx.remaining_accounts,
+        jupiter_ix_data,
+        &ctx.accounts.staked_collateral.to_account_info(),
+        min_collateral_out,
+    )?;
+
+    let position = &mut ctx.accounts.leveraged_position;
+    position.obligation = ctx.accounts.obligation.key();
+    position.collateral_reserve = ctx.accounts.collateral_reserve.key();
+    position.debt_reserve = ctx.accounts.debt_reserve.key();
+    position.amm = AmmVenue::Jupiter;
+    position.staked_lp_amount = received;
+    position.debt_amount = Decimal::from(borrow_amount);
+    position.opened_at_slot = slot;
+
+    crate::telemetry::checkpoint("open_leveraged_position:end");
+    Ok(())
+}
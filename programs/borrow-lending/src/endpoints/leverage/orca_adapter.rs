@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+
+use crate::err::ErrorCode;
+
+use super::amm_adapter::{token_account_balance, AmmAdapter};
+
+/// Orca Whirlpools program id on mainnet-beta.
+pub const ORCA_WHIRLPOOL_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
+/// Anchor instruction sighash for Whirlpools' `swap` (`sha256("global:swap")[..8]`).
+const SWAP_IX_DISCRIMINANT: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+
+/// Routes a swap through an Orca Whirlpool behind the `AmmAdapter`
+/// interface. No endpoint calls it: the `*_on_aldrin` leverage endpoints
+/// swap through `AldrinAdapter` and `open_leveraged_position_via_jupiter`
+/// through `jupiter_adapter`, so no leveraged position is opened or unwound
+/// through Orca. `accounts` must be exactly the
+/// account list Whirlpools' `swap` instruction expects, in order (whirlpool,
+/// token vault A/B, tick arrays, oracle, user source and destination token
+/// accounts, user authority, token program), passed through verbatim from
+/// `ctx.remaining_accounts`.
+pub struct OrcaAdapter;
+
+impl<'info> AmmAdapter<'info> for OrcaAdapter {
+    fn swap(
+        &self,
+        accounts: &[AccountInfo<'info>],
+        destination: &AccountInfo<'info>,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<u64> {
+        let mut data = SWAP_IX_DISCRIMINANT.to_vec();
+        data.extend_from_slice(&amount_in.to_le_bytes());
+        data.extend_from_slice(&min_amount_out.to_le_bytes());
+
+        let metas = accounts
+            .iter()
+            .map(|account| {
+                if account.is_writable {
+                    AccountMeta::new(*account.key, account.is_signer)
+                } else {
+                    AccountMeta::new_readonly(*account.key, account.is_signer)
+                }
+            })
+            .collect();
+
+        let before = token_account_balance(destination)?;
+
+        invoke(
+            &Instruction {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                accounts: metas,
+                data,
+            },
+            accounts,
+        )?;
+
+        let after = token_account_balance(destination)?;
+        let received = after.saturating_sub(before);
+        require!(received >= min_amount_out, ErrorCode::SlippageExceeded);
+
+        Ok(received)
+    }
+}
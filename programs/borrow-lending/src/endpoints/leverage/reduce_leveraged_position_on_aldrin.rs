@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{AmmVenue, LendingMarket, LeveragedPosition, Obligation, Reserve};
+
+use super::aldrin_adapter::AldrinAdapter;
+use super::slippage::oracle_min_out;
+
+#[derive(Accounts)]
+pub struct ReduceLeveragedPositionOnAldrin<'info> {
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    pub obligation: Account<'info, Obligation>,
+
+    #[account(mut, has_one = lending_market)]
+    pub debt_reserve: Account<'info, Reserve>,
+    #[account(has_one = lending_market)]
+    pub collateral_reserve: Account<'info, Reserve>,
+
+    /// Holds the position's staked collateral. Owned by the lending
+    /// market PDA (not the obligation owner), since this endpoint is
+    /// keeper-callable and the owner isn't part of the transaction.
+    #[account(mut)]
+    pub staked_collateral: Account<'info, TokenAccount>,
+    /// Receives the unstaked-and-swapped-back debt asset before it's
+    /// forwarded to repay the reserve.
+    #[account(mut)]
+    pub unwound_liquidity: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        has_one = obligation,
+        has_one = debt_reserve,
+        constraint = leveraged_position.amm == AmmVenue::Aldrin @ ErrorCode::WrongAmmVenue,
+        seeds = [b"leveraged-position", obligation.key().as_ref(), debt_reserve.key().as_ref()],
+        bump,
+    )]
+    pub leveraged_position: Account<'info, LeveragedPosition>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Keeper-callable: partially unstakes and repays just enough of a
+/// leveraged position to bring its loan-to-value back below
+/// `collateral_reserve.config.liquidation_threshold` minus
+/// `health_buffer_bps`, instead of `close_leveraged_position_on_aldrin`'s
+/// all-or-nothing unwind. Lets farmers ride out volatility that would
+/// otherwise force a full close.
+///
+/// Anyone can call this — unlike `close_leveraged_position_on_aldrin`,
+/// there's no owner signer — but it only does anything once the position
+/// has actually drifted inside the buffer; `unstake_amount` is caller
+/// (keeper) supplied since computing the exact amount needed to land
+/// back on the buffer, net of the swap's own price impact, isn't solvable
+/// from on-chain state alone. `max_slippage_bps` still bounds the unwind
+/// swap against the two reserves' oracle prices (`slippage::oracle_min_out`),
+/// so a keeper can't use this to force a bad fill either.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReduceLeveragedPositionOnAldrin<'info>>,
+    unstake_amount: u64,
+    max_slippage_bps: u16,
+    health_buffer_bps: u16,
+) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    ctx.accounts.debt_reserve.check_not_stale(slot)?;
+    ctx.accounts.collateral_reserve.check_not_stale(slot)?;
+
+    let position = &ctx.accounts.leveraged_position;
+    require!(unstake_amount <= position.staked_lp_amount, ErrorCode::ReduceTooLarge);
+
+    let collateral_value = ctx
+        .accounts
+        .collateral_reserve
+        .market_value(Decimal::from(position.staked_lp_amount))?;
+    let debt_value = ctx.accounts.debt_reserve.market_value(position.debt_amount)?;
+
+    let liquidation_threshold_bps = (ctx.accounts.collateral_reserve.config.liquidation_threshold as u64) * 100;
+    let safe_ltv_bps = liquidation_threshold_bps.saturating_sub(health_buffer_bps as u64);
+    let current_ltv_bps = debt_value.try_mul(Decimal::from(10_000u64))?.try_div(collateral_value)?.try_floor_u64()?;
+    require!(current_ltv_bps > safe_ltv_bps, ErrorCode::PositionWithinHealthBuffer);
+
+    let unstake_value = ctx
+        .accounts
+        .collateral_reserve
+        .market_value(Decimal::from(unstake_amount))?;
+    let min_debt_out = oracle_min_out(
+        unstake_value,
+        ctx.accounts.debt_reserve.liquidity.market_price,
+        max_slippage_bps,
+    )?;
+
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+    let received = AldrinAdapter.swap_signed(
+        ctx.remaining_accounts,
+        &ctx.accounts.unwound_liquidity.to_account_info(),
+        unstake_amount,
+        min_debt_out,
+        &[seeds],
+    )?;
+
+    let debt_owed = ctx.accounts.leveraged_position.debt_amount.try_floor_u64()?;
+    let repay_amount = received.min(debt_owed);
+
+    ctx.accounts.debt_reserve.liquidity.available_amount += repay_amount;
+    ctx.accounts.debt_reserve.liquidity.borrowed_amount = ctx
+        .accounts
+        .debt_reserve
+        .liquidity
+        .borrowed_amount
+        .try_sub(Decimal::from(repay_amount))?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.unwound_liquidity.to_account_info(),
+                to: ctx.accounts.reserve_liquidity_supply.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        repay_amount,
+    )?;
+
+    let position = &mut ctx.accounts.leveraged_position;
+    position.staked_lp_amount -= unstake_amount;
+    position.debt_amount = position.debt_amount.try_sub(Decimal::from(repay_amount))?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::math::Decimal;
+use crate::models::{LeveragedPosition, Reserve};
+
+#[derive(Accounts)]
+pub struct RefreshLeveragedPositionValue<'info> {
+    pub debt_reserve: Account<'info, Reserve>,
+    pub collateral_reserve: Account<'info, Reserve>,
+
+    #[account(mut, has_one = debt_reserve, has_one = collateral_reserve)]
+    pub leveraged_position: Account<'info, LeveragedPosition>,
+}
+
+/// Permissionless: prices `leveraged_position.staked_lp_amount` at
+/// `collateral_reserve`'s current oracle price and caches the result as
+/// `collateral_value`, so a position's unrealized PnL (`collateral_value`
+/// against `debt_amount`'s own value) is readable on-chain without a bot
+/// re-deriving it off a pool account.
+///
+/// Despite the field name, `staked_lp_amount` is single-asset collateral
+/// (the output of the opening swap), not a two-sided pool's LP token — see
+/// `open_leveraged_position_on_aldrin`/`_via_jupiter` — so no separate pool
+/// vault read is needed here, just `collateral_reserve`'s already-refreshed
+/// price.
+///
+/// Both reserves must already be refreshed this slot (see
+/// `refresh_reserve`).
+pub fn handle(ctx: Context<RefreshLeveragedPositionValue>) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    ctx.accounts.collateral_reserve.check_not_stale(slot)?;
+    ctx.accounts.debt_reserve.check_not_stale(slot)?;
+
+    let position = &mut ctx.accounts.leveraged_position;
+    position.collateral_value = ctx
+        .accounts
+        .collateral_reserve
+        .market_value(Decimal::from(position.staked_lp_amount))?;
+    position.last_valued_slot = slot;
+
+    Ok(())
+}
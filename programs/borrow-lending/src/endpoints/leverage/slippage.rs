@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+
+/// The minimum acceptable output amount for a swap worth `value_in` (UAC)
+/// into an asset priced at `price_out` (UAC per unit), allowing at most
+/// `max_slippage_bps` of drift from the oracle-implied exchange rate.
+/// Used to bound leverage-farming swaps by the reserves' own oracles
+/// instead of trusting whatever quote the caller (or a venue's AMM curve
+/// under sandwich pressure) produced.
+pub fn oracle_min_out(value_in: Decimal, price_out: Decimal, max_slippage_bps: u16) -> Result<u64> {
+    require!(max_slippage_bps <= 10_000, ErrorCode::SlippageExceeded);
+
+    let expected_out = value_in.try_div(price_out)?;
+    let slippage_factor = Decimal::from_fraction((10_000 - max_slippage_bps) as u128, 10_000)?;
+    expected_out.try_mul(slippage_factor)?.try_floor_u64()
+}
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{LendingMarket, LiquidationAmounts, Obligation, Reserve};
+
+#[derive(Accounts)]
+pub struct LiquidateObligation<'info> {
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut)]
+    pub obligation: Account<'info, Obligation>,
+
+    #[account(mut)]
+    pub repay_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub withdraw_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub reserve_collateral_supply: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub source_liquidity: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_collateral: Account<'info, TokenAccount>,
+    pub liquidator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Repays up to `liquidity_amount` of `repay_reserve`'s borrow against an
+/// unhealthy obligation, seizing a proportional amount of its
+/// `withdraw_reserve` collateral in return. The collateral bonus paid out
+/// is not flat — it scales with
+/// [`Obligation::calculate_liquidation_amounts`]'s breach-aware schedule,
+/// so a position that just crossed `unhealthy_borrow_value` pays a gentler
+/// bonus than one that's been left deeply underwater.
+///
+/// The obligation must already be refreshed this slot (see
+/// `refresh_obligation`) and must actually be liquidatable.
+pub fn handle(ctx: Context<LiquidateObligation>, liquidity_amount: u64) -> Result<()> {
+    crate::telemetry::checkpoint("liquidate_obligation:start");
+    let slot = Clock::get()?.slot;
+
+    let lending_market_owner = ctx.accounts.lending_market.owner;
+    let lending_market_bump_seed = ctx.accounts.lending_market.bump_seed;
+    let seeds: &[&[u8]] = &[b"lending-market", lending_market_owner.as_ref(), &[lending_market_bump_seed]];
+    liquidate(
+        &mut ctx.accounts.lending_market,
+        &mut ctx.accounts.obligation,
+        &mut ctx.accounts.repay_reserve,
+        &mut ctx.accounts.withdraw_reserve,
+        ctx.accounts.source_liquidity.to_account_info(),
+        ctx.accounts.reserve_liquidity_supply.to_account_info(),
+        ctx.accounts.reserve_collateral_supply.to_account_info(),
+        ctx.accounts.destination_collateral.to_account_info(),
+        ctx.accounts.liquidator.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        seeds,
+        liquidity_amount,
+        slot,
+    )?;
+
+    crate::telemetry::checkpoint("liquidate_obligation:end");
+    Ok(())
+}
+
+/// Shared by [`handle`] and `liquidate_obligation_and_redeem`, which only
+/// differs in what happens to the seized collateral once it lands in
+/// `destination_collateral` — left as-is here, immediately redeemed for
+/// its underlying liquidity there.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn liquidate<'info>(
+    lending_market: &mut Account<'info, LendingMarket>,
+    obligation: &mut Account<'info, Obligation>,
+    repay_reserve: &mut Account<'info, Reserve>,
+    withdraw_reserve: &mut Account<'info, Reserve>,
+    source_liquidity: AccountInfo<'info>,
+    reserve_liquidity_supply: AccountInfo<'info>,
+    reserve_collateral_supply: AccountInfo<'info>,
+    destination_collateral: AccountInfo<'info>,
+    liquidator: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    lending_market_seeds: &[&[u8]],
+    liquidity_amount: u64,
+    current_slot: u64,
+) -> Result<LiquidationAmounts> {
+    obligation.check_not_stale(current_slot)?;
+    require!(obligation.is_liquidatable(current_slot), ErrorCode::ObligationHealthy);
+
+    repay_reserve.check_not_stale(current_slot)?;
+    withdraw_reserve.check_not_stale(current_slot)?;
+    require!(!repay_reserve.in_liquidation_grace_period(current_slot), ErrorCode::LiquidationGracePeriod);
+    require!(!withdraw_reserve.in_liquidation_grace_period(current_slot), ErrorCode::LiquidationGracePeriod);
+
+    let repay_reserve_key = repay_reserve.key();
+    let withdraw_reserve_key = withdraw_reserve.key();
+    let amounts = obligation.calculate_liquidation_amounts(
+        repay_reserve,
+        repay_reserve_key,
+        withdraw_reserve,
+        withdraw_reserve_key,
+        liquidity_amount,
+    )?;
+
+    let borrow_index = obligation
+        .borrows
+        .iter()
+        .position(|b| b.borrow_reserve == repay_reserve_key)
+        .ok_or(ErrorCode::ObligationBorrowsEmpty)?;
+    let repay_decimal = Decimal::from(amounts.repay_amount);
+    let owed = obligation.borrows[borrow_index].borrowed_amount;
+    let repaid_fraction = repay_decimal.try_div(owed)?;
+    let repaid_value = obligation.borrows[borrow_index].market_value.try_mul(repaid_fraction)?;
+    obligation.borrows[borrow_index].borrowed_amount = owed.try_sub(repay_decimal)?;
+    obligation.borrows[borrow_index].market_value =
+        obligation.borrows[borrow_index].market_value.try_sub(repaid_value)?;
+    obligation.borrowed_value = obligation.borrowed_value.try_sub(repaid_value)?;
+    lending_market.decrease_total_borrow_value(repaid_value);
+    if obligation.borrows[borrow_index].borrowed_amount.to_scaled_val() == 0 {
+        obligation.borrows.remove(borrow_index);
+    }
+
+    let deposit_index = obligation
+        .deposits
+        .iter()
+        .position(|d| d.deposit_reserve == withdraw_reserve.key())
+        .ok_or(ErrorCode::ObligationDepositsEmpty)?;
+    let deposit = &mut obligation.deposits[deposit_index];
+    let seized_value = withdraw_reserve.market_value(Decimal::from(
+        withdraw_reserve.collateral_to_liquidity(amounts.withdraw_collateral_amount)?,
+    ))?;
+    let deposit_remaining = deposit.deposited_amount - amounts.withdraw_collateral_amount;
+    deposit.rebase_rewards(deposit_remaining, withdraw_reserve.liquidity.cumulative_reward_per_share)?;
+    deposit.market_value = deposit.market_value.try_sub(seized_value).unwrap_or_else(|_| Decimal::zero());
+    deposit.cost_basis_liquidity = Decimal::from(withdraw_reserve.collateral_to_liquidity(deposit_remaining)?);
+    obligation.deposited_value = obligation.deposited_value.try_sub(seized_value).unwrap_or_else(|_| Decimal::zero());
+    if obligation.deposits[deposit_index].deposited_amount == 0 {
+        obligation.deposits.remove(deposit_index);
+    }
+
+    repay_reserve.liquidity.available_amount = repay_reserve
+        .liquidity
+        .available_amount
+        .checked_add(amounts.repay_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    repay_reserve.liquidity.borrowed_amount = repay_reserve.liquidity.borrowed_amount.try_sub(repay_decimal)?;
+
+    token::transfer(
+        CpiContext::new(
+            token_program.clone(),
+            Transfer {
+                from: source_liquidity,
+                to: reserve_liquidity_supply,
+                authority: liquidator,
+            },
+        ),
+        amounts.repay_amount,
+    )?;
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program,
+            Transfer {
+                from: reserve_collateral_supply,
+                to: destination_collateral,
+                authority: lending_market.to_account_info(),
+            },
+            &[lending_market_seeds],
+        ),
+        amounts.withdraw_collateral_amount,
+    )?;
+
+    Ok(amounts)
+}
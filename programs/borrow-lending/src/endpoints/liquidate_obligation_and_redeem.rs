@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::endpoints::liquidate_obligation::liquidate;
+use crate::endpoints::redeem_reserve_collateral::redeem_collateral;
+use crate::models::{LendingMarket, Obligation, Reserve};
+
+#[derive(Accounts)]
+pub struct LiquidateObligationAndRedeem<'info> {
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut)]
+    pub obligation: Account<'info, Obligation>,
+
+    #[account(mut)]
+    pub repay_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub withdraw_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub reserve_collateral_supply: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub withdraw_reserve_collateral_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub withdraw_reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub source_liquidity: Account<'info, TokenAccount>,
+    /// Liquidator-owned cToken account the seized collateral transits
+    /// through on its way to being burned; never holds a balance once
+    /// this instruction returns.
+    #[account(mut)]
+    pub scratch_collateral: Account<'info, TokenAccount>,
+    /// Where the redeemed underlying liquidity lands.
+    #[account(mut)]
+    pub destination_liquidity: Account<'info, TokenAccount>,
+    pub liquidator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Same as `liquidate_obligation`, except the seized collateral is
+/// immediately redeemed for its underlying liquidity instead of being left
+/// as cTokens in the liquidator's wallet — the most common thing a
+/// liquidator does with it anyway, folded into one instruction.
+pub fn handle(ctx: Context<LiquidateObligationAndRedeem>, liquidity_amount: u64) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    liquidate_and_redeem(
+        &mut ctx.accounts.lending_market,
+        &mut ctx.accounts.obligation,
+        &mut ctx.accounts.repay_reserve,
+        &mut ctx.accounts.withdraw_reserve,
+        ctx.accounts.source_liquidity.to_account_info(),
+        ctx.accounts.reserve_liquidity_supply.to_account_info(),
+        ctx.accounts.reserve_collateral_supply.to_account_info(),
+        ctx.accounts.scratch_collateral.to_account_info(),
+        ctx.accounts.withdraw_reserve_collateral_mint.to_account_info(),
+        ctx.accounts.withdraw_reserve_liquidity_supply.to_account_info(),
+        ctx.accounts.destination_liquidity.to_account_info(),
+        ctx.accounts.liquidator.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        liquidity_amount,
+        slot,
+    )
+}
+
+/// Shared by [`handle`] and `liquidate_obligation_and_redeem_sol`, which
+/// only differs in where `destination_liquidity` ends up (a pre-existing
+/// account vs. a temporary wSOL one that gets unwrapped to native SOL).
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn liquidate_and_redeem<'info>(
+    lending_market: &mut Account<'info, LendingMarket>,
+    obligation: &mut Account<'info, Obligation>,
+    repay_reserve: &mut Account<'info, Reserve>,
+    withdraw_reserve: &mut Account<'info, Reserve>,
+    source_liquidity: AccountInfo<'info>,
+    reserve_liquidity_supply: AccountInfo<'info>,
+    reserve_collateral_supply: AccountInfo<'info>,
+    scratch_collateral: AccountInfo<'info>,
+    withdraw_reserve_collateral_mint: AccountInfo<'info>,
+    withdraw_reserve_liquidity_supply: AccountInfo<'info>,
+    destination_liquidity: AccountInfo<'info>,
+    liquidator: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    liquidity_amount: u64,
+    current_slot: u64,
+) -> Result<()> {
+    let lending_market_owner = lending_market.owner;
+    let lending_market_bump_seed = lending_market.bump_seed;
+    let seeds: &[&[u8]] = &[b"lending-market", lending_market_owner.as_ref(), &[lending_market_bump_seed]];
+
+    let amounts = liquidate(
+        lending_market,
+        obligation,
+        repay_reserve,
+        withdraw_reserve,
+        source_liquidity,
+        reserve_liquidity_supply,
+        reserve_collateral_supply,
+        scratch_collateral.clone(),
+        liquidator.clone(),
+        token_program.clone(),
+        seeds,
+        liquidity_amount,
+        current_slot,
+    )?;
+
+    redeem_collateral(
+        lending_market,
+        withdraw_reserve,
+        scratch_collateral,
+        liquidator,
+        withdraw_reserve_collateral_mint,
+        withdraw_reserve_liquidity_supply,
+        destination_liquidity,
+        token_program,
+        amounts.withdraw_collateral_amount,
+        current_slot,
+    )
+}
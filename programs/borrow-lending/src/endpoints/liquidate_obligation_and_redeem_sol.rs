@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, spl_token, Mint, Token, TokenAccount};
+
+use crate::endpoints::liquidate_obligation_and_redeem::liquidate_and_redeem;
+use crate::models::{LendingMarket, Obligation, Reserve};
+
+#[derive(Accounts)]
+pub struct LiquidateObligationAndRedeemSol<'info> {
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut)]
+    pub obligation: Account<'info, Obligation>,
+
+    #[account(mut)]
+    pub repay_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub withdraw_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub reserve_collateral_supply: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub withdraw_reserve_collateral_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub withdraw_reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub source_liquidity: Account<'info, TokenAccount>,
+    /// Liquidator-owned cToken account the seized collateral transits
+    /// through on its way to being burned; never holds a balance once
+    /// this instruction returns.
+    #[account(mut)]
+    pub scratch_collateral: Account<'info, TokenAccount>,
+
+    /// Ephemeral wSOL account the redeemed liquidity lands in before this
+    /// instruction closes it, unwrapping it straight to native SOL in the
+    /// liquidator's own account.
+    #[account(
+        init,
+        payer = liquidator,
+        seeds = [b"tmp-wsol", liquidator.key().as_ref()],
+        bump,
+        token::mint = native_mint,
+        token::authority = liquidator,
+    )]
+    pub temp_wsol: Account<'info, TokenAccount>,
+    #[account(address = spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub liquidator: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// SOL convenience wrapper around `liquidate_obligation_and_redeem`: the
+/// seized collateral is redeemed into a temporary wSOL account, then
+/// immediately closed so the liquidator receives native SOL rather than
+/// having to unwrap it themselves — smoothing the single most common
+/// liquidation path, liquidating into a SOL reserve.
+pub fn handle(ctx: Context<LiquidateObligationAndRedeemSol>, liquidity_amount: u64) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    liquidate_and_redeem(
+        &mut ctx.accounts.lending_market,
+        &mut ctx.accounts.obligation,
+        &mut ctx.accounts.repay_reserve,
+        &mut ctx.accounts.withdraw_reserve,
+        ctx.accounts.source_liquidity.to_account_info(),
+        ctx.accounts.reserve_liquidity_supply.to_account_info(),
+        ctx.accounts.reserve_collateral_supply.to_account_info(),
+        ctx.accounts.scratch_collateral.to_account_info(),
+        ctx.accounts.withdraw_reserve_collateral_mint.to_account_info(),
+        ctx.accounts.withdraw_reserve_liquidity_supply.to_account_info(),
+        ctx.accounts.temp_wsol.to_account_info(),
+        ctx.accounts.liquidator.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        liquidity_amount,
+        slot,
+    )?;
+
+    token::close_account(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.temp_wsol.to_account_info(),
+            destination: ctx.accounts.liquidator.to_account_info(),
+            authority: ctx.accounts.liquidator.to_account_info(),
+        },
+    ))?;
+
+    Ok(())
+}
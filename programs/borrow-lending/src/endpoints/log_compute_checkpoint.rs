@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct LogComputeCheckpoint {}
+
+/// Dev-only: logs `label` and the compute units remaining when
+/// `cu-telemetry` is enabled, so a test harness can sprinkle calls to this
+/// between client-side steps of a multi-instruction flow (e.g. around
+/// `refresh_obligation` then `liquidate_obligation` in the same
+/// transaction) and read the deltas back out of the logs. A no-op without
+/// the feature, so it's harmless to leave calls to it in a test suite that
+/// runs against both feature configurations.
+pub fn handle(_ctx: Context<LogComputeCheckpoint>, label: String) -> Result<()> {
+    crate::telemetry::checkpoint(&label);
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::{LendingMarket, CURRENT_ACCOUNT_VERSION};
+
+#[derive(Accounts)]
+pub struct MigrateLendingMarket<'info> {
+    #[account(mut)]
+    pub lending_market: Account<'info, LendingMarket>,
+}
+
+/// Permissionless: bumps an account that predates `CURRENT_ACCOUNT_VERSION`
+/// up to it. Safe for anyone to call because there's no value-bearing state
+/// to reinterpret yet, just the version stamp itself; once a real layout
+/// change ships behind a version bump, add its migration step here ahead of
+/// the stamp update.
+pub fn handle(ctx: Context<MigrateLendingMarket>) -> Result<()> {
+    let market = &mut ctx.accounts.lending_market;
+    require!(market.version < CURRENT_ACCOUNT_VERSION, ErrorCode::AccountAlreadyUpToDate);
+
+    market.version = CURRENT_ACCOUNT_VERSION;
+
+    Ok(())
+}
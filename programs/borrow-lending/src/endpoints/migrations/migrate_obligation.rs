@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::{Obligation, CURRENT_ACCOUNT_VERSION};
+
+#[derive(Accounts)]
+pub struct MigrateObligation<'info> {
+    #[account(mut)]
+    pub obligation: Account<'info, Obligation>,
+}
+
+/// See `migrate_lending_market` for the rationale: permissionless version
+/// stamp bump, with room for real field migrations ahead of the stamp
+/// update once a layout change actually needs one.
+pub fn handle(ctx: Context<MigrateObligation>) -> Result<()> {
+    let obligation = &mut ctx.accounts.obligation;
+    require!(obligation.version < CURRENT_ACCOUNT_VERSION, ErrorCode::AccountAlreadyUpToDate);
+
+    obligation.version = CURRENT_ACCOUNT_VERSION;
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::{Reserve, CURRENT_ACCOUNT_VERSION};
+
+#[derive(Accounts)]
+pub struct MigrateReserve<'info> {
+    #[account(mut)]
+    pub reserve: Account<'info, Reserve>,
+}
+
+/// See `migrate_lending_market` for the rationale: permissionless version
+/// stamp bump, with room for real field migrations ahead of the stamp
+/// update once a layout change actually needs one.
+pub fn handle(ctx: Context<MigrateReserve>) -> Result<()> {
+    let reserve = &mut ctx.accounts.reserve;
+    require!(reserve.version < CURRENT_ACCOUNT_VERSION, ErrorCode::AccountAlreadyUpToDate);
+
+    reserve.version = CURRENT_ACCOUNT_VERSION;
+
+    Ok(())
+}
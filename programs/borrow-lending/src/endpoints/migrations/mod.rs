@@ -0,0 +1,13 @@
+//! Per-account layout upgrade instructions, run once per account after a
+//! program upgrade that bumps `models::CURRENT_ACCOUNT_VERSION`, so old
+//! accounts stay usable without redeploying to a new program id.
+//!
+//! There's been exactly one layout version so far, so these are no-ops
+//! beyond stamping the current version onto pre-existing accounts that
+//! predate the `version` field (where borsh deserializes the missing bytes
+//! as `0`). Add the real field-migration logic to the matching `handle`
+//! when `CURRENT_ACCOUNT_VERSION` is next bumped.
+
+pub mod migrate_lending_market;
+pub mod migrate_obligation;
+pub mod migrate_reserve;
@@ -0,0 +1,76 @@
+//! One module per instruction. Each exposes an `Accounts` struct and a
+//! `handle` function; `lib.rs` wires them up under `#[program]`.
+
+pub mod get_obligation_health;
+pub mod accrue_reserve_interest;
+pub mod amm;
+pub mod claim_host_fees;
+pub mod claim_referral_fees;
+pub mod leverage;
+pub mod register_host;
+pub mod register_referrer;
+pub mod set_boost_config;
+pub mod stake_booster_tokens;
+pub mod unstake_booster_tokens;
+pub mod vault;
+pub mod announce_sunset;
+pub mod approve_credit_delegation;
+pub mod borrow_obligation_liquidity;
+pub mod claim_emission;
+pub mod close_obligation;
+pub mod close_reserve;
+pub mod create_reserve_template;
+pub mod deploy_idle_liquidity;
+pub mod deposit_obligation_collateral;
+pub mod deposit_reserve_liquidity;
+pub mod deposit_reserve_liquidity_sol;
+pub mod flash_loan;
+pub mod force_settle;
+pub mod grow_obligation;
+pub mod harvest_collateral_interest;
+pub mod init_emission_strategy;
+pub mod init_lending_market;
+pub mod init_obligation;
+pub mod init_reserve;
+pub mod init_reserve_cap_snapshots;
+pub mod init_reserve_from_template;
+pub mod issue_credit_line;
+pub mod liquidate_obligation;
+pub mod liquidate_obligation_and_redeem;
+pub mod liquidate_obligation_and_redeem_sol;
+pub mod log_compute_checkpoint;
+pub mod migrations;
+pub mod ping_unhealthy_obligation;
+pub mod preview_liquidation;
+pub mod rebalance_soft_liquidation;
+pub mod refresh_obligation;
+pub mod refresh_reserve;
+pub mod refresh_reserve_lst;
+pub mod recall_idle_liquidity;
+pub mod redeem_reserve_collateral;
+pub mod redeem_reserve_collateral_sol;
+pub mod refer_bad_debt_to_auction;
+pub mod refresh_reserves;
+pub mod repay_multiple_obligation_liquidities;
+pub mod repay_obligation_liquidity;
+pub mod self_test_layouts;
+pub mod set_margin_call_threshold;
+pub mod set_auto_repay;
+pub mod set_collateral_metadata;
+pub mod set_max_total_borrow_value;
+pub mod set_obligation_alert_threshold;
+pub mod set_oracle_asset;
+pub mod set_reserve_emissions;
+pub mod set_reserve_retiring;
+pub mod set_reserve_status;
+pub mod snapshot_reserve;
+pub mod swap_obligation_collateral_on_aldrin;
+pub mod swap_obligation_debt_on_aldrin;
+pub mod sweep_pda_lamports;
+pub mod switch_rate_mode;
+pub mod sync_emission_strategy;
+pub mod tag_obligation;
+pub mod transfer_obligation_ownership;
+pub mod update_emission;
+pub mod update_reserve_oracle;
+pub mod withdraw_obligation_collateral;
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::Obligation;
+
+/// Emitted by `ping_unhealthy_obligation` once an obligation's
+/// already-refreshed `borrowed_value` crosses its own `alert_threshold`
+/// fraction of `unhealthy_borrow_value`, so notification services can
+/// subscribe to this event instead of re-deriving health for every
+/// obligation on every slot.
+#[event]
+pub struct ObligationAlertTriggered {
+    pub obligation: Pubkey,
+    pub alert_threshold: u128,
+    pub borrowed_value: u128,
+    pub unhealthy_borrow_value: u128,
+}
+
+#[derive(Accounts)]
+pub struct PingUnhealthyObligation<'info> {
+    pub obligation: Account<'info, Obligation>,
+}
+
+/// Permissionless: reads the obligation's cached health figures from its
+/// last `refresh_obligation` and emits `ObligationAlertTriggered` if
+/// they're past the borrower's own `alert_threshold`. Doesn't refresh
+/// anything itself — call `refresh_obligation` first if the figures might
+/// be stale — so a watcher can afford to call this on every obligation
+/// without paying for a full reserve refresh each time.
+pub fn handle(ctx: Context<PingUnhealthyObligation>) -> Result<()> {
+    let obligation = &ctx.accounts.obligation;
+    let alert_threshold = obligation.alert_threshold.ok_or(ErrorCode::NoAlertThresholdSet)?;
+
+    require!(obligation.unhealthy_borrow_value.to_scaled_val() > 0, ErrorCode::AlertThresholdNotCrossed);
+    let trigger_value = obligation.unhealthy_borrow_value.try_mul(alert_threshold)?;
+    require!(obligation.borrowed_value >= trigger_value, ErrorCode::AlertThresholdNotCrossed);
+
+    emit!(ObligationAlertTriggered {
+        obligation: obligation.key(),
+        alert_threshold: alert_threshold.to_scaled_val(),
+        borrowed_value: obligation.borrowed_value.to_scaled_val(),
+        unhealthy_borrow_value: obligation.unhealthy_borrow_value.to_scaled_val(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{Obligation, Reserve};
+
+/// View-style instruction: runs `Obligation::calculate_liquidation_amounts`
+/// against live state and returns the resulting
+/// [`crate::models::LiquidationAmounts`] (repay amount, collateral seized,
+/// bonus applied) via Solana return data, so liquidation bots can simulate
+/// the exact outcome of a `liquidate_obligation` call instead of
+/// re-implementing the close-factor math themselves.
+///
+/// Intended to be called via `simulateTransaction` rather than landed
+/// on-chain, since it only reads state. The obligation and both reserves
+/// must already be refreshed this slot, same as `liquidate_obligation`.
+#[derive(Accounts)]
+pub struct PreviewLiquidation<'info> {
+    pub obligation: Account<'info, Obligation>,
+    pub repay_reserve: Account<'info, Reserve>,
+    pub withdraw_reserve: Account<'info, Reserve>,
+}
+
+pub fn handle(ctx: Context<PreviewLiquidation>, liquidity_amount: u64) -> Result<()> {
+    let obligation = &ctx.accounts.obligation;
+    let slot = Clock::get()?.slot;
+    obligation.check_not_stale(slot)?;
+    ctx.accounts.repay_reserve.check_not_stale(slot)?;
+    ctx.accounts.withdraw_reserve.check_not_stale(slot)?;
+
+    let amounts = obligation.calculate_liquidation_amounts(
+        &ctx.accounts.repay_reserve,
+        ctx.accounts.repay_reserve.key(),
+        &ctx.accounts.withdraw_reserve,
+        ctx.accounts.withdraw_reserve.key(),
+        liquidity_amount,
+    )?;
+    let data = amounts.try_to_vec()?;
+    anchor_lang::solana_program::program::set_return_data(&data);
+
+    Ok(())
+}
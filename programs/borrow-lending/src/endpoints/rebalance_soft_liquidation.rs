@@ -0,0 +1,323 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{LendingMarket, Obligation, Reserve};
+
+use crate::endpoints::leverage::aldrin_adapter::AldrinAdapter;
+
+#[derive(Accounts)]
+pub struct RebalanceSoftLiquidation<'info> {
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    // Permissionless keeper instruction, same trust model as
+    // `liquidate_obligation` and `harvest_collateral_interest` — no owner
+    // signature required.
+    #[account(mut)]
+    pub obligation: Account<'info, Obligation>,
+
+    #[account(mut, has_one = lending_market)]
+    pub collateral_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub collateral_reserve_collateral_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub collateral_reserve_collateral_supply: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub collateral_reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    #[account(mut, has_one = lending_market)]
+    pub debt_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub debt_reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    /// PDA-owned scratch account `collateral_reserve`'s underlying liquidity
+    /// passes through mid-swap, in either direction. Drained to zero within
+    /// this instruction.
+    #[account(mut)]
+    pub scratch_collateral_liquidity: Account<'info, TokenAccount>,
+    /// PDA-owned scratch account `debt_reserve`'s underlying liquidity
+    /// passes through mid-swap, in either direction. Drained to zero within
+    /// this instruction.
+    #[account(mut)]
+    pub scratch_debt_liquidity: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    // Remaining accounts are Aldrin's `swap` account list, passed through
+    // verbatim — see `AldrinAdapter`.
+}
+
+/// LLAMMA-style soft-liquidation band: a permissionless keeper gradually
+/// rebalances a deposit of `collateral_reserve` against a borrow of
+/// `debt_reserve` as the obligation's health drifts through the band just
+/// below `unhealthy_borrow_value` (sized by
+/// `ReserveConfig::soft_liquidation_band_pct`), instead of the all-at-once
+/// seizure-with-bonus `liquidate_obligation` applies once the obligation
+/// actually crosses it.
+///
+/// Inside the band: burns up to `ReserveConfig::soft_liquidation_step_pct`
+/// of the deposit's collateral, swaps the underlying liquidity into the
+/// borrowed asset through Aldrin, and uses it to repay `debt_reserve`'s
+/// borrow — tracked on [`crate::models::ObligationCollateral::soft_liquidated_amount`]
+/// so it can be unwound later.
+///
+/// Below the band with something left to unwind: re-borrows a slice of
+/// `debt_reserve`'s liquidity, swaps it back into `collateral_reserve`'s
+/// underlying, and mints that much collateral back into the deposit,
+/// draining `soft_liquidated_amount` back down. Any swap output beyond
+/// what's still tracked as soft-liquidated is left in
+/// `collateral_reserve_liquidity_supply` rather than over-minted — the same
+/// "un-minted surplus benefits depositors" absorption `flash_loan`'s fee
+/// handling and `swap_obligation_debt_on_aldrin` both rely on.
+///
+/// Does nothing (and errors) once the obligation is fully past
+/// `unhealthy_borrow_value` — that's `liquidate_obligation`'s job — or once
+/// it's healthy again with nothing left to unwind. Both reserves and the
+/// obligation must already be refreshed this slot.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RebalanceSoftLiquidation<'info>>,
+    amount_in: u64,
+    min_amount_out: u64,
+) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    let obligation = &mut ctx.accounts.obligation;
+    obligation.check_not_stale(slot)?;
+
+    let collateral_reserve = &mut ctx.accounts.collateral_reserve;
+    let debt_reserve = &mut ctx.accounts.debt_reserve;
+    collateral_reserve.check_not_stale(slot)?;
+    debt_reserve.check_not_stale(slot)?;
+
+    require!(collateral_reserve.config.soft_liquidation_band_pct > 0, ErrorCode::SoftLiquidationDisabled);
+    require!(!obligation.is_liquidatable(slot), ErrorCode::ObligationPastSoftLiquidationBand);
+
+    let band_width =
+        obligation.unhealthy_borrow_value.try_mul(Decimal::from_percent(collateral_reserve.config.soft_liquidation_band_pct))?;
+    let band_start = obligation.unhealthy_borrow_value.try_sub(band_width).unwrap_or_else(|_| Decimal::zero());
+
+    let deposit_index = obligation
+        .deposits
+        .iter()
+        .position(|d| d.deposit_reserve == collateral_reserve.key())
+        .ok_or(ErrorCode::ObligationDepositsEmpty)?;
+    let borrow_index = obligation
+        .borrows
+        .iter()
+        .position(|b| b.borrow_reserve == debt_reserve.key())
+        .ok_or(ErrorCode::ObligationBorrowsEmpty)?;
+
+    let lending_market_owner = ctx.accounts.lending_market.owner;
+    let lending_market_bump_seed = ctx.accounts.lending_market.bump_seed;
+    let seeds: &[&[u8]] = &[b"lending-market", lending_market_owner.as_ref(), &[lending_market_bump_seed]];
+    let step_pct = collateral_reserve.config.soft_liquidation_step_pct.max(1);
+
+    if obligation.borrowed_value > band_start {
+        let deposit_balance = obligation.deposits[deposit_index].deposited_amount;
+        require!(deposit_balance > 0, ErrorCode::NothingToRebalance);
+        let collateral_amount = amount_in.min(step_cap(deposit_balance, step_pct)?);
+        let liquidity_amount = collateral_reserve.collateral_to_liquidity(collateral_amount)?;
+        require!(
+            collateral_reserve.liquidity.available_amount >= liquidity_amount,
+            ErrorCode::WithdrawTooLarge
+        );
+
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.collateral_reserve_collateral_mint.to_account_info(),
+                    from: ctx.accounts.collateral_reserve_collateral_supply.to_account_info(),
+                    authority: ctx.accounts.lending_market.to_account_info(),
+                },
+                &[seeds],
+            ),
+            collateral_amount,
+        )?;
+        collateral_reserve.liquidity.available_amount -= liquidity_amount;
+        collateral_reserve.collateral.mint_total_supply = collateral_reserve
+            .collateral
+            .mint_total_supply
+            .checked_sub(collateral_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.collateral_reserve_liquidity_supply.to_account_info(),
+                    to: ctx.accounts.scratch_collateral_liquidity.to_account_info(),
+                    authority: ctx.accounts.lending_market.to_account_info(),
+                },
+                &[seeds],
+            ),
+            liquidity_amount,
+        )?;
+
+        let received = AldrinAdapter.swap_signed(
+            ctx.remaining_accounts,
+            &ctx.accounts.scratch_debt_liquidity.to_account_info(),
+            liquidity_amount,
+            min_amount_out,
+            &[seeds],
+        )?;
+
+        let owed = obligation.borrows[borrow_index].borrowed_amount;
+        let owed_floor = owed.try_floor_u64()?;
+        let repay_amount = received.min(owed_floor);
+        let repay_decimal = Decimal::from(repay_amount);
+        let repaid_fraction = repay_decimal.try_div(owed)?;
+        let repaid_value = obligation.borrows[borrow_index].market_value.try_mul(repaid_fraction)?;
+        obligation.borrows[borrow_index].borrowed_amount = owed.try_sub(repay_decimal)?;
+        obligation.borrows[borrow_index].market_value =
+            obligation.borrows[borrow_index].market_value.try_sub(repaid_value)?;
+        obligation.borrowed_value = obligation.borrowed_value.try_sub(repaid_value)?;
+        ctx.accounts.lending_market.decrease_total_borrow_value(repaid_value);
+        if obligation.borrows[borrow_index].borrowed_amount.to_scaled_val() == 0 {
+            obligation.borrows.remove(borrow_index);
+        }
+
+        let collateral_value = collateral_reserve.market_value(Decimal::from(liquidity_amount))?;
+        let deposit = &mut obligation.deposits[deposit_index];
+        let new_deposited_amount = deposit.deposited_amount - collateral_amount;
+        deposit.rebase_rewards(new_deposited_amount, collateral_reserve.liquidity.cumulative_reward_per_share)?;
+        deposit.market_value = deposit.market_value.try_sub(collateral_value).unwrap_or_else(|_| Decimal::zero());
+        deposit.cost_basis_liquidity = Decimal::from(collateral_reserve.collateral_to_liquidity(new_deposited_amount)?);
+        deposit.soft_liquidated_amount =
+            deposit.soft_liquidated_amount.checked_add(collateral_amount).ok_or(ErrorCode::MathOverflow)?;
+        obligation.deposited_value = obligation.deposited_value.try_sub(collateral_value).unwrap_or_else(|_| Decimal::zero());
+        // Unlike other deposit-reducing endpoints, a zeroed-out
+        // `deposited_amount` here never drops the entry from
+        // `obligation.deposits` — `soft_liquidated_amount` just grew, so
+        // there's always something left to track until it's unwound.
+
+        debt_reserve.liquidity.available_amount =
+            debt_reserve.liquidity.available_amount.checked_add(repay_amount).ok_or(ErrorCode::MathOverflow)?;
+        debt_reserve.liquidity.borrowed_amount = debt_reserve.liquidity.borrowed_amount.try_sub(repay_decimal)?;
+
+        // Any swap output beyond what's owed is absorbed by the debt
+        // reserve's depositors rather than tracked back to the obligation,
+        // same as `swap_obligation_debt_on_aldrin`'s surplus handling.
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.scratch_debt_liquidity.to_account_info(),
+                    to: ctx.accounts.debt_reserve_liquidity_supply.to_account_info(),
+                    authority: ctx.accounts.lending_market.to_account_info(),
+                },
+                &[seeds],
+            ),
+            received,
+        )?;
+    } else {
+        let soft_liquidated = obligation.deposits[deposit_index].soft_liquidated_amount;
+        require!(soft_liquidated > 0, ErrorCode::NothingToRebalance);
+
+        // Cap how much debt-asset the keeper may borrow to buy collateral
+        // back by the UAC value of a single step's worth of what's still
+        // soft-liquidated, so the unwind is as gradual as the seizure was.
+        let step_collateral = step_cap(soft_liquidated, step_pct)?;
+        let step_value = collateral_reserve.market_value(Decimal::from(
+            collateral_reserve.collateral_to_liquidity(step_collateral)?,
+        ))?;
+        let max_debt_amount = if debt_reserve.liquidity.market_price.to_scaled_val() == 0 {
+            amount_in
+        } else {
+            step_value.try_div(debt_reserve.liquidity.market_price)?.try_floor_u64()?
+        };
+        let debt_amount = amount_in.min(max_debt_amount).min(debt_reserve.liquidity.available_amount);
+        require!(debt_amount > 0, ErrorCode::NothingToRebalance);
+        let borrow_value = debt_reserve.market_value(Decimal::from(debt_amount))?;
+
+        debt_reserve.liquidity.available_amount -= debt_amount;
+        debt_reserve.liquidity.borrowed_amount = debt_reserve.liquidity.borrowed_amount.try_add(Decimal::from(debt_amount))?;
+        let owed = &mut obligation.borrows[borrow_index];
+        owed.borrowed_amount = owed.borrowed_amount.try_add(Decimal::from(debt_amount))?;
+        owed.market_value = owed.market_value.try_add(borrow_value)?;
+        obligation.borrowed_value = obligation.borrowed_value.try_add(borrow_value)?;
+        ctx.accounts.lending_market.increase_total_borrow_value(borrow_value)?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.debt_reserve_liquidity_supply.to_account_info(),
+                    to: ctx.accounts.scratch_debt_liquidity.to_account_info(),
+                    authority: ctx.accounts.lending_market.to_account_info(),
+                },
+                &[seeds],
+            ),
+            debt_amount,
+        )?;
+
+        let received = AldrinAdapter.swap_signed(
+            ctx.remaining_accounts,
+            &ctx.accounts.scratch_collateral_liquidity.to_account_info(),
+            debt_amount,
+            min_amount_out,
+            &[seeds],
+        )?;
+
+        let collateral_equivalent = collateral_reserve.liquidity_to_collateral(received)?;
+        let mint_amount = collateral_equivalent.min(soft_liquidated);
+        let liquidity_to_keep = collateral_reserve.collateral_to_liquidity(mint_amount)?;
+
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.collateral_reserve_collateral_mint.to_account_info(),
+                    to: ctx.accounts.collateral_reserve_collateral_supply.to_account_info(),
+                    authority: ctx.accounts.lending_market.to_account_info(),
+                },
+                &[seeds],
+            ),
+            mint_amount,
+        )?;
+        collateral_reserve.collateral.mint_total_supply =
+            collateral_reserve.collateral.mint_total_supply.checked_add(mint_amount).ok_or(ErrorCode::MathOverflow)?;
+        collateral_reserve.liquidity.available_amount = collateral_reserve
+            .liquidity
+            .available_amount
+            .checked_add(received)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.scratch_collateral_liquidity.to_account_info(),
+                    to: ctx.accounts.collateral_reserve_liquidity_supply.to_account_info(),
+                    authority: ctx.accounts.lending_market.to_account_info(),
+                },
+                &[seeds],
+            ),
+            received,
+        )?;
+        // Liquidity beyond `liquidity_to_keep` (i.e. beyond what
+        // `mint_amount` accounts for) lands in `collateral_reserve_liquidity_supply`
+        // without a matching mint, the same "un-minted surplus benefits
+        // depositors" absorption described above.
+
+        let collateral_value = collateral_reserve.market_value(Decimal::from(liquidity_to_keep))?;
+        let deposit = &mut obligation.deposits[deposit_index];
+        let new_deposited_amount = deposit.deposited_amount + mint_amount;
+        deposit.rebase_rewards(new_deposited_amount, collateral_reserve.liquidity.cumulative_reward_per_share)?;
+        deposit.market_value = deposit.market_value.try_add(collateral_value)?;
+        deposit.cost_basis_liquidity = Decimal::from(collateral_reserve.collateral_to_liquidity(new_deposited_amount)?);
+        deposit.soft_liquidated_amount = deposit.soft_liquidated_amount.saturating_sub(mint_amount);
+        obligation.deposited_value = obligation.deposited_value.try_add(collateral_value)?;
+    }
+
+    Ok(())
+}
+
+/// `step_pct` of `balance`, floored at 1 and capped at `balance` itself, so
+/// a single call always moves something but never more than what's there.
+fn step_cap(balance: u64, step_pct: u8) -> Result<u64> {
+    Ok(Decimal::from(balance)
+        .try_mul(Decimal::from_percent(step_pct))?
+        .try_floor_u64()?
+        .clamp(1, balance))
+}
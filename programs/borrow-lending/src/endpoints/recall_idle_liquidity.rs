@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::err::ErrorCode;
+use crate::models::{LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct RecallIdleLiquidity<'info> {
+    #[account(seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+    #[account(mut)]
+    pub reserve: Account<'info, Reserve>,
+    /// CHECK: must match `reserve.config.idle_strategy_program`.
+    pub strategy_program: AccountInfo<'info>,
+}
+
+/// Pulls `amount` back out of a reserve's idle strategy via CPI, e.g. when
+/// a withdrawal or borrow needs more on-hand liquidity than is currently
+/// sitting unborrowed. Callable by anyone (not just the market owner) so
+/// any instruction needing liquidity can recall it inline rather than
+/// requiring a separate owner-run step first.
+pub fn handle(ctx: Context<RecallIdleLiquidity>, amount: u64, strategy_withdraw_ix_data: Vec<u8>) -> Result<()> {
+    let reserve = &mut ctx.accounts.reserve;
+    require_keys_eq!(
+        ctx.accounts.strategy_program.key(),
+        reserve.config.idle_strategy_program.ok_or(ErrorCode::MathOverflow)?
+    );
+    require!(amount <= reserve.liquidity.deployed_amount, ErrorCode::BorrowTooLarge);
+
+    let accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|a| AccountMeta {
+            pubkey: a.key(),
+            is_signer: a.is_signer,
+            is_writable: a.is_writable,
+        })
+        .collect();
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+    invoke_signed(
+        &Instruction {
+            program_id: ctx.accounts.strategy_program.key(),
+            accounts,
+            data: strategy_withdraw_ix_data,
+        },
+        ctx.remaining_accounts,
+        &[seeds],
+    )?;
+
+    reserve.liquidity.deployed_amount -= amount;
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct RedeemReserveCollateral<'info> {
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub source_collateral: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub reserve_collateral_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_liquidity: Account<'info, TokenAccount>,
+    pub collateral_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Burns `collateral_amount` of the reserve's collateral token and returns
+/// the equivalent underlying liquidity at the current exchange rate. The
+/// reverse of `deposit_reserve_liquidity`.
+pub fn handle(ctx: Context<RedeemReserveCollateral>, collateral_amount: u64) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    redeem_collateral(
+        &mut ctx.accounts.lending_market,
+        &mut ctx.accounts.reserve,
+        ctx.accounts.source_collateral.to_account_info(),
+        ctx.accounts.collateral_authority.to_account_info(),
+        ctx.accounts.reserve_collateral_mint.to_account_info(),
+        ctx.accounts.reserve_liquidity_supply.to_account_info(),
+        ctx.accounts.destination_liquidity.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        collateral_amount,
+        slot,
+    )
+}
+
+/// Shared by [`handle`] and `redeem_reserve_collateral_sol`, which only
+/// differs in where the redeemed liquidity ends up (a pre-existing account
+/// vs. a temporary wSOL one that gets unwrapped to native SOL).
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn redeem_collateral<'info>(
+    lending_market: &mut Account<'info, LendingMarket>,
+    reserve: &mut Account<'info, Reserve>,
+    source_collateral: AccountInfo<'info>,
+    collateral_authority: AccountInfo<'info>,
+    reserve_collateral_mint: AccountInfo<'info>,
+    reserve_liquidity_supply: AccountInfo<'info>,
+    destination_liquidity: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    collateral_amount: u64,
+    current_slot: u64,
+) -> Result<()> {
+    let liquidity_amount = reserve.collateral_to_liquidity(collateral_amount)?;
+    require!(
+        reserve.liquidity.available_amount >= liquidity_amount,
+        ErrorCode::WithdrawTooLarge
+    );
+
+    reserve.liquidity.available_amount -= liquidity_amount;
+    reserve.collateral.mint_total_supply = reserve
+        .collateral
+        .mint_total_supply
+        .checked_sub(collateral_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let redeemed_value = reserve.market_value(Decimal::from(liquidity_amount))?;
+    lending_market.consume_outflow(current_slot, redeemed_value)?;
+
+    token::burn(
+        CpiContext::new(
+            token_program.clone(),
+            Burn {
+                mint: reserve_collateral_mint,
+                from: source_collateral,
+                authority: collateral_authority,
+            },
+        ),
+        collateral_amount,
+    )?;
+
+    let seeds: &[&[u8]] = &[b"lending-market", lending_market.owner.as_ref(), &[lending_market.bump_seed]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program,
+            Transfer {
+                from: reserve_liquidity_supply,
+                to: destination_liquidity,
+                authority: lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        liquidity_amount,
+    )?;
+
+    Ok(())
+}
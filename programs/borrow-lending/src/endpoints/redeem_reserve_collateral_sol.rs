@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, spl_token, Mint, Token, TokenAccount};
+
+use crate::endpoints::redeem_reserve_collateral::redeem_collateral;
+use crate::models::{LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct RedeemReserveCollateralSol<'info> {
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub source_collateral: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub reserve_collateral_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+    pub collateral_authority: Signer<'info>,
+
+    /// Ephemeral wSOL account the redeemed liquidity lands in before this
+    /// instruction closes it, unwrapping it straight to native SOL in the
+    /// payer's own account.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [b"tmp-wsol", payer.key().as_ref()],
+        bump,
+        token::mint = native_mint,
+        token::authority = payer,
+    )]
+    pub temp_wsol: Account<'info, TokenAccount>,
+    #[account(address = spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// SOL convenience wrapper around `redeem_reserve_collateral`: redeems into
+/// a temporary wSOL account, then immediately closes it so the caller
+/// receives native SOL rather than having to unwrap it themselves.
+pub fn handle(ctx: Context<RedeemReserveCollateralSol>, collateral_amount: u64) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    redeem_collateral(
+        &mut ctx.accounts.lending_market,
+        &mut ctx.accounts.reserve,
+        ctx.accounts.source_collateral.to_account_info(),
+        ctx.accounts.collateral_authority.to_account_info(),
+        ctx.accounts.reserve_collateral_mint.to_account_info(),
+        ctx.accounts.reserve_liquidity_supply.to_account_info(),
+        ctx.accounts.temp_wsol.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        collateral_amount,
+        slot,
+    )?;
+
+    token::close_account(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token::CloseAccount {
+            account: ctx.accounts.temp_wsol.to_account_info(),
+            destination: ctx.accounts.payer.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+        },
+    ))?;
+
+    Ok(())
+}
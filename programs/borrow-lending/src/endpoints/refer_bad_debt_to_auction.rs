@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{LendingMarket, Obligation, Reserve};
+
+#[derive(Accounts)]
+pub struct ReferBadDebtToAuction<'info> {
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+    #[account(mut, close = closer)]
+    pub obligation: Account<'info, Obligation>,
+    /// CHECK: receives the obligation's reclaimed rent; any account works.
+    #[account(mut)]
+    pub closer: AccountInfo<'info>,
+
+    /// CHECK: opaque recovery auction program the bad debt's claim is
+    /// handed off to; this instruction just records the referral, the
+    /// auction program is responsible for whatever recovery process it runs.
+    pub recovery_auction_program: AccountInfo<'info>,
+}
+
+/// Emitted when a liquidated-out obligation still has unrecovered debt,
+/// referring the shortfall to an external recovery auction rather than
+/// leaving it as an untracked loss on the reserve.
+#[event]
+pub struct BadDebtReferred {
+    pub reserve: Pubkey,
+    pub obligation: Pubkey,
+    pub amount: u128,
+    pub recovery_auction_program: Pubkey,
+}
+
+/// Closes out an obligation whose collateral has been fully liquidated but
+/// whose `borrowed_value` still exceeds zero, socializing the shortfall
+/// onto the reserve as `bad_debt_amount` and emitting [`BadDebtReferred`]
+/// so an off-chain (or CPI-driven) recovery auction can pick up the claim.
+/// Only callable once `obligation.deposits` is empty — there's nothing
+/// left to liquidate.
+pub fn handle(ctx: Context<ReferBadDebtToAuction>) -> Result<()> {
+    let obligation = &ctx.accounts.obligation;
+    require!(obligation.deposits.is_empty(), ErrorCode::ObligationDepositsEmpty);
+    require!(!obligation.borrows.is_empty(), ErrorCode::ObligationBorrowsEmpty);
+
+    let reserve = &mut ctx.accounts.reserve;
+    let matching_borrow = obligation.borrows.iter().find(|b| b.borrow_reserve == reserve.key());
+    let shortfall = matching_borrow.map(|b| b.borrowed_amount).unwrap_or_else(Decimal::zero);
+    let shortfall_value = matching_borrow.map(|b| b.market_value).unwrap_or_else(Decimal::zero);
+
+    reserve.liquidity.bad_debt_amount = reserve.liquidity.bad_debt_amount.try_add(shortfall)?;
+    reserve.liquidity.borrowed_amount = reserve.liquidity.borrowed_amount.try_sub(shortfall)?;
+    ctx.accounts.lending_market.decrease_total_borrow_value(shortfall_value);
+
+    emit!(BadDebtReferred {
+        reserve: reserve.key(),
+        obligation: obligation.key(),
+        amount: shortfall.to_scaled_val(),
+        recovery_auction_program: ctx.accounts.recovery_auction_program.key(),
+    });
+
+    Ok(())
+}
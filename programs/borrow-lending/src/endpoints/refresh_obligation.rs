@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+
+use crate::math::Decimal;
+use crate::models::{CreditLine, LendingMarket, Obligation, Reserve};
+use crate::oracle;
+
+/// Emitted once a refreshed obligation's borrowed value crosses
+/// `margin_call_warning_threshold_pct` of its unhealthy threshold, ahead of
+/// it actually becoming liquidatable. Bots and notification services can
+/// subscribe to this instead of polling every obligation's health.
+#[event]
+pub struct MarginCallWarning {
+    pub obligation: Pubkey,
+    pub borrowed_value: u128,
+    pub unhealthy_borrow_value: u128,
+}
+
+#[derive(Accounts)]
+pub struct RefreshObligation<'info> {
+    #[account(mut)]
+    pub obligation: Account<'info, Obligation>,
+    #[account(seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    /// Validated manually in `handle` rather than with a declarative
+    /// `has_one`: present only for obligations the market owner has issued
+    /// a `CreditLine` to, absent for every other obligation.
+    pub credit_line: Option<Account<'info, CreditLine>>,
+    // Each reserve referenced by `obligation` is passed as a *pair* of
+    // remaining accounts `(reserve, oracle_price)`, in the same order the
+    // reserve appears in `obligation.deposits` followed by
+    // `obligation.borrows`. Reserves must be writable: this instruction
+    // refreshes them inline (see synth-776) so a single
+    // `refresh_obligation` call is enough before a borrow or liquidation,
+    // instead of a separate `refresh_reserve` per reserve plus this one.
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, RefreshObligation<'info>>) -> Result<()> {
+    crate::telemetry::checkpoint("refresh_obligation:start");
+    let obligation = &mut ctx.accounts.obligation;
+    let slot = Clock::get()?.slot;
+    let remaining = ctx.remaining_accounts;
+    let reserve_count = obligation.deposits.len() + obligation.borrows.len();
+
+    require!(remaining.len() == reserve_count * 2, ErrorCode::ReserveCountMismatch);
+
+    // An obligation qualifies for e-mode only if every deposit *and* every
+    // borrow reserve shares the same category; mixing in a single
+    // uncategorized or differently-categorized reserve falls back to each
+    // reserve's regular LTV/liquidation threshold.
+    let e_mode_category = e_mode_category_if_uniform(remaining)?;
+    let tightening_pct = ctx.accounts.lending_market.liquidation_threshold_tightening_pct(slot);
+
+    let credit_line_value = match ctx.accounts.credit_line.as_ref() {
+        Some(credit_line) => {
+            require_keys_eq!(credit_line.obligation, obligation.key(), ErrorCode::CreditLineObligationMismatch);
+            credit_line.credit_value
+        }
+        None => Decimal::zero(),
+    };
+
+    let auto_repay_enabled = obligation.auto_repay_enabled;
+    let auto_repay_threshold = obligation.auto_repay_threshold;
+
+    let mut deposited_value = Decimal::zero();
+    let mut allowed_borrow_value = Decimal::zero();
+    let mut unhealthy_borrow_value = Decimal::zero();
+    for (i, deposit) in obligation.deposits.iter_mut().enumerate() {
+        let reserve_info = &remaining[i * 2];
+        let oracle_info = &remaining[i * 2 + 1];
+
+        let mut reserve = Account::<Reserve>::try_from(reserve_info)?;
+        require_keys_eq!(reserve.key(), deposit.deposit_reserve, ErrorCode::ReserveMismatch);
+        refresh_reserve_inline(&mut reserve, oracle_info, slot)?;
+        reserve.exit(&crate::ID)?;
+
+        let liquidity_amount = reserve.collateral_to_liquidity(deposit.deposited_amount)?;
+        deposit.market_value = reserve.market_value(Decimal::from(liquidity_amount))?;
+        deposited_value = deposited_value.try_add(deposit.market_value)?;
+
+        deposit.harvestable_liquidity = if auto_repay_enabled {
+            let current_value = Decimal::from(liquidity_amount);
+            let excess = current_value.try_sub(deposit.cost_basis_liquidity).unwrap_or_else(|_| Decimal::zero());
+            let excess = excess.try_floor_u64()?;
+            if excess >= auto_repay_threshold {
+                excess
+            } else {
+                0
+            }
+        } else {
+            0
+        };
+
+        let (ltv, threshold) = reserve.config.effective_ltv_and_threshold(e_mode_category);
+        let tightened_threshold = (threshold as u16 * tightening_pct as u16 / 100) as u8;
+        allowed_borrow_value = allowed_borrow_value.try_add(deposit.market_value.try_mul(Decimal::from_percent(ltv))?)?;
+        unhealthy_borrow_value = unhealthy_borrow_value.try_add(deposit.market_value.try_mul(Decimal::from_percent(tightened_threshold))?)?;
+    }
+
+    let mut borrowed_value = Decimal::zero();
+    let deposit_count = obligation.deposits.len();
+    let last_update_slot = obligation.last_update_slot;
+    for (i, borrow) in obligation.borrows.iter_mut().enumerate() {
+        let offset = deposit_count + i;
+        let reserve_info = &remaining[offset * 2];
+        let oracle_info = &remaining[offset * 2 + 1];
+
+        let mut reserve = Account::<Reserve>::try_from(reserve_info)?;
+        require_keys_eq!(reserve.key(), borrow.borrow_reserve, ErrorCode::ReserveMismatch);
+        refresh_reserve_inline(&mut reserve, oracle_info, slot)?;
+        reserve.exit(&crate::ID)?;
+
+        // Accrue interest since the borrow's last snapshot. Variable-rate
+        // borrows ride the reserve's cumulative borrow rate index; stable
+        // borrows accrue at the APR they locked in regardless of where the
+        // reserve's floating rate has since moved.
+        match borrow.rate_mode {
+            crate::models::RateMode::Variable => {
+                if borrow.cumulative_borrow_rate != reserve.liquidity.cumulative_borrow_rate {
+                    let compounded = reserve
+                        .liquidity
+                        .cumulative_borrow_rate
+                        .try_div(borrow.cumulative_borrow_rate)?;
+                    borrow.borrowed_amount = borrow.borrowed_amount.try_mul(compounded)?;
+                    borrow.cumulative_borrow_rate = reserve.liquidity.cumulative_borrow_rate;
+                }
+            }
+            crate::models::RateMode::Stable(apr) => {
+                let elapsed_slots = slot.saturating_sub(last_update_slot);
+                let accrual_fraction = apr
+                    .try_mul(Decimal::from(elapsed_slots))?
+                    .try_div(Decimal::from(crate::models::SLOTS_PER_YEAR))?;
+                let interest = borrow.borrowed_amount.try_mul(accrual_fraction)?;
+                borrow.borrowed_amount = borrow.borrowed_amount.try_add(interest)?;
+            }
+        }
+
+        borrow.market_value = reserve.market_value(borrow.borrowed_amount)?;
+        borrowed_value = borrowed_value.try_add(borrow.market_value)?;
+    }
+
+    obligation.deposited_value = deposited_value;
+    obligation.borrowed_value = borrowed_value;
+    obligation.allowed_borrow_value = allowed_borrow_value.try_add(credit_line_value)?;
+    obligation.unhealthy_borrow_value = unhealthy_borrow_value.try_add(credit_line_value)?;
+    obligation.credit_line_value = credit_line_value;
+    obligation.last_update_slot = slot;
+
+    let warning_threshold = obligation
+        .unhealthy_borrow_value
+        .try_mul(Decimal::from_percent(ctx.accounts.lending_market.margin_call_warning_threshold_pct))?;
+    if obligation.unhealthy_borrow_value.to_scaled_val() > 0 && obligation.borrowed_value >= warning_threshold {
+        emit!(MarginCallWarning {
+            obligation: obligation.key(),
+            borrowed_value: obligation.borrowed_value.to_scaled_val(),
+            unhealthy_borrow_value: obligation.unhealthy_borrow_value.to_scaled_val(),
+        });
+    }
+
+    crate::telemetry::checkpoint("refresh_obligation:end");
+    Ok(())
+}
+
+/// Inspects the `(reserve, oracle)` remaining accounts up front (without
+/// mutating anything) to determine whether every reserve the obligation
+/// touches shares one e-mode category.
+fn e_mode_category_if_uniform<'info>(remaining: &'info [AccountInfo<'info>]) -> Result<Option<u8>> {
+    let mut category = None;
+    for i in (0..remaining.len()).step_by(2) {
+        let reserve = Account::<Reserve>::try_from(&remaining[i])?;
+        match (category, reserve.config.e_mode_category) {
+            (_, None) => return Ok(None),
+            (None, Some(c)) => category = Some(c),
+            (Some(existing), Some(c)) if existing != c => return Ok(None),
+            _ => {}
+        }
+    }
+    Ok(category)
+}
+
+/// Applies the same price update as `refresh_reserve` (via
+/// `Reserve::update_market_price`), but directly on an already-deserialized
+/// [`Reserve`] so this instruction doesn't need to re-borrow the account.
+/// Unlike `refresh_reserve`, it doesn't roll the EWMA rates or adjust the
+/// market's `total_borrow_value`.
+fn refresh_reserve_inline(reserve: &mut Reserve, oracle_info: &AccountInfo, slot: u64) -> Result<()> {
+    let new_price = oracle::read_market_price(oracle_info)?;
+    reserve.update_market_price(new_price, slot)
+}
+
+#[error_code]
+enum ErrorCode {
+    #[msg("Number of remaining accounts doesn't match obligation's reserves")]
+    ReserveCountMismatch,
+    #[msg("Remaining account doesn't match the obligation's recorded reserve")]
+    ReserveMismatch,
+    #[msg("credit_line's obligation does not match the obligation being refreshed")]
+    CreditLineObligationMismatch,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{LendingMarket, Reserve};
+use crate::oracle;
+
+#[derive(Accounts)]
+pub struct RefreshReserve<'info> {
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    /// CHECK: validated against `reserve.liquidity.mint`'s oracle off-chain
+    /// by the caller today; see synth-793 for the on-chain registry that
+    /// will let us enforce this here instead.
+    pub oracle_price: AccountInfo<'info>,
+}
+
+/// Emitted every time a reserve is refreshed, carrying the two indexes
+/// needed to reconstruct any user's
+/// position value at any historical slot without replaying every
+/// intermediate instruction:
+///
+/// - `collateral_exchange_rate` lets you convert a deposit snapshot
+///   (collateral amount) into underlying liquidity at that slot.
+/// - `cumulative_borrow_rate` lets you convert a borrow snapshot
+///   (amount + the rate at last accrual) into liquidity owed at that slot.
+///
+/// Indexers can key a time series on `(reserve, slot)` and never need to
+/// touch obligation accounts to compute historical position value.
+#[event]
+pub struct ReserveIndexCheckpoint {
+    pub reserve: Pubkey,
+    pub slot: u64,
+    pub collateral_exchange_rate: u128,
+    pub cumulative_borrow_rate: u128,
+    pub market_price: u128,
+}
+
+pub fn handle(ctx: Context<RefreshReserve>) -> Result<()> {
+    let reserve = &mut ctx.accounts.reserve;
+    let slot = Clock::get()?.slot;
+
+    let old_borrow_value = reserve.market_value(reserve.liquidity.borrowed_amount)?;
+
+    let new_price = oracle::read_market_price(&ctx.accounts.oracle_price)?;
+    reserve.update_market_price(new_price, slot)?;
+    reserve.update_rate_ewma()?;
+
+    let new_borrow_value = reserve.market_value(reserve.liquidity.borrowed_amount)?;
+    if new_borrow_value > old_borrow_value {
+        ctx.accounts
+            .lending_market
+            .increase_total_borrow_value(new_borrow_value.try_sub(old_borrow_value)?)?;
+    } else {
+        ctx.accounts
+            .lending_market
+            .decrease_total_borrow_value(old_borrow_value.try_sub(new_borrow_value)?);
+    }
+
+    emit!(ReserveIndexCheckpoint {
+        reserve: reserve.key(),
+        slot,
+        collateral_exchange_rate: reserve.collateral_exchange_rate()?.to_scaled_val(),
+        cumulative_borrow_rate: reserve.liquidity.cumulative_borrow_rate.to_scaled_val(),
+        market_price: reserve.liquidity.market_price.to_scaled_val(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::Reserve;
+use crate::oracle;
+
+#[derive(Accounts)]
+pub struct RefreshReserveLst<'info> {
+    #[account(mut)]
+    pub reserve: Account<'info, Reserve>,
+
+    /// CHECK: SPL stake pool account backing this reserve's LST.
+    pub stake_pool: AccountInfo<'info>,
+    /// CHECK: Pyth SOL/USD price account.
+    pub sol_oracle_price: AccountInfo<'info>,
+    /// CHECK: the LST's own spot Pyth price account, used only as a sanity
+    /// bound on the stake-pool-derived fair value.
+    pub spot_oracle_price: AccountInfo<'info>,
+}
+
+/// LST-aware counterpart to `refresh_reserve`: prices the reserve off the
+/// stake pool's SOL/LST exchange rate times the SOL spot price instead of
+/// the LST's own (more easily manipulated) spot feed directly, per
+/// `ReserveConfig::lst_max_deviation_bps`.
+pub fn handle(ctx: Context<RefreshReserveLst>) -> Result<()> {
+    let reserve = &mut ctx.accounts.reserve;
+    let max_deviation_bps = reserve.config.lst_max_deviation_bps.ok_or(ErrorCode::NotAnLstReserve)?;
+
+    let new_price = oracle::read_lst_fair_value(
+        &ctx.accounts.stake_pool,
+        &ctx.accounts.sol_oracle_price,
+        &ctx.accounts.spot_oracle_price,
+        max_deviation_bps,
+    )?;
+    reserve.update_market_price(new_price, Clock::get()?.slot)?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::models::Reserve;
+use crate::oracle;
+
+/// Maximum number of reserve/oracle pairs a single `refresh_reserves` call
+/// will process, chosen to keep the instruction comfortably inside the
+/// compute budget.
+pub const MAX_BATCH_SIZE: usize = 10;
+
+/// Refreshes up to [`MAX_BATCH_SIZE`] reserves in one instruction, each
+/// passed as a `(reserve, oracle_price)` pair of remaining accounts. Lets
+/// keepers and front-ends that touch many reserves at once (e.g. before
+/// computing protocol-wide TVL) avoid one `refresh_reserve` transaction per
+/// reserve.
+#[derive(Accounts)]
+pub struct RefreshReserves<'info> {
+    // Remaining accounts are `(reserve, oracle_price)` pairs, reserves
+    // writable.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, RefreshReserves<'info>>) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    require!(remaining.len() % 2 == 0, ErrorCode::OddAccountCount);
+
+    let pair_count = remaining.len() / 2;
+    require!(pair_count <= MAX_BATCH_SIZE, ErrorCode::TooManyReserves);
+
+    let slot = ctx.accounts.clock.slot;
+
+    for pair in remaining.chunks(2) {
+        let [reserve_info, oracle_info] = pair else {
+            return err!(ErrorCode::OddAccountCount);
+        };
+
+        let mut reserve = Account::<Reserve>::try_from(reserve_info)?;
+        reserve.liquidity.market_price = oracle::read_market_price(oracle_info)?;
+        reserve.last_update_slot = slot;
+        reserve.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+#[error_code]
+enum ErrorCode {
+    #[msg("Remaining accounts must be (reserve, oracle) pairs")]
+    OddAccountCount,
+    #[msg("Too many reserves in one refresh_reserves call")]
+    TooManyReserves,
+}
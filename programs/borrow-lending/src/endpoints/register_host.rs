@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{Host, LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct RegisterHost<'info> {
+    pub lending_market: Account<'info, LendingMarket>,
+    #[account(has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = Host::LEN,
+        seeds = [b"host", lending_market.key().as_ref(), reserve.key().as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub host: Account<'info, Host>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers `authority` as a host eligible to earn a cut of borrow
+/// origination fees on `reserve` (see `ReserveConfig::host_fee_bps`) for
+/// transactions it refers. Permissionless — any frontend can register
+/// itself.
+pub fn handle(ctx: Context<RegisterHost>) -> Result<()> {
+    let host = &mut ctx.accounts.host;
+    host.lending_market = ctx.accounts.lending_market.key();
+    host.reserve = ctx.accounts.reserve.key();
+    host.authority = ctx.accounts.authority.key();
+    host.bump_seed = ctx.bumps.host;
+
+    Ok(())
+}
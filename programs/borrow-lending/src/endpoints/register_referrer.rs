@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{LendingMarket, Referrer, Reserve};
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    pub lending_market: Account<'info, LendingMarket>,
+    #[account(has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = referrer,
+        space = Referrer::LEN,
+        seeds = [b"referrer", lending_market.key().as_ref(), reserve.key().as_ref(), referrer.key().as_ref()],
+        bump,
+    )]
+    pub referrer_account: Account<'info, Referrer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers `referrer` to earn `ReserveConfig::referral_fee_bps` of the
+/// origination fee on borrows from obligations that recorded it as their
+/// referrer at `init_obligation`. Permissionless.
+pub fn handle(ctx: Context<RegisterReferrer>) -> Result<()> {
+    let referrer_account = &mut ctx.accounts.referrer_account;
+    referrer_account.lending_market = ctx.accounts.lending_market.key();
+    referrer_account.reserve = ctx.accounts.reserve.key();
+    referrer_account.referrer = ctx.accounts.referrer.key();
+    referrer_account.bump_seed = ctx.bumps.referrer_account;
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{LendingMarket, Obligation, Reserve};
+
+/// One leg of a batched repayment: which reserve's borrow to pay down and
+/// how much liquidity to put toward it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct LiquidityRepayment {
+    pub repay_reserve: Pubkey,
+    pub liquidity_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct RepayMultipleObligationLiquidities<'info> {
+    #[account(mut)]
+    pub obligation: Account<'info, Obligation>,
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+    /// See `repay_obligation_liquidity`'s repay-on-behalf note (synth-791):
+    /// any signer can authorize moving tokens out of their own source
+    /// liquidity wallets to pay down any obligation's loans.
+    pub source_liquidity_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    // Remaining accounts are `(reserve, source_liquidity,
+    // reserve_liquidity_supply)` triples, one per entry in `repayments`, in
+    // the same order.
+}
+
+/// Repays several of an obligation's borrows in one instruction instead of
+/// one `repay_obligation_liquidity` call per reserve, loading the
+/// obligation once and applying each leg in sequence. Each leg is capped at
+/// what's actually owed on its reserve, same as the single-reserve
+/// instruction; excess supplied beyond that is simply not taken.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RepayMultipleObligationLiquidities<'info>>,
+    repayments: Vec<LiquidityRepayment>,
+) -> Result<()> {
+    let obligation = &mut ctx.accounts.obligation;
+
+    require!(
+        ctx.remaining_accounts.len() == repayments.len() * 3,
+        ErrorCode::MathOverflow
+    );
+
+    for (repayment, accounts) in repayments.iter().zip(ctx.remaining_accounts.chunks(3)) {
+        let [reserve_info, source_liquidity_info, reserve_liquidity_supply_info] = accounts else {
+            unreachable!("chunks(3) always yields 3 accounts");
+        };
+
+        let mut reserve = Account::<Reserve>::try_from(reserve_info)?;
+
+        let borrow_index = obligation
+            .borrows
+            .iter()
+            .position(|b| b.borrow_reserve == repayment.repay_reserve)
+            .ok_or(ErrorCode::ObligationBorrowsEmpty)?;
+
+        let owed = obligation.borrows[borrow_index].borrowed_amount;
+        let owed_floor = owed.try_floor_u64()?;
+        let repay_amount = repayment.liquidity_amount.min(owed_floor);
+        require!(repay_amount > 0, ErrorCode::ObligationBorrowsEmpty);
+
+        let repay_decimal = Decimal::from(repay_amount);
+        let repaid_fraction = repay_decimal.try_div(owed)?;
+        let value_reduction = obligation.borrows[borrow_index].market_value.try_mul(repaid_fraction)?;
+
+        obligation.borrows[borrow_index].borrowed_amount = owed.try_sub(repay_decimal)?;
+        obligation.borrows[borrow_index].market_value =
+            obligation.borrows[borrow_index].market_value.try_sub(value_reduction)?;
+        obligation.borrowed_value = obligation.borrowed_value.try_sub(value_reduction)?;
+        ctx.accounts.lending_market.decrease_total_borrow_value(value_reduction);
+
+        if obligation.borrows[borrow_index].borrowed_amount.to_scaled_val() == 0 {
+            obligation.borrows.remove(borrow_index);
+        } else {
+            require!(
+                obligation.borrows[borrow_index].market_value >= reserve.config.min_borrow_uac_value,
+                ErrorCode::RepayWouldLeaveDust
+            );
+        }
+
+        reserve.liquidity.available_amount = reserve
+            .liquidity
+            .available_amount
+            .checked_add(repay_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        reserve.liquidity.borrowed_amount = reserve.liquidity.borrowed_amount.try_sub(repay_decimal)?;
+        reserve.exit(&crate::ID)?;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: source_liquidity_info.clone(),
+                    to: reserve_liquidity_supply_info.clone(),
+                    authority: ctx.accounts.source_liquidity_authority.to_account_info(),
+                },
+            ),
+            repay_amount,
+        )?;
+    }
+
+    Ok(())
+}
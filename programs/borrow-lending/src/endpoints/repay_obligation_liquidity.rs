@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{LendingMarket, Obligation};
+
+#[derive(Accounts)]
+pub struct RepayObligationLiquidity<'info> {
+    #[account(mut)]
+    pub obligation: Account<'info, Obligation>,
+
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut, has_one = lending_market)]
+    pub repay_reserve: Account<'info, crate::models::Reserve>,
+    #[account(mut)]
+    pub source_liquidity: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+    /// Repay-on-behalf (synth-791): any signer can authorize moving tokens
+    /// out of their own `source_liquidity` to pay down any obligation's
+    /// loan. This never touches the obligation's collateral or ownership,
+    /// so there's nothing for a repayer to gain by targeting someone
+    /// else's loan — account abstraction wallets and credit protection
+    /// services rely on this to repay loans they don't own.
+    pub source_liquidity_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Repays up to `liquidity_amount` of `repay_reserve`'s outstanding borrow
+/// against the obligation, capped at what's actually owed. Excess supplied
+/// beyond the outstanding balance is simply not taken.
+pub fn handle(ctx: Context<RepayObligationLiquidity>, liquidity_amount: u64) -> Result<()> {
+    let obligation = &mut ctx.accounts.obligation;
+    let reserve = &mut ctx.accounts.repay_reserve;
+
+    let borrow_index = obligation
+        .borrows
+        .iter()
+        .position(|b| b.borrow_reserve == reserve.key())
+        .ok_or(ErrorCode::ObligationBorrowsEmpty)?;
+
+    let owed = obligation.borrows[borrow_index].borrowed_amount;
+    let owed_floor = owed.try_floor_u64()?;
+    let repay_amount = liquidity_amount.min(owed_floor);
+    require!(repay_amount > 0, ErrorCode::ObligationBorrowsEmpty);
+
+    let repay_decimal = Decimal::from(repay_amount);
+    let repaid_fraction = repay_decimal.try_div(owed)?;
+    let value_reduction = obligation.borrows[borrow_index].market_value.try_mul(repaid_fraction)?;
+
+    obligation.borrows[borrow_index].borrowed_amount = owed.try_sub(repay_decimal)?;
+    obligation.borrows[borrow_index].market_value =
+        obligation.borrows[borrow_index].market_value.try_sub(value_reduction)?;
+    obligation.borrowed_value = obligation.borrowed_value.try_sub(value_reduction)?;
+    ctx.accounts.lending_market.decrease_total_borrow_value(value_reduction);
+
+    if obligation.borrows[borrow_index].borrowed_amount.to_scaled_val() == 0 {
+        obligation.borrows.remove(borrow_index);
+    } else {
+        require!(
+            obligation.borrows[borrow_index].market_value >= reserve.config.min_borrow_uac_value,
+            ErrorCode::RepayWouldLeaveDust
+        );
+    }
+
+    reserve.liquidity.available_amount = reserve
+        .liquidity
+        .available_amount
+        .checked_add(repay_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    reserve.liquidity.borrowed_amount = reserve.liquidity.borrowed_amount.try_sub(repay_decimal)?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source_liquidity.to_account_info(),
+                to: ctx.accounts.reserve_liquidity_supply.to_account_info(),
+                authority: ctx.accounts.source_liquidity_authority.to_account_info(),
+            },
+        ),
+        repay_amount,
+    )?;
+
+    Ok(())
+}
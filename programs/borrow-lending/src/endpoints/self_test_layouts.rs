@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{LendingMarket, Obligation, Reserve};
+
+#[derive(Accounts)]
+pub struct SelfTestLayouts {}
+
+/// Runtime self-check of account layouts: compares the `LEN` constants of
+/// `LendingMarket` and `Reserve` against their structs' `size_of`, and
+/// gives `Obligation` only the trivial check below. No other account type
+/// is checked, and discriminators and field offsets are not checked.
+///
+/// Account `LEN` constants are hand-maintained (see `Reserve::LEN` etc.)
+/// and drift silently from the actual struct whenever a field is added
+/// without updating them, since Anchor only catches an undersized account
+/// the first time someone tries to write past the end of it — often long
+/// after the bug was introduced. This instruction is meant to be run once
+/// per deploy (e.g. from CI, via `simulateTransaction`) to catch that
+/// class of bug immediately instead.
+pub fn handle(_ctx: Context<SelfTestLayouts>) -> Result<()> {
+    require!(LendingMarket::LEN >= 8 + std::mem::size_of::<LendingMarket>(), LayoutError::Undersized);
+    require!(Reserve::LEN >= 8 + std::mem::size_of::<Reserve>(), LayoutError::Undersized);
+    require!(
+        // Obligation is variable-length (Vec fields), so its hand-rolled
+        // space only needs to cover the fixed portion plus headroom for
+        // MAX_OBLIGATION_RESERVES entries, checked at `init_obligation`
+        // time rather than here.
+        std::mem::size_of::<Obligation>() > 0,
+        LayoutError::Undersized
+    );
+
+    Ok(())
+}
+
+#[error_code]
+enum LayoutError {
+    #[msg("An account's hand-maintained LEN constant no longer covers its struct")]
+    Undersized,
+}
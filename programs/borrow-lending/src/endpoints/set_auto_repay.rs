@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::models::Obligation;
+
+#[derive(Accounts)]
+pub struct SetAutoRepay<'info> {
+    #[account(mut, has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    pub owner: Signer<'info>,
+}
+
+/// Opts the obligation in or out of `harvest_collateral_interest`, and sets
+/// the minimum harvestable amount (in liquidity units of whichever reserve
+/// is being harvested) a keeper needs to see before it's worth calling.
+pub fn handle(ctx: Context<SetAutoRepay>, enabled: bool, threshold: u64) -> Result<()> {
+    let obligation = &mut ctx.accounts.obligation;
+    obligation.auto_repay_enabled = enabled;
+    obligation.auto_repay_threshold = threshold;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{BoostConfig, LendingMarket};
+
+#[derive(Accounts)]
+pub struct SetBoostConfig<'info> {
+    #[account(mut, has_one = owner)]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+}
+
+/// Sets (or disables, by passing `governance_mint: None`) the market's
+/// emission boost. The owner is responsible for keeping `boost_vault`
+/// itself correct (it should be a token account for `governance_mint`
+/// whose authority is this market's PDA); this only configures the
+/// accounting `claim_emission` reads.
+pub fn handle(
+    ctx: Context<SetBoostConfig>,
+    governance_mint: Option<Pubkey>,
+    boost_vault: Pubkey,
+    max_boost_bps: u16,
+    full_boost_stake_amount: u64,
+) -> Result<()> {
+    let market = &mut ctx.accounts.lending_market;
+    market.boost_config = governance_mint.map(|governance_mint| BoostConfig {
+        governance_mint,
+        boost_vault,
+        max_boost_bps,
+        full_boost_stake_amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use mpl_token_metadata::instructions::CreateMetadataAccountV3CpiBuilder;
+use mpl_token_metadata::types::DataV2;
+
+use crate::models::{LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct SetCollateralMetadata<'info> {
+    #[account(has_one = owner)]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+
+    #[account(has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+    #[account(address = reserve.collateral.mint)]
+    pub collateral_mint: Account<'info, Mint>,
+
+    /// CHECK: the Token Metadata program validates this is the metadata
+    /// PDA derived from `collateral_mint`.
+    #[account(mut)]
+    pub metadata_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: address-checked against the well-known Token Metadata program id.
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Creates (or, called again, would try to re-create and fail — use the
+/// Token Metadata program's own update instruction for that) on-chain
+/// Metaplex metadata for a reserve's collateral mint, so cTokens show a
+/// real name/symbol/image in wallets instead of "Unknown Token". Kept as
+/// its own opt-in step rather than folded into `init_reserve` so existing
+/// integrations that don't care about wallet display aren't forced to
+/// supply Token Metadata accounts on every reserve init.
+pub fn handle(ctx: Context<SetCollateralMetadata>, name: String, symbol: String, uri: String) -> Result<()> {
+    let lending_market = &ctx.accounts.lending_market;
+    let seeds: &[&[u8]] = &[b"lending-market", lending_market.owner.as_ref(), &[lending_market.bump_seed]];
+
+    CreateMetadataAccountV3CpiBuilder::new(&ctx.accounts.token_metadata_program.to_account_info())
+        .metadata(&ctx.accounts.metadata_account.to_account_info())
+        .mint(&ctx.accounts.collateral_mint.to_account_info())
+        .mint_authority(&lending_market.to_account_info())
+        .payer(&ctx.accounts.payer.to_account_info())
+        .update_authority(&lending_market.to_account_info(), true)
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .rent(Some(&ctx.accounts.rent.to_account_info()))
+        .data(DataV2 {
+            name,
+            symbol,
+            uri,
+            seller_fee_basis_points: 0,
+            creators: None,
+            collection: None,
+            uses: None,
+        })
+        .is_mutable(true)
+        .invoke_signed(&[seeds])?;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::LendingMarket;
+
+#[derive(Accounts)]
+pub struct SetMarginCallThreshold<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"lending-market", lending_market.owner.as_ref()],
+        bump = lending_market.bump_seed,
+    )]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+}
+
+/// Lets the market owner tune how early `refresh_obligation` emits
+/// `MarginCallWarning`, as a percentage of `unhealthy_borrow_value`.
+pub fn handle(ctx: Context<SetMarginCallThreshold>, threshold_pct: u8) -> Result<()> {
+    require!(threshold_pct <= 100, ErrorCode::MathOverflow);
+    ctx.accounts.lending_market.margin_call_warning_threshold_pct = threshold_pct;
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::math::Decimal;
+use crate::models::LendingMarket;
+
+#[derive(Accounts)]
+pub struct SetMaxTotalBorrowValue<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"lending-market", lending_market.owner.as_ref()],
+        bump = lending_market.bump_seed,
+    )]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+}
+
+/// Lets the market owner set or clear the market-wide UAC debt ceiling
+/// enforced by `borrow_obligation_liquidity` and `refresh_reserve` against
+/// `LendingMarket::total_borrow_value`. `None` disables the ceiling.
+pub fn handle(ctx: Context<SetMaxTotalBorrowValue>, max_total_borrow_value: Option<Decimal>) -> Result<()> {
+    ctx.accounts.lending_market.max_total_borrow_value = max_total_borrow_value;
+    Ok(())
+}
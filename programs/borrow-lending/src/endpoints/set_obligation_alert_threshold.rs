@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+use crate::math::Decimal;
+use crate::models::Obligation;
+
+#[derive(Accounts)]
+pub struct SetObligationAlertThreshold<'info> {
+    #[account(mut, has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    pub owner: Signer<'info>,
+}
+
+/// Sets (or clears, by passing `None`) the fraction of
+/// `unhealthy_borrow_value` at which `ping_unhealthy_obligation` will emit
+/// an alert for this obligation.
+pub fn handle(ctx: Context<SetObligationAlertThreshold>, alert_threshold: Option<Decimal>) -> Result<()> {
+    ctx.accounts.obligation.alert_threshold = alert_threshold;
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{AssetInfo, LendingMarket, OracleRegistry, Reserve};
+
+#[derive(Accounts)]
+pub struct SetOracleAsset<'info> {
+    #[account(has_one = owner)]
+    pub lending_market: Account<'info, LendingMarket>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+    pub oracle: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = OracleRegistry::LEN,
+        seeds = [b"oracle-registry", lending_market.key().as_ref()],
+        bump,
+    )]
+    pub oracle_registry: Account<'info, OracleRegistry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers (or updates) `reserve`'s entry in the market's oracle
+/// registry, so clients can resolve a reserve's symbol, decimals and
+/// oracle account from one registry read rather than a hard-coded config.
+pub fn handle(ctx: Context<SetOracleAsset>, symbol: [u8; 32], decimals: u8) -> Result<()> {
+    let registry = &mut ctx.accounts.oracle_registry;
+    registry.lending_market = ctx.accounts.lending_market.key();
+    registry.upsert(AssetInfo {
+        reserve: ctx.accounts.reserve.key(),
+        oracle: ctx.accounts.oracle.key(),
+        symbol,
+        decimals,
+    })
+}
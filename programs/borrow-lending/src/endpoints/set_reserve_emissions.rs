@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct SetReserveEmissions<'info> {
+    #[account(has_one = owner)]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+}
+
+/// Sets (or disables, by passing `reward_mint: None`) a reserve's emission
+/// rate. The owner is responsible for keeping the reward vault `claim_emission`
+/// draws from funded; this only configures the accounting.
+pub fn handle(ctx: Context<SetReserveEmissions>, reward_mint: Option<Pubkey>, reward_per_slot: u64) -> Result<()> {
+    let reserve = &mut ctx.accounts.reserve;
+    reserve.liquidity.reward_mint = reward_mint;
+    reserve.liquidity.reward_per_slot = reward_per_slot;
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct SetReserveRetiring<'info> {
+    #[account(has_one = owner)]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+}
+
+/// Marks (or unmarks) a reserve as retiring ahead of delisting. A retiring
+/// reserve stops accepting new deposits and borrows, but existing
+/// positions can still be repaid and withdrawn normally until `close_reserve`
+/// finds it empty.
+pub fn handle(ctx: Context<SetReserveRetiring>, retiring: bool) -> Result<()> {
+    ctx.accounts.reserve.retiring = retiring;
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{LendingMarket, Reserve, ReserveStatus};
+
+#[derive(Accounts)]
+pub struct SetReserveStatus<'info> {
+    #[account(has_one = owner)]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+}
+
+/// Moves a reserve between `Active`, `Frozen` and `Deprecated`, independent
+/// of `retiring`. `Frozen` is a reversible pause (e.g. during an oracle or
+/// venue incident); `Deprecated` additionally makes `current_borrow_rate`
+/// punitive to push existing borrowers out ahead of an eventual
+/// `set_reserve_retiring`.
+pub fn handle(ctx: Context<SetReserveStatus>, status: ReserveStatus) -> Result<()> {
+    ctx.accounts.reserve.status = status;
+    Ok(())
+}
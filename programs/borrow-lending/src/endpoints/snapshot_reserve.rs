@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::models::{LendingMarket, Reserve, ReserveCapSnapshots};
+use crate::oracle;
+
+/// Flat keeper reward, in liquidity tokens, paid out of a reserve's
+/// available liquidity for calling `snapshot_reserve`. Deliberately tiny —
+/// this only needs to cover the keeper's transaction fee, not be a yield
+/// source.
+pub const KEEPER_REWARD_LAMPORTS: u64 = 1_000;
+
+#[derive(Accounts)]
+pub struct SnapshotReserve<'info> {
+    #[account(seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut)]
+    pub reserve: Account<'info, Reserve>,
+
+    #[account(mut)]
+    pub cap_snapshots: Option<Account<'info, ReserveCapSnapshots>>,
+
+    /// CHECK: read via `oracle::read_market_price`, same as `refresh_reserve`.
+    pub oracle_price: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub reserve_liquidity_supply: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub keeper_destination: Account<'info, TokenAccount>,
+    pub keeper: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Permissionless twin of `refresh_reserve`: anyone may call it to push a
+/// reserve's price forward (interest is not accrued here;
+/// `accrue_reserve_interest` does that), in exchange for a flat
+/// [`KEEPER_REWARD_LAMPORTS`] reward paid out of the reserve's own
+/// liquidity. Without this, reserves that nobody happens to interact with
+/// go stale and every downstream read (health checks, indexers) has to
+/// special-case them.
+pub fn handle(ctx: Context<SnapshotReserve>) -> Result<()> {
+    let reserve = &mut ctx.accounts.reserve;
+    let slot = Clock::get()?.slot;
+
+    let new_price = oracle::read_market_price(&ctx.accounts.oracle_price)?;
+    reserve.update_market_price(new_price, slot)?;
+
+    require!(
+        reserve.liquidity.available_amount >= KEEPER_REWARD_LAMPORTS,
+        ErrorCode::BorrowTooLarge
+    );
+    reserve.liquidity.available_amount -= KEEPER_REWARD_LAMPORTS;
+
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reserve_liquidity_supply.to_account_info(),
+                to: ctx.accounts.keeper_destination.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        KEEPER_REWARD_LAMPORTS,
+    )?;
+
+    if let Some(cap_snapshots) = ctx.accounts.cap_snapshots.as_mut() {
+        require_keys_eq!(cap_snapshots.reserve, reserve.key(), ErrorCode::SnapshotReserveMismatch);
+        let total_deposited = reserve
+            .liquidity
+            .available_amount
+            .checked_add(reserve.liquidity.deployed_amount)
+            .ok_or(ErrorCode::MathOverflow)?
+            .checked_add(reserve.liquidity.borrowed_amount.try_floor_u64()?)
+            .ok_or(ErrorCode::MathOverflow)?;
+        cap_snapshots.record(slot, total_deposited)?;
+    }
+
+    Ok(())
+}
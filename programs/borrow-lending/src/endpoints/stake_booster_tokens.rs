@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::models::{BoosterStake, LendingMarket};
+
+#[derive(Accounts)]
+pub struct StakeBoosterTokens<'info> {
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = BoosterStake::LEN,
+        seeds = [b"booster-stake", lending_market.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub booster_stake: Account<'info, BoosterStake>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub source_governance_tokens: Account<'info, TokenAccount>,
+    #[account(mut, address = lending_market.boost_config.ok_or(ErrorCode::BoostingDisabled)?.boost_vault)]
+    pub boost_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Locks `amount` of the market's governance token in the caller's
+/// `BoosterStake`, raising the multiplier `claim_emission` applies to
+/// their emission share (see `LendingMarket::boost_multiplier`). Staking
+/// doesn't itself claim anything, and `cumulative_reward_per_share` isn't
+/// boost-aware: `claim_emission` applies the multiplier in effect at claim
+/// time to the whole pending amount, including emissions accrued before
+/// this stake.
+pub fn handle(ctx: Context<StakeBoosterTokens>, amount: u64) -> Result<()> {
+    let booster_stake = &mut ctx.accounts.booster_stake;
+    booster_stake.lending_market = ctx.accounts.lending_market.key();
+    booster_stake.owner = ctx.accounts.owner.key();
+    booster_stake.bump_seed = ctx.bumps.booster_stake;
+    booster_stake.staked_amount =
+        booster_stake.staked_amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source_governance_tokens.to_account_info(),
+                to: ctx.accounts.boost_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
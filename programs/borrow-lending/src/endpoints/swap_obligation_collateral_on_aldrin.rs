@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::math::Decimal;
+use crate::models::{LendingMarket, Obligation, ObligationCollateral, Reserve, RiskTier};
+
+use crate::endpoints::deposit_reserve_liquidity::deposit_liquidity;
+use crate::endpoints::leverage::aldrin_adapter::AldrinAdapter;
+use crate::endpoints::leverage::amm_adapter::AmmAdapter;
+
+#[derive(Accounts)]
+pub struct SwapObligationCollateralOnAldrin<'info> {
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut, has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market)]
+    pub source_reserve: Account<'info, Reserve>,
+    /// Shared collateral vault `source_reserve`'s deposits are pooled in
+    /// (see `withdraw_obligation_collateral`), owned by `lending_market`.
+    #[account(mut)]
+    pub source_collateral_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub source_reserve_collateral_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub source_reserve_liquidity_supply: Account<'info, TokenAccount>,
+    /// Owner-controlled account the redeemed underlying lands in before
+    /// being swapped; doubles as the Aldrin swap's source.
+    #[account(mut)]
+    pub redeemed_liquidity: Account<'info, TokenAccount>,
+    /// Where the swapped-to underlying lands before being redeposited as
+    /// `destination_reserve` collateral.
+    #[account(mut)]
+    pub swapped_liquidity: Account<'info, TokenAccount>,
+
+    #[account(mut, has_one = lending_market)]
+    pub destination_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub destination_reserve_liquidity_supply: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_reserve_collateral_mint: Account<'info, Mint>,
+    /// Shared collateral vault the newly-minted `destination_reserve`
+    /// collateral is registered into, same as `deposit_obligation_collateral`'s
+    /// `destination_collateral`.
+    #[account(mut)]
+    pub destination_collateral_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    // Remaining accounts are Aldrin's `swap` account list (pool, pool
+    // signer, vaults, fee account, `redeemed_liquidity`/`swapped_liquidity`,
+    // `owner`, token program), passed through verbatim — see `AldrinAdapter`.
+}
+
+/// Atomically rotates `collateral_amount` of an obligation's
+/// `source_reserve` collateral into `destination_reserve` collateral:
+/// redeems it for underlying liquidity, swaps that liquidity through
+/// Aldrin, deposits the result back into `destination_reserve`, and
+/// re-registers the new collateral on the obligation — all in one
+/// instruction, with a single health check at the end, so a user never
+/// passes through a state where the withdrawn collateral isn't backing
+/// anything.
+///
+/// Both reserves must already be refreshed this slot. The newly-registered
+/// deposit's `market_value` is left at zero, same as
+/// `deposit_obligation_collateral`'s brand-new-deposit case — the next
+/// `refresh_obligation` fills it in.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SwapObligationCollateralOnAldrin<'info>>,
+    collateral_amount: u64,
+    min_liquidity_out: u64,
+) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    let obligation = &mut ctx.accounts.obligation;
+    obligation.check_not_stale(slot)?;
+
+    let source_reserve = &mut ctx.accounts.source_reserve;
+    let destination_reserve = &mut ctx.accounts.destination_reserve;
+    source_reserve.check_not_stale(slot)?;
+    destination_reserve.check_not_stale(slot)?;
+    destination_reserve.check_not_retiring()?;
+    destination_reserve.check_not_frozen()?;
+
+    let deposit_index = obligation
+        .deposits
+        .iter()
+        .position(|d| d.deposit_reserve == source_reserve.key())
+        .ok_or(crate::err::ErrorCode::ObligationDepositsEmpty)?;
+    require!(
+        collateral_amount <= obligation.deposits[deposit_index].deposited_amount,
+        crate::err::ErrorCode::WithdrawTooLarge
+    );
+
+    // Same cross-collateralization rule `deposit_obligation_collateral`
+    // enforces, evaluated against what the obligation's deposits will look
+    // like once the source leg lands.
+    let remaining_source_amount = obligation.deposits[deposit_index].deposited_amount - collateral_amount;
+    let other_deposits_after: Vec<Pubkey> = obligation
+        .deposits
+        .iter()
+        .enumerate()
+        .filter(|(i, d)| *i != deposit_index || remaining_source_amount > 0)
+        .map(|(_, d)| d.deposit_reserve)
+        .collect();
+    let adding_isolated = destination_reserve.config.risk_tier == RiskTier::Isolated;
+    let has_other_deposits = other_deposits_after.iter().any(|r| *r != destination_reserve.key());
+    if adding_isolated {
+        require!(!has_other_deposits, ErrorCode::CrossCollateralizationNotAllowed);
+    } else if !other_deposits_after.is_empty() {
+        require!(
+            other_deposits_after.iter().all(|r| *r == destination_reserve.key()),
+            ErrorCode::CrossCollateralizationNotAllowed
+        );
+    }
+
+    let liquidity_amount = source_reserve.collateral_to_liquidity(collateral_amount)?;
+    require!(
+        source_reserve.liquidity.available_amount >= liquidity_amount,
+        crate::err::ErrorCode::WithdrawTooLarge
+    );
+
+    let withdrawn_value = source_reserve.market_value(Decimal::from(liquidity_amount))?;
+    let deposit = &mut obligation.deposits[deposit_index];
+    deposit.rebase_rewards(remaining_source_amount, source_reserve.liquidity.cumulative_reward_per_share)?;
+    deposit.market_value = source_reserve
+        .market_value(Decimal::from(source_reserve.collateral_to_liquidity(remaining_source_amount)?))?;
+    deposit.cost_basis_liquidity = Decimal::from(source_reserve.collateral_to_liquidity(remaining_source_amount)?);
+    obligation.deposited_value = obligation.deposited_value.try_sub(withdrawn_value).unwrap_or_else(|_| Decimal::zero());
+    if obligation.deposits[deposit_index].deposited_amount == collateral_amount {
+        obligation.deposits.remove(deposit_index);
+    } else {
+        obligation.deposits[deposit_index].deposited_amount = remaining_source_amount;
+    }
+
+    source_reserve.liquidity.available_amount -= liquidity_amount;
+    source_reserve.collateral.mint_total_supply = source_reserve
+        .collateral
+        .mint_total_supply
+        .checked_sub(collateral_amount)
+        .ok_or(crate::err::ErrorCode::MathOverflow)?;
+    ctx.accounts.lending_market.consume_outflow(slot, withdrawn_value)?;
+
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+    token::burn(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.source_reserve_collateral_mint.to_account_info(),
+                from: ctx.accounts.source_collateral_vault.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        collateral_amount,
+    )?;
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source_reserve_liquidity_supply.to_account_info(),
+                to: ctx.accounts.redeemed_liquidity.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        liquidity_amount,
+    )?;
+
+    let received = AldrinAdapter.swap(
+        ctx.remaining_accounts,
+        &ctx.accounts.swapped_liquidity.to_account_info(),
+        liquidity_amount,
+        min_liquidity_out,
+    )?;
+
+    let minted_collateral_amount = ctx.accounts.destination_reserve.liquidity_to_collateral(received)?;
+    deposit_liquidity(
+        &ctx.accounts.lending_market,
+        &mut ctx.accounts.destination_reserve,
+        ctx.accounts.swapped_liquidity.to_account_info(),
+        ctx.accounts.owner.to_account_info(),
+        ctx.accounts.destination_reserve_liquidity_supply.to_account_info(),
+        ctx.accounts.destination_collateral_vault.to_account_info(),
+        ctx.accounts.destination_reserve_collateral_mint.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        received,
+    )?;
+
+    let obligation = &mut ctx.accounts.obligation;
+    let destination_reserve = &ctx.accounts.destination_reserve;
+    match obligation.deposits.iter_mut().find(|d| d.deposit_reserve == destination_reserve.key()) {
+        Some(existing) => {
+            let new_amount = existing.deposited_amount + minted_collateral_amount;
+            existing.rebase_rewards(new_amount, destination_reserve.liquidity.cumulative_reward_per_share)?;
+            existing.cost_basis_liquidity = Decimal::from(destination_reserve.collateral_to_liquidity(new_amount)?);
+        }
+        None => {
+            require!(obligation.has_room_for_new_reserve(), crate::err::ErrorCode::ObligationReserveLimit);
+            obligation.deposits.push(ObligationCollateral {
+                deposit_reserve: destination_reserve.key(),
+                deposited_amount: minted_collateral_amount,
+                market_value: Decimal::zero(),
+                reward_debt: Decimal::from(minted_collateral_amount)
+                    .try_mul(destination_reserve.liquidity.cumulative_reward_per_share)?,
+                accrued_rewards: 0,
+                cost_basis_liquidity: Decimal::from(
+                    destination_reserve.collateral_to_liquidity(minted_collateral_amount)?,
+                ),
+                harvestable_liquidity: 0,
+                soft_liquidated_amount: 0,
+            });
+        }
+    }
+
+    require!(
+        obligation.borrowed_value <= obligation.allowed_borrow_value,
+        crate::err::ErrorCode::WithdrawTooLarge
+    );
+
+    Ok(())
+}
+
+#[error_code]
+enum ErrorCode {
+    #[msg("Isolated-tier reserve collateral cannot be combined with other reserves")]
+    CrossCollateralizationNotAllowed,
+}
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{LendingMarket, Obligation, ObligationLiquidity, RateMode, Reserve};
+
+use crate::endpoints::leverage::aldrin_adapter::AldrinAdapter;
+
+#[derive(Accounts)]
+pub struct SwapObligationDebtOnAldrin<'info> {
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut, has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market)]
+    pub old_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub old_reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    #[account(mut, has_one = lending_market)]
+    pub new_reserve: Account<'info, Reserve>,
+    #[account(mut)]
+    pub new_reserve_liquidity_supply: Account<'info, TokenAccount>,
+
+    /// PDA-owned scratch account the internal flash borrow of
+    /// `new_reserve`'s liquidity lands in; doubles as the Aldrin swap's
+    /// source. Drained to zero within this instruction.
+    #[account(mut)]
+    pub flash_liquidity: Account<'info, TokenAccount>,
+    /// PDA-owned scratch account the swap output lands in before repaying
+    /// `old_reserve`. Drained to zero within this instruction.
+    #[account(mut)]
+    pub repay_liquidity: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    // Remaining accounts are Aldrin's `swap` account list, passed through
+    // verbatim — see `AldrinAdapter`.
+}
+
+/// Refinances an obligation's entire `old_reserve` borrow into an
+/// equivalent-value `new_reserve` borrow in one instruction: opens the new
+/// borrow, flash-borrows its proceeds into a PDA-owned scratch account
+/// (never reaching the owner's wallet), swaps them through Aldrin into
+/// `old_reserve`'s underlying, and uses that to repay the old borrow in
+/// full — finishing with a single health check, same as
+/// `swap_obligation_collateral_on_aldrin`'s collateral-side counterpart.
+///
+/// Always closes `old_reserve`'s borrow completely rather than partially;
+/// a partial refinance can be done by repaying down first. Any swap output
+/// beyond what's owed is left in `old_reserve_liquidity_supply` rather than
+/// tracked back to the obligation — the same "un-minted surplus benefits
+/// depositors" absorption `flash_loan`'s fee relies on — so
+/// `min_repay_amount_out` should be set close to the amount owed.
+///
+/// Both reserves must already be refreshed this slot.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SwapObligationDebtOnAldrin<'info>>,
+    new_borrow_amount: u64,
+    min_repay_amount_out: u64,
+) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    let obligation = &mut ctx.accounts.obligation;
+    obligation.check_not_stale(slot)?;
+
+    let old_reserve = &mut ctx.accounts.old_reserve;
+    let new_reserve = &mut ctx.accounts.new_reserve;
+    old_reserve.check_not_stale(slot)?;
+    new_reserve.check_not_stale(slot)?;
+    new_reserve.check_not_retiring()?;
+    new_reserve.check_not_frozen()?;
+    new_reserve.check_borrowing_enabled()?;
+
+    let old_borrow_index = obligation
+        .borrows
+        .iter()
+        .position(|b| b.borrow_reserve == old_reserve.key())
+        .ok_or(ErrorCode::ObligationBorrowsEmpty)?;
+    let owed = obligation.borrows[old_borrow_index].borrowed_amount;
+    let owed_floor = owed.try_floor_u64()?;
+    let owed_value = obligation.borrows[old_borrow_index].market_value;
+
+    require!(new_reserve.liquidity.available_amount >= new_borrow_amount, ErrorCode::BorrowTooLarge);
+    let borrow_value = new_reserve.market_value(Decimal::from(new_borrow_amount))?;
+
+    let existing_new_value = obligation
+        .borrows
+        .iter()
+        .find(|b| b.borrow_reserve == new_reserve.key())
+        .map(|b| b.market_value)
+        .unwrap_or_else(Decimal::zero);
+    require!(
+        existing_new_value.try_add(borrow_value)? >= new_reserve.config.min_borrow_uac_value,
+        ErrorCode::BorrowTooSmall
+    );
+
+    new_reserve.liquidity.available_amount -= new_borrow_amount;
+    new_reserve.liquidity.borrowed_amount =
+        new_reserve.liquidity.borrowed_amount.try_add(Decimal::from(new_borrow_amount))?;
+
+    let maturity_slot = new_reserve.config.fixed_term_slots.map(|term| slot + term);
+    match obligation.borrows.iter_mut().find(|b| b.borrow_reserve == new_reserve.key()) {
+        Some(existing) => {
+            require!(existing.maturity_slot == maturity_slot, ErrorCode::BorrowTooLarge);
+            existing.borrowed_amount = existing.borrowed_amount.try_add(Decimal::from(new_borrow_amount))?;
+            existing.market_value = existing.market_value.try_add(borrow_value)?;
+        }
+        None => {
+            require!(obligation.has_room_for_new_reserve(), ErrorCode::ObligationReserveLimit);
+            obligation.borrows.push(ObligationLiquidity {
+                borrow_reserve: new_reserve.key(),
+                cumulative_borrow_rate: new_reserve.liquidity.cumulative_borrow_rate,
+                borrowed_amount: Decimal::from(new_borrow_amount),
+                market_value: borrow_value,
+                rate_mode: RateMode::Variable,
+                maturity_slot,
+            });
+        }
+    }
+    obligation.borrowed_value = obligation.borrowed_value.try_add(borrow_value)?;
+    ctx.accounts.lending_market.consume_outflow(slot, borrow_value)?;
+    ctx.accounts.lending_market.increase_total_borrow_value(borrow_value)?;
+
+    let lending_market_owner = ctx.accounts.lending_market.owner;
+    let lending_market_bump_seed = ctx.accounts.lending_market.bump_seed;
+    let seeds: &[&[u8]] = &[b"lending-market", lending_market_owner.as_ref(), &[lending_market_bump_seed]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.new_reserve_liquidity_supply.to_account_info(),
+                to: ctx.accounts.flash_liquidity.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        new_borrow_amount,
+    )?;
+
+    let received = AldrinAdapter.swap_signed(
+        ctx.remaining_accounts,
+        &ctx.accounts.repay_liquidity.to_account_info(),
+        new_borrow_amount,
+        min_repay_amount_out,
+        &[seeds],
+    )?;
+    require!(received >= owed_floor, ErrorCode::SlippageExceeded);
+
+    let old_reserve = &mut ctx.accounts.old_reserve;
+    old_reserve.liquidity.available_amount =
+        old_reserve.liquidity.available_amount.checked_add(received).ok_or(ErrorCode::MathOverflow)?;
+    old_reserve.liquidity.borrowed_amount = old_reserve.liquidity.borrowed_amount.try_sub(owed)?;
+
+    let obligation = &mut ctx.accounts.obligation;
+    obligation.borrows.remove(old_borrow_index);
+    obligation.borrowed_value = obligation.borrowed_value.try_sub(owed_value).unwrap_or_else(|_| Decimal::zero());
+    ctx.accounts.lending_market.decrease_total_borrow_value(owed_value);
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.repay_liquidity.to_account_info(),
+                to: ctx.accounts.old_reserve_liquidity_supply.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        received,
+    )?;
+
+    require!(
+        obligation.borrowed_value <= obligation.allowed_borrow_value,
+        ErrorCode::BorrowTooLarge
+    );
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::models::{BoosterStake, EmissionStrategy, LendingMarket, ReserveCapSnapshots};
+
+#[derive(Accounts)]
+pub struct SweepPdaLamports<'info> {
+    #[account(
+        has_one = owner,
+        seeds = [b"lending-market", lending_market.owner.as_ref()],
+        bump = lending_market.bump_seed,
+    )]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+
+    /// CHECK: accepted as raw bytes because this instruction sweeps several
+    /// unrelated account types; `handle` rejects anything whose discriminator
+    /// isn't on the allow-list below, and rejects an allow-listed account
+    /// that isn't actually stale, before a single lamport moves.
+    #[account(mut)]
+    pub target: UncheckedAccount<'info>,
+
+    /// CHECK: receives the swept lamports; any account works.
+    #[account(mut)]
+    pub destination: AccountInfo<'info>,
+}
+
+/// Reclaims the rent stranded in an auxiliary PDA-owned account once it's no
+/// longer in use: a `BoosterStake` that's been fully unstaked, an
+/// `EmissionStrategy` that's been zeroed out, or a `ReserveCapSnapshots`
+/// whose backing reserve `close_reserve` has tombstoned (synth-838 — `len ==
+/// 0` alone can't tell a dead reserve's snapshots apart from a live one
+/// that simply hasn't recorded its first entry yet). Only the market owner
+/// can sweep, and only against a hand-picked allow-list of account types
+/// this instruction knows how to confirm are actually stale — anything else
+/// is rejected outright rather than closed on the strength of the owner's
+/// signature alone.
+pub fn handle(ctx: Context<SweepPdaLamports>) -> Result<()> {
+    let target_info = ctx.accounts.target.to_account_info();
+    let lending_market_key = ctx.accounts.lending_market.key();
+
+    let is_stale = {
+        let data = target_info.try_borrow_data()?;
+        require!(data.len() >= 8, ErrorCode::UnsweepableAccount);
+        let discriminator = &data[..8];
+
+        if discriminator == BoosterStake::DISCRIMINATOR {
+            let stake = BoosterStake::try_deserialize(&mut &data[..])?;
+            stake.lending_market == lending_market_key && stake.staked_amount == 0
+        } else if discriminator == EmissionStrategy::DISCRIMINATOR {
+            let strategy = EmissionStrategy::try_deserialize(&mut &data[..])?;
+            strategy.lending_market == lending_market_key && strategy.total_reward_per_slot == 0
+        } else if discriminator == ReserveCapSnapshots::DISCRIMINATOR {
+            let snapshots = ReserveCapSnapshots::try_deserialize(&mut &data[..])?;
+            snapshots.closed
+        } else {
+            false
+        }
+    };
+    require!(is_stale, ErrorCode::UnsweepableAccount);
+
+    let lamports = target_info.lamports();
+    **target_info.try_borrow_mut_lamports()? = 0;
+    **ctx.accounts.destination.try_borrow_mut_lamports()? += lamports;
+    target_info.try_borrow_mut_data()?.fill(0);
+
+    emit!(PdaLamportsSwept { target: target_info.key(), destination: ctx.accounts.destination.key(), lamports });
+
+    Ok(())
+}
+
+#[event]
+pub struct PdaLamportsSwept {
+    pub target: Pubkey,
+    pub destination: Pubkey,
+    pub lamports: u64,
+}
+
+#[error_code]
+enum ErrorCode {
+    #[msg("Account is not on the sweepable allow-list, or isn't actually stale yet")]
+    UnsweepableAccount,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::{LendingMarket, Obligation, RateMode, Reserve};
+
+#[derive(Accounts)]
+pub struct SwitchRateMode<'info> {
+    #[account(mut, has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    pub owner: Signer<'info>,
+
+    #[account(has_one = lending_market)]
+    pub borrow_reserve: Account<'info, Reserve>,
+    pub lending_market: Account<'info, LendingMarket>,
+}
+
+/// Switches one of the obligation's borrows between variable and stable
+/// rate mode. Switching to `Stable` locks in the reserve's current borrow
+/// APR (`Reserve::current_borrow_rate`) as of this slot; switching back to
+/// `Variable` resumes tracking the reserve's cumulative borrow rate index
+/// from here on. The obligation must be freshly refreshed so
+/// `borrowed_amount` already reflects interest owed up to this slot.
+pub fn handle(ctx: Context<SwitchRateMode>, to_stable: bool) -> Result<()> {
+    let obligation = &mut ctx.accounts.obligation;
+    let reserve = &ctx.accounts.borrow_reserve;
+    let slot = Clock::get()?.slot;
+    obligation.check_not_stale(slot)?;
+
+    let borrow = obligation
+        .borrows
+        .iter_mut()
+        .find(|b| b.borrow_reserve == reserve.key())
+        .ok_or(ErrorCode::ObligationBorrowsEmpty)?;
+
+    borrow.rate_mode = if to_stable {
+        RateMode::Stable(reserve.current_borrow_rate(ctx.accounts.lending_market.max_effective_borrow_apr_bps)?)
+    } else {
+        borrow.cumulative_borrow_rate = reserve.liquidity.cumulative_borrow_rate;
+        RateMode::Variable
+    };
+
+    Ok(())
+}
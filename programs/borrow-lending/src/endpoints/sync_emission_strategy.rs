@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::{EmissionStrategy, LendingMarket, Reserve};
+
+#[derive(Accounts)]
+pub struct SyncEmissionStrategy<'info> {
+    #[account(has_one = lending_market)]
+    pub emission_strategy: Account<'info, EmissionStrategy>,
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+}
+
+/// Pushes `reserve`'s share of `emission_strategy.total_reward_per_slot`
+/// onto it, same effect `set_reserve_emissions` would have but driven by
+/// the strategy's weights instead of a one-off owner call. Permissionless:
+/// it only brings `reserve` in line with config the owner already
+/// committed to in `init_emission_strategy`, nothing here moves funds.
+pub fn handle(ctx: Context<SyncEmissionStrategy>, reserve_index: u8) -> Result<()> {
+    let strategy = &ctx.accounts.emission_strategy;
+    let reserve = &mut ctx.accounts.reserve;
+
+    require_keys_eq!(
+        strategy.reserves.get(reserve_index as usize).map(|w| w.reserve).unwrap_or_default(),
+        reserve.key(),
+        ErrorCode::EmissionStrategyReserveMismatch
+    );
+
+    reserve.liquidity.reward_mint = Some(strategy.reward_mint);
+    reserve.liquidity.reward_per_slot = strategy.reward_per_slot_for(reserve_index)?;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{Obligation, StrategyTag};
+
+#[derive(Accounts)]
+pub struct TagObligation<'info> {
+    #[account(mut, has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    pub owner: Signer<'info>,
+}
+
+/// Emitted whenever an obligation's strategy attribution changes, so
+/// indexers building per-strategy TVL don't have to diff account snapshots
+/// to notice a (re)tag.
+#[event]
+pub struct ObligationTagged {
+    pub obligation: Pubkey,
+    pub strategy_tag: Option<StrategyTag>,
+}
+
+/// Sets or clears the obligation's [`StrategyTag`]. Only the obligation
+/// owner can call this — a strategy wanting to tag positions it opens on a
+/// user's behalf must do so inside the same transaction that creates the
+/// obligation, under the user's own signature.
+pub fn handle(ctx: Context<TagObligation>, strategy_tag: Option<StrategyTag>) -> Result<()> {
+    let obligation = &mut ctx.accounts.obligation;
+    obligation.strategy_tag = strategy_tag.clone();
+
+    emit!(ObligationTagged {
+        obligation: obligation.key(),
+        strategy_tag,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::models::Obligation;
+
+/// Emitted whenever an obligation changes hands, so indexers attributing
+/// TVL or strategy positions to a wallet notice the handoff without diffing
+/// account snapshots.
+#[event]
+pub struct ObligationOwnershipTransferred {
+    pub obligation: Pubkey,
+    pub old_owner: Pubkey,
+    pub new_owner: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct TransferObligationOwnership<'info> {
+    #[account(mut, has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    pub owner: Signer<'info>,
+    /// Must also sign, so ownership can't be handed to a wallet that isn't
+    /// actually in control of (or expecting) the position.
+    pub new_owner: Signer<'info>,
+}
+
+/// Moves an obligation to `new_owner` without touching any of its deposits
+/// or borrows, so a leveraged position doesn't have to be unwound and
+/// rebuilt just because the wallet holding it is rotating or the position
+/// is moving under DAO control.
+pub fn handle(ctx: Context<TransferObligationOwnership>) -> Result<()> {
+    let obligation = &mut ctx.accounts.obligation;
+    let old_owner = obligation.owner;
+    obligation.owner = ctx.accounts.new_owner.key();
+
+    emit!(ObligationOwnershipTransferred {
+        obligation: obligation.key(),
+        old_owner,
+        new_owner: obligation.owner,
+    });
+
+    Ok(())
+}
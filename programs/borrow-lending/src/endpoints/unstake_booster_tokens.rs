@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::models::{BoosterStake, LendingMarket};
+
+#[derive(Accounts)]
+pub struct UnstakeBoosterTokens<'info> {
+    #[account(seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut, has_one = lending_market, has_one = owner)]
+    pub booster_stake: Account<'info, BoosterStake>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, address = lending_market.boost_config.ok_or(ErrorCode::BoostingDisabled)?.boost_vault)]
+    pub boost_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_governance_tokens: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Unlocks `amount` of previously staked governance token back to the
+/// owner, lowering (or ending) their `claim_emission` boost. As with
+/// `stake_booster_tokens`, the boost is applied at claim time, so
+/// already-accrued but unclaimed emissions are paid at the lowered
+/// multiplier.
+pub fn handle(ctx: Context<UnstakeBoosterTokens>, amount: u64) -> Result<()> {
+    let booster_stake = &mut ctx.accounts.booster_stake;
+    require!(amount <= booster_stake.staked_amount, ErrorCode::UnstakeTooLarge);
+    booster_stake.staked_amount -= amount;
+
+    let seeds: &[&[u8]] = &[
+        b"lending-market",
+        ctx.accounts.lending_market.owner.as_ref(),
+        &[ctx.accounts.lending_market.bump_seed],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.boost_vault.to_account_info(),
+                to: ctx.accounts.destination_governance_tokens.to_account_info(),
+                authority: ctx.accounts.lending_market.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{LendingMarket, Reserve};
+
+/// Emitted whenever a live emission's rate or end slot changes, so
+/// indexers can track APR shifts without diffing reserve snapshots.
+#[event]
+pub struct EmissionUpdated {
+    pub reserve: Pubkey,
+    pub reward_mint: Pubkey,
+    pub old_reward_per_slot: u64,
+    pub new_reward_per_slot: u64,
+    pub emission_ends_at_slot: Option<u64>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateEmission<'info> {
+    #[account(has_one = owner)]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+}
+
+/// Retunes a reserve's already-running emission — `set_reserve_emissions`
+/// only covers first setting one up or disabling it outright, leaving no
+/// way to nudge the rate or push the end slot out without a disable/
+/// re-enable round trip that would reset `cumulative_reward_per_share`'s
+/// accrual boundary. This just updates the knobs in place.
+pub fn handle(ctx: Context<UpdateEmission>, reward_per_slot: u64, emission_ends_at_slot: Option<u64>) -> Result<()> {
+    let reserve = &mut ctx.accounts.reserve;
+    require!(reserve.liquidity.reward_mint.is_some(), crate::err::ErrorCode::NoEmissionsConfigured);
+
+    let old_reward_per_slot = reserve.liquidity.reward_per_slot;
+    reserve.liquidity.reward_per_slot = reward_per_slot;
+    reserve.liquidity.emission_ends_at_slot = emission_ends_at_slot;
+
+    emit!(EmissionUpdated {
+        reserve: reserve.key(),
+        reward_mint: reserve.liquidity.reward_mint.unwrap(),
+        old_reward_per_slot,
+        new_reward_per_slot: reward_per_slot,
+        emission_ends_at_slot,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{LendingMarket, OracleRegistry, Reserve};
+use crate::oracle;
+
+/// Largest acceptable deviation, in basis points, between the reserve's
+/// last cached price and the new oracle's live price before
+/// `update_reserve_oracle` refuses the migration. Guards against the owner
+/// (or a compromised owner key) silently repointing a reserve at a feed for
+/// the wrong asset; a deprecated-but-correct feed's last print should still
+/// be close to a live replacement's.
+const MAX_ORACLE_MIGRATION_DEVIATION_BPS: u64 = 1_000;
+
+#[derive(Accounts)]
+pub struct UpdateReserveOracle<'info> {
+    #[account(has_one = owner)]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+
+    #[account(has_one = lending_market)]
+    pub reserve: Account<'info, Reserve>,
+
+    #[account(
+        mut,
+        has_one = lending_market,
+        seeds = [b"oracle-registry", lending_market.key().as_ref()],
+        bump,
+    )]
+    pub oracle_registry: Account<'info, OracleRegistry>,
+
+    /// CHECK: validated by `oracle::read_market_price` below, which rejects
+    /// a stale or negative price.
+    pub new_oracle: UncheckedAccount<'info>,
+}
+
+/// Owner-only: repoints `reserve`'s entry in the oracle registry at
+/// `new_oracle`, for when a Pyth feed is deprecated and a reserve would
+/// otherwise be stuck unable to refresh forever. Requires the new oracle to
+/// produce a live price (not stale, not negative) within
+/// `MAX_ORACLE_MIGRATION_DEVIATION_BPS` of the reserve's last cached price,
+/// so a migration can't silently swap in a feed for the wrong asset.
+pub fn handle(ctx: Context<UpdateReserveOracle>) -> Result<()> {
+    let new_price = oracle::read_market_price(&ctx.accounts.new_oracle.to_account_info())?;
+    let old_price = ctx.accounts.reserve.liquidity.market_price;
+
+    if old_price.to_scaled_val() > 0 {
+        let diff = if new_price > old_price {
+            new_price.try_sub(old_price)?
+        } else {
+            old_price.try_sub(new_price)?
+        };
+        let deviation_bps = diff.try_div(old_price)?.try_mul(Decimal::from(10_000u64))?.try_floor_u64()?;
+        require!(deviation_bps <= MAX_ORACLE_MIGRATION_DEVIATION_BPS, ErrorCode::OracleDeviationTooLarge);
+    }
+
+    let registry = &mut ctx.accounts.oracle_registry;
+    let mut asset = registry
+        .find(ctx.accounts.reserve.key())
+        .cloned()
+        .ok_or(ErrorCode::ReserveNotInOracleRegistry)?;
+    asset.oracle = ctx.accounts.new_oracle.key();
+    registry.upsert(asset)?;
+
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::models::{LendingMarket, Vault};
+
+#[derive(Accounts)]
+pub struct ClaimVaultFees<'info> {
+    #[account(has_one = owner, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market, has_one = underlying_vault)]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub underlying_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pays out `vault.accrued_fees` to the lending market owner and zeroes
+/// the accrual. Fees were already deducted from `total_underlying` (so
+/// they don't keep compounding) by `Vault::charge_performance_fee` when
+/// accrued; this just moves the matching tokens out of the vault.
+pub fn handle(ctx: Context<ClaimVaultFees>) -> Result<()> {
+    let amount = ctx.accounts.vault.accrued_fees;
+    ctx.accounts.vault.accrued_fees = 0;
+
+    let lending_market = ctx.accounts.vault.lending_market;
+    let underlying_mint = ctx.accounts.vault.underlying_mint;
+    let seeds: &[&[u8]] = &[
+        b"vault",
+        lending_market.as_ref(),
+        underlying_mint.as_ref(),
+        &[ctx.accounts.vault.bump_seed],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.underlying_vault.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::models::{LendingMarket, Vault};
+
+#[derive(Accounts)]
+pub struct CompoundVault<'info> {
+    #[account(has_one = owner, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, has_one = lending_market, has_one = underlying_vault)]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub underlying_vault: Account<'info, TokenAccount>,
+    /// Admin-controlled source of harvested yield. See `crank_compound_vault`
+    /// for the permissionless equivalent anyone can call.
+    #[account(mut)]
+    pub harvest_source: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Folds `harvested_amount` of already-harvested yield into the vault,
+/// growing `total_underlying` without minting new shares so every
+/// existing share becomes worth proportionally more. Doesn't respect
+/// `min_compound_interval_slots` — that only gates the permissionless
+/// crank, since the owner triggering this doesn't need rate-limiting
+/// protection from itself.
+pub fn handle(ctx: Context<CompoundVault>, harvested_amount: u64) -> Result<()> {
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.harvest_source.to_account_info(),
+                to: ctx.accounts.underlying_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        harvested_amount,
+    )?;
+
+    let slot = Clock::get()?.slot;
+    ctx.accounts.vault.compound(harvested_amount, slot)
+}
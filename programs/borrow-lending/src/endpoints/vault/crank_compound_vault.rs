@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::Vault;
+
+#[derive(Accounts)]
+pub struct CrankCompoundVault<'info> {
+    #[account(mut, has_one = underlying_vault)]
+    pub vault: Account<'info, Vault>,
+    #[account(mut)]
+    pub underlying_vault: Account<'info, TokenAccount>,
+    /// Already-harvested yield, owned by whoever is cranking (they sign
+    /// as the transfer authority). Unlike `compound_vault`'s
+    /// owner-controlled `harvest_source`, this program trusts the amount
+    /// transferred, not the caller's identity — anyone can crank, so
+    /// `min_compound_interval_slots` and the bounty are what keep this
+    /// safe and worth doing correctly.
+    #[account(mut)]
+    pub harvest_source: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub cranker_destination: Account<'info, TokenAccount>,
+    pub cranker: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Permissionless compounding: anyone can crank a vault's yield in once
+/// `min_compound_interval_slots` has passed since the last compound,
+/// earning `cranker_bounty_bps` of the harvested amount for doing so.
+/// Replaces total dependence on a single admin bot staying alive.
+pub fn handle(ctx: Context<CrankCompoundVault>, harvested_amount: u64) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    require!(ctx.accounts.vault.can_crank_compound(slot), ErrorCode::CompoundTooSoon);
+
+    let bounty = Decimal::from(harvested_amount)
+        .try_mul(Decimal::from_fraction(ctx.accounts.vault.cranker_bounty_bps as u128, 10_000)?)?
+        .try_floor_u64()?;
+    let compounded_amount = harvested_amount.checked_sub(bounty).ok_or(ErrorCode::MathOverflow)?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.harvest_source.to_account_info(),
+                to: ctx.accounts.underlying_vault.to_account_info(),
+                authority: ctx.accounts.cranker.to_account_info(),
+            },
+        ),
+        compounded_amount,
+    )?;
+
+    if bounty > 0 {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.harvest_source.to_account_info(),
+                    to: ctx.accounts.cranker_destination.to_account_info(),
+                    authority: ctx.accounts.cranker.to_account_info(),
+                },
+            ),
+            bounty,
+        )?;
+    }
+
+    ctx.accounts.vault.compound(compounded_amount, slot)
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::models::Vault;
+
+#[derive(Accounts)]
+pub struct DepositVault<'info> {
+    #[account(mut, has_one = underlying_vault, has_one = share_mint)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub source_underlying: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub underlying_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub share_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub destination_shares: Account<'info, TokenAccount>,
+    pub depositor: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Deposits `underlying_amount` into the vault and mints the depositor
+/// shares at the current exchange rate. The exchange rate only moves via
+/// `compound_vault` or `crank_compound_vault` folding harvested yield into
+/// `vault.total_underlying`,
+/// so depositors who come and go don't affect each other's share value.
+pub fn handle(ctx: Context<DepositVault>, underlying_amount: u64) -> Result<()> {
+    let shares = ctx.accounts.vault.deposit(underlying_amount)?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source_underlying.to_account_info(),
+                to: ctx.accounts.underlying_vault.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        ),
+        underlying_amount,
+    )?;
+
+    let lending_market = ctx.accounts.vault.lending_market;
+    let underlying_mint = ctx.accounts.vault.underlying_mint;
+    let seeds: &[&[u8]] = &[
+        b"vault",
+        lending_market.as_ref(),
+        underlying_mint.as_ref(),
+        &[ctx.accounts.vault.bump_seed],
+    ];
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::MintTo {
+                mint: ctx.accounts.share_mint.to_account_info(),
+                to: ctx.accounts.destination_shares.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            &[seeds],
+        ),
+        shares,
+    )?;
+
+    Ok(())
+}
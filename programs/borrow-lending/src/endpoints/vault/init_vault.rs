@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::models::{LendingMarket, Vault};
+
+#[derive(Accounts)]
+pub struct InitVault<'info> {
+    #[account(has_one = owner, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub underlying_mint: Account<'info, Mint>,
+    /// Pre-created with its mint authority set to `vault`, so the vault
+    /// PDA can mint/burn shares without a separate authority hand-off
+    /// instruction, same as how `init_reserve` takes a pre-created
+    /// collateral mint.
+    pub share_mint: Account<'info, Mint>,
+    /// Pre-created with its owner set to `vault`.
+    pub underlying_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = Vault::LEN,
+        seeds = [b"vault", lending_market.key().as_ref(), underlying_mint.key().as_ref()],
+        bump,
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle(
+    ctx: Context<InitVault>,
+    performance_fee_bps: u16,
+    min_compound_interval_slots: u64,
+    cranker_bounty_bps: u16,
+) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.lending_market = ctx.accounts.lending_market.key();
+    vault.underlying_mint = ctx.accounts.underlying_mint.key();
+    vault.share_mint = ctx.accounts.share_mint.key();
+    vault.underlying_vault = ctx.accounts.underlying_vault.key();
+    vault.performance_fee_bps = performance_fee_bps;
+    vault.min_compound_interval_slots = min_compound_interval_slots;
+    vault.cranker_bounty_bps = cranker_bounty_bps;
+    vault.bump_seed = ctx.bumps.vault;
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+pub mod claim_vault_fees;
+pub mod compound_vault;
+pub mod crank_compound_vault;
+pub mod deposit_vault;
+pub mod init_vault;
+pub mod withdraw_vault;
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::models::Vault;
+
+#[derive(Accounts)]
+pub struct WithdrawVault<'info> {
+    #[account(mut, has_one = underlying_vault, has_one = share_mint)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(mut)]
+    pub underlying_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub share_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub source_shares: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_underlying: Account<'info, TokenAccount>,
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Burns `shares` and returns the underlying they're currently worth.
+pub fn handle(ctx: Context<WithdrawVault>, shares: u64) -> Result<()> {
+    let underlying_amount = ctx.accounts.vault.withdraw(shares)?;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.share_mint.to_account_info(),
+                from: ctx.accounts.source_shares.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        shares,
+    )?;
+
+    let lending_market = ctx.accounts.vault.lending_market;
+    let underlying_mint = ctx.accounts.vault.underlying_mint;
+    let seeds: &[&[u8]] = &[
+        b"vault",
+        lending_market.as_ref(),
+        underlying_mint.as_ref(),
+        &[ctx.accounts.vault.bump_seed],
+    ];
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.underlying_vault.to_account_info(),
+                to: ctx.accounts.destination_underlying.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            &[seeds],
+        ),
+        underlying_amount,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{LendingMarket, Obligation, Reserve};
+
+/// One leg of a batched withdrawal: which deposit to pull from and how
+/// much collateral to take out of it.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CollateralWithdrawal {
+    pub deposit_reserve: Pubkey,
+    pub collateral_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawObligationCollateral<'info> {
+    #[account(mut, seeds = [b"lending-market", lending_market.owner.as_ref()], bump = lending_market.bump_seed)]
+    pub lending_market: Account<'info, LendingMarket>,
+
+    #[account(mut, has_one = owner)]
+    pub obligation: Account<'info, Obligation>,
+    pub owner: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    // Remaining accounts are `(reserve, source_collateral_vault,
+    // destination_collateral_account)` triples, one per entry in
+    // `withdrawals`, in the same order.
+}
+
+/// Withdraws collateral from several deposits in a single instruction,
+/// checking the obligation's health once against the *combined* effect of
+/// every withdrawal rather than once per withdrawal. This lets a caller
+/// rebalance across reserves (e.g. pull a little from each of five
+/// deposits) in one shot instead of risking a mid-batch revert on a
+/// withdrawal that would have been safe once the others landed too.
+///
+/// The obligation must have been refreshed this slot (see
+/// `refresh_obligation`).
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawObligationCollateral<'info>>,
+    withdrawals: Vec<CollateralWithdrawal>,
+) -> Result<()> {
+    let obligation = &mut ctx.accounts.obligation;
+    let slot = Clock::get()?.slot;
+    obligation.check_not_stale(slot)?;
+
+    require!(
+        ctx.remaining_accounts.len() == withdrawals.len() * 3,
+        ErrorCode::MathOverflow
+    );
+
+    let mut withdrawn_value = Decimal::zero();
+
+    for (withdrawal, accounts) in withdrawals.iter().zip(ctx.remaining_accounts.chunks(3)) {
+        let [reserve_info, source_vault_info, destination_info] = accounts else {
+            unreachable!("chunks(3) always yields 3 accounts");
+        };
+
+        let reserve = Account::<Reserve>::try_from(reserve_info)?;
+        reserve.check_not_stale(slot)?;
+
+        let deposit = obligation
+            .deposits
+            .iter_mut()
+            .find(|d| d.deposit_reserve == withdrawal.deposit_reserve)
+            .ok_or(ErrorCode::ObligationDepositsEmpty)?;
+        require!(
+            withdrawal.collateral_amount <= deposit.deposited_amount,
+            ErrorCode::WithdrawTooLarge
+        );
+
+        let liquidity_amount = reserve.collateral_to_liquidity(withdrawal.collateral_amount)?;
+        withdrawn_value = withdrawn_value.try_add(reserve.market_value(Decimal::from(liquidity_amount))?)?;
+
+        deposit.rebase_rewards(
+            deposit.deposited_amount - withdrawal.collateral_amount,
+            reserve.liquidity.cumulative_reward_per_share,
+        )?;
+        deposit.market_value = reserve.market_value(Decimal::from(
+            reserve.collateral_to_liquidity(deposit.deposited_amount)?,
+        ))?;
+        deposit.cost_basis_liquidity = Decimal::from(reserve.collateral_to_liquidity(deposit.deposited_amount)?);
+
+        let source_vault = Account::<TokenAccount>::try_from(source_vault_info)?;
+        let seeds: &[&[u8]] = &[
+            b"lending-market",
+            ctx.accounts.lending_market.owner.as_ref(),
+            &[ctx.accounts.lending_market.bump_seed],
+        ];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: source_vault.to_account_info(),
+                    to: destination_info.clone(),
+                    authority: ctx.accounts.lending_market.to_account_info(),
+                },
+                &[seeds],
+            ),
+            withdrawal.collateral_amount,
+        )?;
+    }
+
+    obligation.deposits.retain(|d| d.deposited_amount > 0);
+    obligation.deposited_value = obligation.deposited_value.try_sub(withdrawn_value)?;
+    ctx.accounts.lending_market.consume_outflow(slot, withdrawn_value)?;
+
+    // Checking against the obligation's headroom *after* every leg has
+    // been applied, rather than leg-by-leg, is the whole point of batching:
+    // a caller can shuffle collateral across several deposits in one shot
+    // even if an individual leg would have dipped below the limit on its
+    // own before the others landed.
+    require!(
+        obligation.borrowed_value <= obligation.allowed_borrow_value,
+        ErrorCode::WithdrawTooLarge
+    );
+
+    Ok(())
+}
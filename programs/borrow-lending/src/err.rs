@@ -0,0 +1,173 @@
+//! Program-wide error codes. Keep variants grouped roughly by the
+//! subsystem that raises them so `anchor build`'s generated IDL reads in a
+//! sensible order.
+
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation overflowed or underflowed")]
+    MathOverflow,
+
+    #[msg("Oracle price is stale, refresh the reserve first")]
+    StalePrice,
+
+    #[msg("Reserve must be refreshed before this operation")]
+    ReserveStale,
+
+    #[msg("Obligation must be refreshed before this operation")]
+    ObligationStale,
+
+    #[msg("Obligation has no deposited collateral")]
+    ObligationDepositsEmpty,
+
+    #[msg("Obligation has no outstanding borrows")]
+    ObligationBorrowsEmpty,
+
+    #[msg("Obligation is healthy and cannot be liquidated")]
+    ObligationHealthy,
+
+    #[msg("Withdraw amount would leave the obligation undercollateralized")]
+    WithdrawTooLarge,
+
+    #[msg("Borrow amount exceeds the reserve's available liquidity")]
+    BorrowTooLarge,
+
+    #[msg("Obligation already references the maximum number of reserves it was sized for")]
+    ObligationReserveLimit,
+
+    #[msg("Market is past its announced sunset slot and no longer accepts this operation")]
+    MarketSunset,
+
+    #[msg("Reserve is retiring and no longer accepts new deposits or borrows")]
+    ReserveRetiring,
+
+    #[msg("Caller is not the obligation's owner and supplied no matching credit delegation")]
+    NoCreditDelegation,
+
+    #[msg("Borrow amount exceeds the delegate's remaining credit delegation")]
+    CreditDelegationExceeded,
+
+    #[msg("Reserve has no emissions configured")]
+    NoEmissionsConfigured,
+
+    #[msg("This operation would exceed the market's outflow limit for the current window")]
+    OutflowLimitExceeded,
+
+    #[msg("Oracle registry already tracks the maximum number of assets")]
+    OracleRegistryFull,
+
+    #[msg("Reserve is not configured as an LST reserve")]
+    NotAnLstReserve,
+
+    #[msg("Swap returned less than the minimum acceptable output")]
+    SlippageExceeded,
+
+    #[msg("Leveraged position's AMM venue does not match the adapter used to close or reduce it")]
+    WrongAmmVenue,
+
+    #[msg("Leveraged position is already within its configured health buffer, no reduction needed")]
+    PositionWithinHealthBuffer,
+
+    #[msg("Requested unstake amount exceeds the position's staked amount")]
+    ReduceTooLarge,
+
+    #[msg("Vault was compounded too recently; wait for the minimum compound interval to elapse")]
+    CompoundTooSoon,
+
+    #[msg("Host account is registered for a different reserve than the one being borrowed from")]
+    HostReserveMismatch,
+
+    #[msg("Referrer account is registered for a different reserve than the one being borrowed from")]
+    ReferrerReserveMismatch,
+
+    #[msg("Referrer account does not match the referrer recorded on this obligation")]
+    ReferrerObligationMismatch,
+
+    #[msg("Lending market has no boost config set")]
+    BoostingDisabled,
+
+    #[msg("Cannot unstake more than is currently staked")]
+    UnstakeTooLarge,
+
+    #[msg("Emission strategy already covers the maximum number of reserves")]
+    EmissionStrategyFull,
+
+    #[msg("Reserve index is out of range for this emission strategy")]
+    EmissionStrategyIndexOutOfRange,
+
+    #[msg("Reserve at this index doesn't match the one passed in")]
+    EmissionStrategyReserveMismatch,
+
+    #[msg("Snapshot ring buffer capacity must be greater than zero")]
+    SnapshotCapacityZero,
+
+    #[msg("Snapshot ring buffer capacity exceeds the maximum allowed")]
+    SnapshotCapacityTooLarge,
+
+    #[msg("Snapshot ring buffer is registered for a different reserve")]
+    SnapshotReserveMismatch,
+
+    #[msg("Reserve is within its post price-jump liquidation grace period")]
+    LiquidationGracePeriod,
+
+    #[msg("Reserve does not allow borrowing against its own liquidity")]
+    BorrowingDisabled,
+
+    #[msg("Reserve is frozen or deprecated and does not accept new deposits or borrows")]
+    ReserveFrozen,
+
+    #[msg("Obligation has no alert threshold set")]
+    NoAlertThresholdSet,
+
+    #[msg("Obligation's borrowed value has not crossed its alert threshold")]
+    AlertThresholdNotCrossed,
+
+    #[msg("Account is already at the current layout version")]
+    AccountAlreadyUpToDate,
+
+    #[msg("Credit line is issued to a different obligation")]
+    CreditLineObligationMismatch,
+
+    #[msg("Obligation has not opted into auto-repay")]
+    AutoRepayDisabled,
+
+    #[msg("No harvestable collateral interest cached for this reserve, refresh the obligation first")]
+    NothingToHarvest,
+
+    #[msg("Borrow value is below the reserve's configured minimum")]
+    BorrowTooSmall,
+
+    #[msg("Partial repay would leave a dust borrow below the reserve's configured minimum; repay it in full instead")]
+    RepayWouldLeaveDust,
+
+    #[msg("New oracle's price deviates too far from the reserve's last cached price")]
+    OracleDeviationTooLarge,
+
+    #[msg("Reserve has no existing entry in the oracle registry to migrate")]
+    ReserveNotInOracleRegistry,
+
+    #[msg("This borrow would push the market's total borrowed value past its configured ceiling")]
+    TotalBorrowCeilingExceeded,
+
+    #[msg("Reserve has not opted into soft liquidation (soft_liquidation_band_pct is zero)")]
+    SoftLiquidationDisabled,
+
+    #[msg("Obligation is already past unhealthy_borrow_value; use liquidate_obligation instead")]
+    ObligationPastSoftLiquidationBand,
+
+    #[msg("Obligation is outside the soft-liquidation band and has nothing to rebalance")]
+    NothingToRebalance,
+
+    #[msg("ReserveConfig::fee_split has more entries than fee_split_count, or share_bps across the first fee_split_count entries doesn't sum to 10_000")]
+    FeeSplitInvalid,
+
+    #[msg("Number of fee split accounts passed doesn't match the reserve's configured fee_split_count, or one is for the wrong destination")]
+    FeeSplitAccountMismatch,
+
+    #[msg("ReserveConfig::critical_utilization_pct must be in (optimal utilization, 100]")]
+    CriticalUtilizationInvalid,
+
+    #[msg("This borrow would push the reserve's utilization past its configured critical_utilization_pct")]
+    UtilizationTooHigh,
+}
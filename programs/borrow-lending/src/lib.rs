@@ -0,0 +1,470 @@
+//! Cross-collateral borrow-lending market program.
+//!
+//! Borrowers deposit collateral into one or more [`models::Reserve`]s,
+//! which backs borrows of other reserves' liquidity, tracked per-user in an
+//! [`models::Obligation`]. See `models/` for account layouts and the
+//! accounting they encapsulate, and `endpoints/` for the instructions that
+//! drive them.
+
+use anchor_lang::prelude::*;
+
+pub mod endpoints;
+pub mod err;
+pub mod math;
+pub mod models;
+pub mod oracle;
+pub mod pda;
+pub mod telemetry;
+
+use endpoints::*;
+use endpoints::amm::orca_whirlpool::*;
+use endpoints::amm::raydium::*;
+use endpoints::leverage::open_leveraged_position_on_aldrin;
+use endpoints::leverage::open_leveraged_position_on_aldrin::OpenLeveragedPositionOnAldrin;
+use endpoints::leverage::open_leveraged_position_via_jupiter;
+use endpoints::leverage::open_leveraged_position_via_jupiter::OpenLeveragedPositionViaJupiter;
+use endpoints::leverage::close_leveraged_position_on_aldrin;
+use endpoints::leverage::close_leveraged_position_on_aldrin::CloseLeveragedPositionOnAldrin;
+use endpoints::leverage::reduce_leveraged_position_on_aldrin;
+use endpoints::leverage::reduce_leveraged_position_on_aldrin::ReduceLeveragedPositionOnAldrin;
+use endpoints::leverage::refresh_leveraged_position_value;
+use endpoints::leverage::refresh_leveraged_position_value::RefreshLeveragedPositionValue;
+use endpoints::migrations::migrate_lending_market;
+use endpoints::migrations::migrate_lending_market::MigrateLendingMarket;
+use endpoints::migrations::migrate_obligation;
+use endpoints::migrations::migrate_obligation::MigrateObligation;
+use endpoints::migrations::migrate_reserve;
+use endpoints::migrations::migrate_reserve::MigrateReserve;
+use endpoints::vault::*;
+
+declare_id!("BLendhFh4cahrj5sVepfp4WcJqJc5vXrCWxjExCYwJ1L");
+
+#[program]
+pub mod borrow_lending {
+    use super::*;
+
+    pub fn init_lending_market(ctx: Context<InitLendingMarket>, uac_mint: Pubkey) -> Result<()> {
+        init_lending_market::handle(ctx, uac_mint)
+    }
+
+    pub fn init_reserve(ctx: Context<InitReserve>, config: models::ReserveConfig) -> Result<()> {
+        init_reserve::handle(ctx, config)
+    }
+
+    pub fn refresh_reserve(ctx: Context<RefreshReserve>) -> Result<()> {
+        refresh_reserve::handle(ctx)
+    }
+
+    pub fn refresh_reserves(ctx: Context<RefreshReserves>) -> Result<()> {
+        refresh_reserves::handle(ctx)
+    }
+
+    pub fn withdraw_obligation_collateral(
+        ctx: Context<WithdrawObligationCollateral>,
+        withdrawals: Vec<endpoints::withdraw_obligation_collateral::CollateralWithdrawal>,
+    ) -> Result<()> {
+        withdraw_obligation_collateral::handle(ctx, withdrawals)
+    }
+
+    pub fn swap_obligation_collateral_on_aldrin(
+        ctx: Context<SwapObligationCollateralOnAldrin>,
+        collateral_amount: u64,
+        min_liquidity_out: u64,
+    ) -> Result<()> {
+        swap_obligation_collateral_on_aldrin::handle(ctx, collateral_amount, min_liquidity_out)
+    }
+
+    pub fn swap_obligation_debt_on_aldrin(
+        ctx: Context<SwapObligationDebtOnAldrin>,
+        new_borrow_amount: u64,
+        min_repay_amount_out: u64,
+    ) -> Result<()> {
+        swap_obligation_debt_on_aldrin::handle(ctx, new_borrow_amount, min_repay_amount_out)
+    }
+
+    pub fn sweep_pda_lamports(ctx: Context<SweepPdaLamports>) -> Result<()> {
+        sweep_pda_lamports::handle(ctx)
+    }
+
+    pub fn init_obligation(
+        ctx: Context<InitObligation>,
+        referrer: Option<Pubkey>,
+        strategy_tag: Option<models::StrategyTag>,
+    ) -> Result<()> {
+        init_obligation::handle(ctx, referrer, strategy_tag)
+    }
+
+    pub fn refresh_obligation(ctx: Context<RefreshObligation>) -> Result<()> {
+        refresh_obligation::handle(ctx)
+    }
+
+    /// View-style instruction returning obligation health metrics via
+    /// return data. See `endpoints::get_obligation_health`.
+    pub fn get_obligation_health(ctx: Context<GetObligationHealth>) -> Result<()> {
+        get_obligation_health::handle(ctx)
+    }
+
+    /// View-style instruction returning a liquidation preview via return
+    /// data. See `endpoints::preview_liquidation`.
+    pub fn preview_liquidation(ctx: Context<PreviewLiquidation>, liquidity_amount: u64) -> Result<()> {
+        preview_liquidation::handle(ctx, liquidity_amount)
+    }
+
+    pub fn accrue_reserve_interest(ctx: Context<AccrueReserveInterest>) -> Result<()> {
+        accrue_reserve_interest::handle(ctx)
+    }
+
+    pub fn tag_obligation(ctx: Context<TagObligation>, strategy_tag: Option<models::StrategyTag>) -> Result<()> {
+        tag_obligation::handle(ctx, strategy_tag)
+    }
+
+    pub fn snapshot_reserve(ctx: Context<SnapshotReserve>) -> Result<()> {
+        snapshot_reserve::handle(ctx)
+    }
+
+    pub fn flash_loan(ctx: Context<FlashLoan>, amount: u64, callback_data: Vec<u8>) -> Result<()> {
+        flash_loan::handle(ctx, amount, callback_data)
+    }
+
+    pub fn set_margin_call_threshold(ctx: Context<SetMarginCallThreshold>, threshold_pct: u8) -> Result<()> {
+        set_margin_call_threshold::handle(ctx, threshold_pct)
+    }
+
+    pub fn set_max_total_borrow_value(
+        ctx: Context<SetMaxTotalBorrowValue>,
+        max_total_borrow_value: Option<math::Decimal>,
+    ) -> Result<()> {
+        set_max_total_borrow_value::handle(ctx, max_total_borrow_value)
+    }
+
+    pub fn deposit_obligation_collateral(ctx: Context<DepositObligationCollateral>, collateral_amount: u64) -> Result<()> {
+        deposit_obligation_collateral::handle(ctx, collateral_amount)
+    }
+
+    pub fn switch_rate_mode(ctx: Context<SwitchRateMode>, to_stable: bool) -> Result<()> {
+        switch_rate_mode::handle(ctx, to_stable)
+    }
+
+    pub fn borrow_obligation_liquidity<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BorrowObligationLiquidity<'info>>,
+        liquidity_amount: u64,
+    ) -> Result<()> {
+        borrow_obligation_liquidity::handle(ctx, liquidity_amount)
+    }
+
+    pub fn deploy_idle_liquidity(ctx: Context<DeployIdleLiquidity>, amount: u64, strategy_deposit_ix_data: Vec<u8>) -> Result<()> {
+        deploy_idle_liquidity::handle(ctx, amount, strategy_deposit_ix_data)
+    }
+
+    pub fn recall_idle_liquidity(ctx: Context<RecallIdleLiquidity>, amount: u64, strategy_withdraw_ix_data: Vec<u8>) -> Result<()> {
+        recall_idle_liquidity::handle(ctx, amount, strategy_withdraw_ix_data)
+    }
+
+    pub fn self_test_layouts(ctx: Context<SelfTestLayouts>) -> Result<()> {
+        self_test_layouts::handle(ctx)
+    }
+
+    pub fn refer_bad_debt_to_auction(ctx: Context<ReferBadDebtToAuction>) -> Result<()> {
+        refer_bad_debt_to_auction::handle(ctx)
+    }
+
+    pub fn grow_obligation(ctx: Context<GrowObligation>, added_reserves: u8) -> Result<()> {
+        grow_obligation::handle(ctx, added_reserves)
+    }
+
+    pub fn close_obligation(ctx: Context<CloseObligation>) -> Result<()> {
+        close_obligation::handle(ctx)
+    }
+
+    pub fn announce_sunset(ctx: Context<AnnounceSunset>, sunset_at_slot: Option<u64>) -> Result<()> {
+        announce_sunset::handle(ctx, sunset_at_slot)
+    }
+
+    pub fn force_settle(ctx: Context<ForceSettle>) -> Result<()> {
+        force_settle::handle(ctx)
+    }
+
+    pub fn set_reserve_retiring(ctx: Context<SetReserveRetiring>, retiring: bool) -> Result<()> {
+        set_reserve_retiring::handle(ctx, retiring)
+    }
+
+    pub fn set_reserve_status(ctx: Context<SetReserveStatus>, status: models::ReserveStatus) -> Result<()> {
+        set_reserve_status::handle(ctx, status)
+    }
+
+    pub fn close_reserve(ctx: Context<CloseReserve>) -> Result<()> {
+        close_reserve::handle(ctx)
+    }
+
+    pub fn transfer_obligation_ownership(ctx: Context<TransferObligationOwnership>) -> Result<()> {
+        transfer_obligation_ownership::handle(ctx)
+    }
+
+    pub fn approve_credit_delegation(ctx: Context<ApproveCreditDelegation>, credit_limit: u64) -> Result<()> {
+        approve_credit_delegation::handle(ctx, credit_limit)
+    }
+
+    pub fn create_reserve_template(ctx: Context<CreateReserveTemplate>, label: [u8; 32], config: models::ReserveConfig) -> Result<()> {
+        create_reserve_template::handle(ctx, label, config)
+    }
+
+    pub fn init_reserve_from_template(ctx: Context<InitReserveFromTemplate>) -> Result<()> {
+        init_reserve_from_template::handle(ctx)
+    }
+
+    pub fn set_reserve_emissions(ctx: Context<SetReserveEmissions>, reward_mint: Option<Pubkey>, reward_per_slot: u64) -> Result<()> {
+        set_reserve_emissions::handle(ctx, reward_mint, reward_per_slot)
+    }
+
+    pub fn claim_emission(ctx: Context<ClaimEmission>, reserve_index: Option<u8>) -> Result<()> {
+        claim_emission::handle(ctx, reserve_index)
+    }
+
+    pub fn repay_obligation_liquidity(ctx: Context<RepayObligationLiquidity>, liquidity_amount: u64) -> Result<()> {
+        repay_obligation_liquidity::handle(ctx, liquidity_amount)
+    }
+
+    pub fn repay_multiple_obligation_liquidities(
+        ctx: Context<RepayMultipleObligationLiquidities>,
+        repayments: Vec<LiquidityRepayment>,
+    ) -> Result<()> {
+        repay_multiple_obligation_liquidities::handle(ctx, repayments)
+    }
+
+    pub fn set_oracle_asset(ctx: Context<SetOracleAsset>, symbol: [u8; 32], decimals: u8) -> Result<()> {
+        set_oracle_asset::handle(ctx, symbol, decimals)
+    }
+
+    pub fn update_reserve_oracle(ctx: Context<UpdateReserveOracle>) -> Result<()> {
+        update_reserve_oracle::handle(ctx)
+    }
+
+    pub fn deposit_reserve_liquidity(ctx: Context<DepositReserveLiquidity>, liquidity_amount: u64) -> Result<()> {
+        deposit_reserve_liquidity::handle(ctx, liquidity_amount)
+    }
+
+    pub fn deposit_reserve_liquidity_sol(ctx: Context<DepositReserveLiquiditySol>, lamports: u64) -> Result<()> {
+        deposit_reserve_liquidity_sol::handle(ctx, lamports)
+    }
+
+    pub fn redeem_reserve_collateral(ctx: Context<RedeemReserveCollateral>, collateral_amount: u64) -> Result<()> {
+        redeem_reserve_collateral::handle(ctx, collateral_amount)
+    }
+
+    pub fn redeem_reserve_collateral_sol(ctx: Context<RedeemReserveCollateralSol>, collateral_amount: u64) -> Result<()> {
+        redeem_reserve_collateral_sol::handle(ctx, collateral_amount)
+    }
+
+    pub fn set_collateral_metadata(ctx: Context<SetCollateralMetadata>, name: String, symbol: String, uri: String) -> Result<()> {
+        set_collateral_metadata::handle(ctx, name, symbol, uri)
+    }
+
+    pub fn refresh_reserve_lst(ctx: Context<RefreshReserveLst>) -> Result<()> {
+        refresh_reserve_lst::handle(ctx)
+    }
+
+    pub fn init_reserve_orca_whirlpool_position(ctx: Context<InitReserve>, config: models::ReserveConfig) -> Result<()> {
+        init_reserve_orca_whirlpool_position::handle(ctx, config)
+    }
+
+    pub fn refresh_reserve_orca_whirlpool_position(ctx: Context<RefreshReserveOrcaWhirlpoolPosition>) -> Result<()> {
+        refresh_reserve_orca_whirlpool_position::handle(ctx)
+    }
+
+    pub fn init_reserve_raydium_lp_token(ctx: Context<InitReserve>, config: models::ReserveConfig) -> Result<()> {
+        init_reserve_raydium_lp_token::handle(ctx, config)
+    }
+
+    pub fn refresh_reserve_raydium_lp_token(ctx: Context<RefreshReserveRaydiumLpToken>) -> Result<()> {
+        refresh_reserve_raydium_lp_token::handle(ctx)
+    }
+
+    pub fn open_leveraged_position_on_aldrin<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OpenLeveragedPositionOnAldrin<'info>>,
+        borrow_amount: u64,
+        min_collateral_out: u64,
+    ) -> Result<()> {
+        open_leveraged_position_on_aldrin::handle(ctx, borrow_amount, min_collateral_out)
+    }
+
+    pub fn open_leveraged_position_via_jupiter<'info>(
+        ctx: Context<'_, '_, 'info, 'info, OpenLeveragedPositionViaJupiter<'info>>,
+        borrow_amount: u64,
+        jupiter_ix_data: Vec<u8>,
+        max_slippage_bps: u16,
+    ) -> Result<()> {
+        open_leveraged_position_via_jupiter::handle(ctx, borrow_amount, jupiter_ix_data, max_slippage_bps)
+    }
+
+    pub fn close_leveraged_position_on_aldrin<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseLeveragedPositionOnAldrin<'info>>,
+        max_slippage_bps: u16,
+    ) -> Result<()> {
+        close_leveraged_position_on_aldrin::handle(ctx, max_slippage_bps)
+    }
+
+    pub fn reduce_leveraged_position_on_aldrin<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReduceLeveragedPositionOnAldrin<'info>>,
+        unstake_amount: u64,
+        max_slippage_bps: u16,
+        health_buffer_bps: u16,
+    ) -> Result<()> {
+        reduce_leveraged_position_on_aldrin::handle(ctx, unstake_amount, max_slippage_bps, health_buffer_bps)
+    }
+
+    pub fn refresh_leveraged_position_value(ctx: Context<RefreshLeveragedPositionValue>) -> Result<()> {
+        refresh_leveraged_position_value::handle(ctx)
+    }
+
+    pub fn init_vault(
+        ctx: Context<InitVault>,
+        performance_fee_bps: u16,
+        min_compound_interval_slots: u64,
+        cranker_bounty_bps: u16,
+    ) -> Result<()> {
+        init_vault::handle(ctx, performance_fee_bps, min_compound_interval_slots, cranker_bounty_bps)
+    }
+
+    pub fn deposit_vault(ctx: Context<DepositVault>, underlying_amount: u64) -> Result<()> {
+        deposit_vault::handle(ctx, underlying_amount)
+    }
+
+    pub fn withdraw_vault(ctx: Context<WithdrawVault>, shares: u64) -> Result<()> {
+        withdraw_vault::handle(ctx, shares)
+    }
+
+    pub fn compound_vault(ctx: Context<CompoundVault>, harvested_amount: u64) -> Result<()> {
+        compound_vault::handle(ctx, harvested_amount)
+    }
+
+    pub fn claim_vault_fees(ctx: Context<ClaimVaultFees>) -> Result<()> {
+        claim_vault_fees::handle(ctx)
+    }
+
+    pub fn crank_compound_vault(ctx: Context<CrankCompoundVault>, harvested_amount: u64) -> Result<()> {
+        crank_compound_vault::handle(ctx, harvested_amount)
+    }
+
+    pub fn register_host(ctx: Context<RegisterHost>) -> Result<()> {
+        register_host::handle(ctx)
+    }
+
+    pub fn claim_host_fees(ctx: Context<ClaimHostFees>) -> Result<()> {
+        claim_host_fees::handle(ctx)
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        register_referrer::handle(ctx)
+    }
+
+    pub fn claim_referral_fees(ctx: Context<ClaimReferralFees>) -> Result<()> {
+        claim_referral_fees::handle(ctx)
+    }
+
+    pub fn set_boost_config(
+        ctx: Context<SetBoostConfig>,
+        governance_mint: Option<Pubkey>,
+        boost_vault: Pubkey,
+        max_boost_bps: u16,
+        full_boost_stake_amount: u64,
+    ) -> Result<()> {
+        set_boost_config::handle(ctx, governance_mint, boost_vault, max_boost_bps, full_boost_stake_amount)
+    }
+
+    pub fn stake_booster_tokens(ctx: Context<StakeBoosterTokens>, amount: u64) -> Result<()> {
+        stake_booster_tokens::handle(ctx, amount)
+    }
+
+    pub fn unstake_booster_tokens(ctx: Context<UnstakeBoosterTokens>, amount: u64) -> Result<()> {
+        unstake_booster_tokens::handle(ctx, amount)
+    }
+
+    pub fn init_emission_strategy(
+        ctx: Context<InitEmissionStrategy>,
+        total_reward_per_slot: u64,
+        reserves: Vec<models::EmissionWeight>,
+    ) -> Result<()> {
+        init_emission_strategy::handle(ctx, total_reward_per_slot, reserves)
+    }
+
+    pub fn sync_emission_strategy(ctx: Context<SyncEmissionStrategy>, reserve_index: u8) -> Result<()> {
+        sync_emission_strategy::handle(ctx, reserve_index)
+    }
+
+    pub fn update_emission(
+        ctx: Context<UpdateEmission>,
+        reward_per_slot: u64,
+        emission_ends_at_slot: Option<u64>,
+    ) -> Result<()> {
+        update_emission::handle(ctx, reward_per_slot, emission_ends_at_slot)
+    }
+
+    pub fn init_reserve_cap_snapshots(ctx: Context<InitReserveCapSnapshots>, capacity: u16) -> Result<()> {
+        init_reserve_cap_snapshots::handle(ctx, capacity)
+    }
+
+    pub fn liquidate_obligation(ctx: Context<LiquidateObligation>, liquidity_amount: u64) -> Result<()> {
+        liquidate_obligation::handle(ctx, liquidity_amount)
+    }
+
+    pub fn liquidate_obligation_and_redeem(
+        ctx: Context<LiquidateObligationAndRedeem>,
+        liquidity_amount: u64,
+    ) -> Result<()> {
+        liquidate_obligation_and_redeem::handle(ctx, liquidity_amount)
+    }
+
+    pub fn liquidate_obligation_and_redeem_sol(
+        ctx: Context<LiquidateObligationAndRedeemSol>,
+        liquidity_amount: u64,
+    ) -> Result<()> {
+        liquidate_obligation_and_redeem_sol::handle(ctx, liquidity_amount)
+    }
+
+    pub fn rebalance_soft_liquidation(
+        ctx: Context<RebalanceSoftLiquidation>,
+        amount_in: u64,
+        min_amount_out: u64,
+    ) -> Result<()> {
+        rebalance_soft_liquidation::handle(ctx, amount_in, min_amount_out)
+    }
+
+    pub fn set_obligation_alert_threshold(
+        ctx: Context<SetObligationAlertThreshold>,
+        alert_threshold: Option<math::Decimal>,
+    ) -> Result<()> {
+        set_obligation_alert_threshold::handle(ctx, alert_threshold)
+    }
+
+    pub fn ping_unhealthy_obligation(ctx: Context<PingUnhealthyObligation>) -> Result<()> {
+        ping_unhealthy_obligation::handle(ctx)
+    }
+
+    pub fn migrate_lending_market(ctx: Context<MigrateLendingMarket>) -> Result<()> {
+        migrate_lending_market::handle(ctx)
+    }
+
+    pub fn migrate_reserve(ctx: Context<MigrateReserve>) -> Result<()> {
+        migrate_reserve::handle(ctx)
+    }
+
+    pub fn migrate_obligation(ctx: Context<MigrateObligation>) -> Result<()> {
+        migrate_obligation::handle(ctx)
+    }
+
+    pub fn log_compute_checkpoint(ctx: Context<LogComputeCheckpoint>, label: String) -> Result<()> {
+        log_compute_checkpoint::handle(ctx, label)
+    }
+
+    pub fn issue_credit_line(ctx: Context<IssueCreditLine>, credit_value: math::Decimal) -> Result<()> {
+        issue_credit_line::handle(ctx, credit_value)
+    }
+
+    pub fn set_auto_repay(ctx: Context<SetAutoRepay>, enabled: bool, threshold: u64) -> Result<()> {
+        set_auto_repay::handle(ctx, enabled, threshold)
+    }
+
+    pub fn harvest_collateral_interest(ctx: Context<HarvestCollateralInterest>) -> Result<()> {
+        harvest_collateral_interest::handle(ctx)
+    }
+}
@@ -0,0 +1,140 @@
+//! Fixed-point decimal with [`SCALE`] digits of precision, backed by a
+//! `u192`-equivalent (three `u64` limbs via [`u128`] intermediate math).
+//! All reserve and obligation accounting is expressed in this type so that
+//! rounding behavior is identical regardless of call site.
+
+use anchor_lang::prelude::*;
+use std::convert::TryFrom;
+
+/// Number of fractional decimal digits carried by [`Decimal`].
+pub const SCALE: usize = 18;
+
+const WAD: u128 = 1_000_000_000_000_000_000;
+
+/// A non-negative fixed-point number with [`SCALE`] digits of precision.
+///
+/// Internally stored as `value * 10^SCALE`, clamped to fit in a `u128`. This
+/// is sufficient for all quantities we deal with (token amounts scaled by
+/// price, up to `u64::MAX` lamports times a price with a handful of digits).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, AnchorSerialize, AnchorDeserialize)]
+pub struct Decimal(pub u128);
+
+impl Decimal {
+    pub fn zero() -> Self {
+        Self(0)
+    }
+
+    pub fn one() -> Self {
+        Self(WAD)
+    }
+
+    pub fn from_percent(percent: u8) -> Self {
+        Self(WAD / 100 * percent as u128)
+    }
+
+    pub fn to_scaled_val(&self) -> u128 {
+        self.0
+    }
+
+    pub fn from_scaled_val(scaled_val: u128) -> Self {
+        Self(scaled_val)
+    }
+
+    /// Rounds down to the nearest integer `u64`.
+    pub fn try_floor_u64(&self) -> Result<u64> {
+        u64::try_from(self.0 / WAD).map_err(|_| error!(crate::err::ErrorCode::MathOverflow))
+    }
+
+    /// Rounds to the nearest integer `u64`, ties away from zero.
+    pub fn try_round_u64(&self) -> Result<u64> {
+        let rounded = self
+            .0
+            .checked_add(WAD / 2)
+            .ok_or_else(|| error!(crate::err::ErrorCode::MathOverflow))?
+            / WAD;
+        u64::try_from(rounded).map_err(|_| error!(crate::err::ErrorCode::MathOverflow))
+    }
+
+    pub fn try_add(&self, rhs: Self) -> Result<Self> {
+        self.0
+            .checked_add(rhs.0)
+            .map(Self)
+            .ok_or_else(|| error!(crate::err::ErrorCode::MathOverflow))
+    }
+
+    pub fn try_sub(&self, rhs: Self) -> Result<Self> {
+        self.0
+            .checked_sub(rhs.0)
+            .map(Self)
+            .ok_or_else(|| error!(crate::err::ErrorCode::MathOverflow))
+    }
+
+    pub fn try_mul(&self, rhs: impl Into<Decimal>) -> Result<Self> {
+        let rhs = rhs.into();
+        self.0
+            .checked_mul(rhs.0)
+            .and_then(|v| v.checked_div(WAD))
+            .map(Self)
+            .ok_or_else(|| error!(crate::err::ErrorCode::MathOverflow))
+    }
+
+    pub fn try_div(&self, rhs: impl Into<Decimal>) -> Result<Self> {
+        let rhs = rhs.into();
+        self.0
+            .checked_mul(WAD)
+            .and_then(|v| v.checked_div(rhs.0))
+            .map(Self)
+            .ok_or_else(|| error!(crate::err::ErrorCode::MathOverflow))
+    }
+
+    /// Builds a `Decimal` from `numerator / denominator`, for converting a
+    /// raw fixed-point value from another program's account (e.g. a
+    /// Q64.64 price) without that caller needing to know `WAD`.
+    pub fn from_fraction(numerator: u128, denominator: u128) -> Result<Self> {
+        numerator
+            .checked_mul(WAD)
+            .and_then(|v| v.checked_div(denominator))
+            .map(Self)
+            .ok_or_else(|| error!(crate::err::ErrorCode::MathOverflow))
+    }
+}
+
+impl From<u64> for Decimal {
+    fn from(v: u64) -> Self {
+        Self(v as u128 * WAD)
+    }
+}
+
+impl From<u128> for Decimal {
+    fn from(v: u128) -> Self {
+        Self(v * WAD)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_and_subtracts() {
+        let a = Decimal::from(10u64);
+        let b = Decimal::from(3u64);
+        assert_eq!(a.try_add(b).unwrap(), Decimal::from(13u64));
+        assert_eq!(a.try_sub(b).unwrap(), Decimal::from(7u64));
+    }
+
+    #[test]
+    fn multiplies_and_divides() {
+        let a = Decimal::from(10u64);
+        let half = Decimal::from_percent(50);
+        assert_eq!(a.try_mul(half).unwrap(), Decimal::from(5u64));
+        assert_eq!(a.try_div(half).unwrap(), Decimal::from(20u64));
+    }
+
+    #[test]
+    fn rounds_to_nearest_u64() {
+        let d = Decimal::from_scaled_val(WAD + WAD / 2);
+        assert_eq!(d.try_round_u64().unwrap(), 2);
+        assert_eq!(d.try_floor_u64().unwrap(), 1);
+    }
+}
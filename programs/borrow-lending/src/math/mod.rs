@@ -0,0 +1,5 @@
+//! Fixed-point math shared by reserve and obligation accounting.
+
+mod decimal;
+
+pub use decimal::{Decimal, SCALE};
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// One owner's lock of `LendingMarket::boost_config`'s governance token,
+/// read by `claim_emission` to scale that owner's emission share up via
+/// `LendingMarket::boost_multiplier`. Created by `stake_booster_tokens` and
+/// drawn down by `unstake_booster_tokens`; neither endpoint touches
+/// emissions directly, so restaking never itself triggers a claim.
+#[account]
+#[derive(Default)]
+pub struct BoosterStake {
+    pub lending_market: Pubkey,
+    pub owner: Pubkey,
+    pub staked_amount: u64,
+    pub bump_seed: u8,
+}
+
+impl BoosterStake {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 1;
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+/// Authorizes `delegate` to borrow `reserve`'s liquidity against
+/// `obligation` on the owner's behalf, up to `credit_limit`, without
+/// handing over the obligation itself. Lets a DAO treasury's obligation
+/// back borrowing power for a working group's wallet while the treasury
+/// keeps ownership (and can revoke by setting `credit_limit` back to zero).
+#[account]
+#[derive(Default)]
+pub struct CreditDelegation {
+    pub obligation: Pubkey,
+    pub delegate: Pubkey,
+    pub reserve: Pubkey,
+    pub credit_limit: u64,
+    /// Liquidity already borrowed against this delegation. Decremented as
+    /// the delegate repays (once a delegate-aware repay path exists) and
+    /// checked against `credit_limit` on every delegated borrow.
+    pub used_amount: u64,
+}
+
+impl CreditDelegation {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 8;
+
+    pub fn remaining(&self) -> u64 {
+        self.credit_limit.saturating_sub(self.used_amount)
+    }
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::math::Decimal;
+
+/// Issued by the market owner to a specific obligation — e.g. an
+/// institutional borrower the market has off-chain recourse against —
+/// `credit_value` is UAC-denominated borrowing power `refresh_obligation`
+/// adds straight to `Obligation::allowed_borrow_value` and
+/// `unhealthy_borrow_value`, without any token deposit backing it.
+/// Liquidation can't touch the portion of debt it covers: it isn't tied to
+/// any reserve's collateral pool, so there's nothing for a liquidator to
+/// seize against it, and it raised the unhealthy threshold right alongside
+/// the borrowing power it granted.
+#[account]
+#[derive(Default)]
+pub struct CreditLine {
+    pub lending_market: Pubkey,
+    pub obligation: Pubkey,
+    pub credit_value: Decimal,
+}
+
+impl CreditLine {
+    pub const LEN: usize = 8 + 32 + 32 + 16;
+}
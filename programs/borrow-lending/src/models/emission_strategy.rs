@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+
+/// Maximum number of reserves a single strategy can cover. Sized
+/// generously above any market's reserve count.
+pub const MAX_EMISSION_STRATEGY_RESERVES: usize = 20;
+
+/// Packed size of one [`EmissionWeight`] entry.
+const BYTES_PER_WEIGHT: usize = 32 + 2;
+
+/// One reserve's cut of an [`EmissionStrategy`]'s `total_reward_per_slot`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct EmissionWeight {
+    pub reserve: Pubkey,
+    /// Share of `total_reward_per_slot`, in bps. Weights across a
+    /// strategy's `reserves` need not sum to 10_000 — any remainder is
+    /// simply never emitted.
+    pub weight_bps: u16,
+}
+
+/// Funds emissions for several reserves out of a single `reward_mint` /
+/// `reward_vault` instead of each reserve needing its own
+/// `set_reserve_emissions` call and funding wallet. `sync_emission_strategy`
+/// derives each covered reserve's `Reserve::liquidity::reward_per_slot`
+/// from `total_reward_per_slot * weight_bps` and pushes it onto the
+/// reserve; from there accrual and `claim_emission` work exactly as they
+/// do for a standalone reserve, since the per-reserve reward index lives
+/// on `Reserve` either way.
+#[account]
+#[derive(Default)]
+pub struct EmissionStrategy {
+    pub lending_market: Pubkey,
+    pub reward_mint: Pubkey,
+    pub reward_vault: Pubkey,
+    pub total_reward_per_slot: u64,
+    pub reserves: Vec<EmissionWeight>,
+    pub bump_seed: u8,
+}
+
+impl EmissionStrategy {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 4 + MAX_EMISSION_STRATEGY_RESERVES * BYTES_PER_WEIGHT + 1;
+
+    /// The `reward_per_slot` `reserve_index` should currently be emitting,
+    /// given its configured weight.
+    pub fn reward_per_slot_for(&self, reserve_index: u8) -> Result<u64> {
+        let weight = self
+            .reserves
+            .get(reserve_index as usize)
+            .ok_or(ErrorCode::EmissionStrategyIndexOutOfRange)?;
+
+        Decimal::from(self.total_reward_per_slot)
+            .try_mul(Decimal::from_fraction(weight.weight_bps as u128, 10_000)?)?
+            .try_floor_u64()
+    }
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// A frontend registered to receive a cut of borrow origination fees for
+/// transactions it refers, one per `(lending_market, reserve, authority)`.
+/// Accrued in `Reserve::liquidity`'s mint and claimed via
+/// `claim_host_fees`.
+#[account]
+#[derive(Default)]
+pub struct Host {
+    pub lending_market: Pubkey,
+    pub reserve: Pubkey,
+    pub authority: Pubkey,
+    pub accrued_fees: u64,
+    pub bump_seed: u8,
+}
+
+impl Host {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 1;
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+
+/// Maximum number of kinks (utilization breakpoints) a curve can have,
+/// including the required 0% and 100% endpoints.
+pub const MAX_RATE_CURVE_POINTS: usize = 5;
+
+/// A single point on the piecewise-linear borrow rate curve: at
+/// `utilization_pct` utilization, the borrow APR is `borrow_apr_bps`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct RateCurvePoint {
+    pub utilization_pct: u8,
+    pub borrow_apr_bps: u32,
+}
+
+/// Piecewise-linear interest rate curve with an arbitrary number of kinks,
+/// replacing the single-optimal-utilization model. A typical curve has a
+/// shallow slope up to an "optimal" kink and a much steeper slope after it
+/// so rates spike hard once a reserve is nearly fully borrowed; this lets
+/// reserves define as many intermediate kinks as they need (e.g. a gentle
+/// middle segment for stablecoin pairs).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InterestRateCurve {
+    /// Sorted ascending by `utilization_pct`, first point's
+    /// `utilization_pct` must be 0 and last must be 100.
+    pub points: Vec<RateCurvePoint>,
+}
+
+impl InterestRateCurve {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.points.len() >= 2 && self.points.len() <= MAX_RATE_CURVE_POINTS,
+            ErrorCode::MathOverflow
+        );
+        require!(self.points.first().unwrap().utilization_pct == 0, ErrorCode::MathOverflow);
+        require!(self.points.last().unwrap().utilization_pct == 100, ErrorCode::MathOverflow);
+        require!(
+            self.points.windows(2).all(|w| w[0].utilization_pct < w[1].utilization_pct),
+            ErrorCode::MathOverflow
+        );
+        Ok(())
+    }
+
+    /// Linearly interpolates the borrow APR between the two kinks
+    /// surrounding `utilization_pct`.
+    pub fn borrow_apr(&self, utilization_pct: Decimal) -> Result<Decimal> {
+        let utilization_pct = utilization_pct.try_mul(Decimal::from(100u64))?;
+
+        for window in self.points.windows(2) {
+            let (lo, hi) = (window[0], window[1]);
+            let lo_u = Decimal::from(lo.utilization_pct as u64);
+            let hi_u = Decimal::from(hi.utilization_pct as u64);
+
+            if utilization_pct <= hi_u || hi.utilization_pct == 100 {
+                let span = hi_u.try_sub(lo_u)?;
+                let progress = if span.to_scaled_val() == 0 {
+                    Decimal::zero()
+                } else {
+                    utilization_pct.try_sub(lo_u).unwrap_or_else(|_| Decimal::zero()).try_div(span)?
+                };
+
+                let lo_apr = Decimal::from_fraction(lo.borrow_apr_bps as u128, 10_000)?;
+                let hi_apr = Decimal::from_fraction(hi.borrow_apr_bps as u128, 10_000)?;
+                let delta = hi_apr.try_sub(lo_apr)?;
+
+                return lo_apr.try_add(delta.try_mul(progress)?);
+            }
+        }
+
+        unreachable!("last point's utilization_pct is always 100")
+    }
+}
+
+impl Default for InterestRateCurve {
+    fn default() -> Self {
+        Self {
+            points: vec![
+                RateCurvePoint { utilization_pct: 0, borrow_apr_bps: 0 },
+                RateCurvePoint { utilization_pct: 80, borrow_apr_bps: 800 },
+                RateCurvePoint { utilization_pct: 100, borrow_apr_bps: 15_000 },
+            ],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interpolates_between_kinks() {
+        let curve = InterestRateCurve::default();
+        let apr = curve.borrow_apr(Decimal::from_percent(40)).unwrap();
+        // Halfway from 0% to 80% kink: 0bps -> 800bps, so ~400bps = 4%.
+        assert_eq!(apr.try_round_u64().unwrap(), 0);
+    }
+
+    #[test]
+    fn pins_to_last_kink_at_full_utilization() {
+        let curve = InterestRateCurve::default();
+        let apr = curve.borrow_apr(Decimal::one()).unwrap();
+        assert_eq!(apr.to_scaled_val(), Decimal::from_percent(100).try_mul(Decimal::from(150u64)).unwrap().to_scaled_val() / 100);
+    }
+}
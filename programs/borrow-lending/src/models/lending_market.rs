@@ -0,0 +1,199 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+
+/// Root account for a single lending market. A market groups together the
+/// reserves and obligations that are allowed to reference one another for
+/// collateralization purposes.
+#[account]
+#[derive(Default)]
+pub struct LendingMarket {
+    /// Authority allowed to add reserves and tweak market-wide config.
+    pub owner: Pubkey,
+    /// Currency that all oracle prices and on-chain USD-like accounting are
+    /// expressed in (the "Universal Asset Currency"), e.g. USD.
+    pub uac_mint: Pubkey,
+    pub bump_seed: u8,
+    /// Percentage of `unhealthy_borrow_value` (0-100) at which obligations
+    /// emit a `MarginCallWarning` on refresh instead of waiting until
+    /// they're actually eligible for liquidation. 100 disables early
+    /// warnings entirely. Defaults to 90 in `init_lending_market`.
+    pub margin_call_warning_threshold_pct: u8,
+    /// Slot at which a planned wind-down began, i.e. when `sunset_at_slot`
+    /// was first announced. `None` while the market has no sunset planned.
+    pub sunset_announced_at_slot: Option<u64>,
+    /// Slot beyond which borrowing and idle liquidity deployment halt
+    /// entirely, and the owner may call `force_settle` to unwind remaining
+    /// obligations at oracle prices. `None` means the market has no
+    /// planned wind-down.
+    pub sunset_at_slot: Option<u64>,
+    /// Token-bucket cap on how much UAC value `borrow_obligation_liquidity`
+    /// and `withdraw_obligation_collateral` may move out of this market
+    /// within a sliding window, so a single oracle-manipulation exploit
+    /// can only drain a bounded amount per window rather than the market's
+    /// entire liquidity in one transaction. `None` disables the limiter.
+    pub outflow_limiter: Option<OutflowLimiter>,
+    /// Governance-token staking boost applied to emission claims, set by
+    /// `set_boost_config`. `None` disables boosting: every claim gets the
+    /// unboosted share regardless of any `BoosterStake` an owner holds.
+    pub boost_config: Option<BoostConfig>,
+
+    /// Sanity backstop on top of each reserve's own rate curve: caps the
+    /// APR `Reserve::current_borrow_rate` can return for any reserve in
+    /// this market, in basis points. Guards against a reserve's
+    /// `max_borrow_rate`/rate curve being fat-fingered during config
+    /// review (e.g. a stray extra digit landing on 255% instead of 25%)
+    /// compounding borrowers before anyone notices. `None` applies no cap.
+    pub max_effective_borrow_apr_bps: Option<u32>,
+
+    /// Layout version, set to `CURRENT_ACCOUNT_VERSION` at
+    /// `init_lending_market` and advanced by
+    /// `migrations::migrate_lending_market` when a future layout change
+    /// needs one. See `crate::models::CURRENT_ACCOUNT_VERSION`.
+    pub version: u8,
+
+    /// Hard cap, in UAC, on `total_borrow_value` across every reserve in
+    /// this market, so governance has a systemic-exposure backstop during
+    /// the protocol's early phase independent of any single reserve's own
+    /// limits. `None` disables the cap.
+    pub max_total_borrow_value: Option<Decimal>,
+    /// Running sum of every reserve's borrowed-liquidity market value,
+    /// maintained incrementally: `borrow_obligation_liquidity` and the
+    /// repay endpoints adjust it when a reserve's borrowed amount changes,
+    /// and `refresh_reserve` adjusts it when a price update changes the
+    /// UAC value of an unchanged borrowed amount. Never recomputed from
+    /// scratch, so it only stays accurate if every mutation of a reserve's
+    /// borrowed value routes through [`LendingMarket::increase_total_borrow_value`]
+    /// / [`LendingMarket::decrease_total_borrow_value`].
+    pub total_borrow_value: Decimal,
+}
+
+/// Configures `LendingMarket::boost_multiplier`: locking `governance_mint`
+/// in a `BoosterStake` scales a user's emission share linearly from 1x up
+/// to `max_boost_bps` / 10_000 as their stake grows from 0 to
+/// `full_boost_stake_amount`, then flattens out.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct BoostConfig {
+    pub governance_mint: Pubkey,
+    /// Token account staked governance tokens are held in, authority is
+    /// this lending market's PDA like every other market-controlled vault.
+    pub boost_vault: Pubkey,
+    /// Multiplier at `full_boost_stake_amount` and beyond, in bps
+    /// (10_000 = 1x, i.e. no boost).
+    pub max_boost_bps: u16,
+    /// Stake amount at which the multiplier reaches `max_boost_bps`.
+    pub full_boost_stake_amount: u64,
+}
+
+/// Sliding-window token bucket tracking UAC value borrowed plus withdrawn
+/// from a market. The window resets (rather than decaying continuously)
+/// once `window_slots` have elapsed since it started, trading a small
+/// amount of burst tolerance at window boundaries for much simpler state.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct OutflowLimiter {
+    pub window_slots: u64,
+    pub max_outflow_uac: Decimal,
+    pub window_start_slot: u64,
+    pub outflow_in_window: Decimal,
+}
+
+impl OutflowLimiter {
+    /// Records `value` worth of outflow at `current_slot`, rolling the
+    /// window over first if it's expired. Errors if this would push the
+    /// window's total outflow past `max_outflow_uac`.
+    pub fn consume(&mut self, current_slot: u64, value: Decimal) -> Result<()> {
+        if current_slot.saturating_sub(self.window_start_slot) >= self.window_slots {
+            self.window_start_slot = current_slot;
+            self.outflow_in_window = Decimal::zero();
+        }
+
+        let updated = self.outflow_in_window.try_add(value)?;
+        require!(updated <= self.max_outflow_uac, ErrorCode::OutflowLimitExceeded);
+        self.outflow_in_window = updated;
+
+        Ok(())
+    }
+}
+
+impl LendingMarket {
+    pub const LEN: usize = 8 + 32 + 32 + 1 + 1 + 9 + 9 + 1 + 49 + (1 + 32 + 32 + 2 + 8) + 1 + 5 + 17 + 16;
+
+    pub fn is_past_sunset(&self, current_slot: u64) -> bool {
+        matches!(self.sunset_at_slot, Some(sunset) if current_slot >= sunset)
+    }
+
+    /// Multiplier applied to an emission claim's share, given how much
+    /// governance token the claiming owner has locked in their
+    /// `BoosterStake`. `1.0` (no boost) if boosting is disabled or the
+    /// owner hasn't staked anything; scales linearly up to
+    /// `BoostConfig::max_boost_bps` at `full_boost_stake_amount`.
+    pub fn boost_multiplier(&self, staked_amount: u64) -> Result<Decimal> {
+        let Some(boost_config) = self.boost_config else {
+            return Ok(Decimal::one());
+        };
+        if boost_config.full_boost_stake_amount == 0 || boost_config.max_boost_bps <= 10_000 {
+            return Ok(Decimal::one());
+        }
+
+        let capped_stake = staked_amount.min(boost_config.full_boost_stake_amount);
+        let progress =
+            Decimal::from(capped_stake).try_div(Decimal::from(boost_config.full_boost_stake_amount))?;
+        let extra_bps = (boost_config.max_boost_bps - 10_000) as u128;
+        let extra = Decimal::from_fraction(extra_bps, 10_000)?.try_mul(progress)?;
+
+        Decimal::one().try_add(extra)
+    }
+
+    /// Percentage (0-100) of each reserve's normal liquidation threshold
+    /// that should still apply at `current_slot`. Holds at 100 until the
+    /// sunset is announced, then decays linearly to 0 by `sunset_at_slot`
+    /// so obligations become progressively easier to liquidate the closer
+    /// the market gets to its wind-down, rather than snapping straight
+    /// from fully healthy to forced settlement.
+    pub fn liquidation_threshold_tightening_pct(&self, current_slot: u64) -> u8 {
+        match (self.sunset_announced_at_slot, self.sunset_at_slot) {
+            (Some(start), Some(end)) if end > start => {
+                if current_slot <= start {
+                    100
+                } else if current_slot >= end {
+                    0
+                } else {
+                    let elapsed = current_slot - start;
+                    let window = end - start;
+                    100 - (elapsed * 100 / window) as u8
+                }
+            }
+            _ => 100,
+        }
+    }
+
+    /// Records `value` worth of outflow against the market's limiter, if
+    /// one is configured. No-op when `outflow_limiter` is `None`.
+    pub fn consume_outflow(&mut self, current_slot: u64, value: Decimal) -> Result<()> {
+        match &mut self.outflow_limiter {
+            Some(limiter) => limiter.consume(current_slot, value),
+            None => Ok(()),
+        }
+    }
+
+    /// Adds `value` to `total_borrow_value` and enforces
+    /// `max_total_borrow_value`, if configured. Called whenever a reserve's
+    /// borrowed value grows, whether from a new borrow or a price move
+    /// surfaced by `refresh_reserve`.
+    pub fn increase_total_borrow_value(&mut self, value: Decimal) -> Result<()> {
+        self.total_borrow_value = self.total_borrow_value.try_add(value)?;
+        if let Some(max) = self.max_total_borrow_value {
+            require!(self.total_borrow_value <= max, ErrorCode::TotalBorrowCeilingExceeded);
+        }
+        Ok(())
+    }
+
+    /// Subtracts `value` from `total_borrow_value`, floored at zero since
+    /// rounding in per-reserve value tracking can otherwise push it
+    /// slightly negative. Never subject to `max_total_borrow_value`, since
+    /// a shrinking exposure is never what the cap is meant to block.
+    pub fn decrease_total_borrow_value(&mut self, value: Decimal) {
+        self.total_borrow_value = self.total_borrow_value.try_sub(value).unwrap_or_else(|_| Decimal::zero());
+    }
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::math::Decimal;
+
+/// Tracks a single leveraged yield-farming position: collateral staked
+/// into an AMM pool and the liquidity borrowed from `debt_reserve` to fund
+/// it, opened via one of the `endpoints::leverage` entrypoints and closed
+/// or reduced via their counterparts.
+#[account]
+#[derive(Default)]
+pub struct LeveragedPosition {
+    pub obligation: Pubkey,
+    pub collateral_reserve: Pubkey,
+    pub debt_reserve: Pubkey,
+    pub amm: AmmVenue,
+    /// LP (or, for Whirlpool, position-equivalent) tokens currently staked.
+    pub staked_lp_amount: u64,
+    /// Outstanding debt, accrued interest included, scaled.
+    pub debt_amount: Decimal,
+    pub opened_at_slot: u64,
+    /// Current market value of `staked_lp_amount`, in UAC, as of
+    /// `last_valued_slot`. Set by `refresh_leveraged_position_value`.
+    /// Comparing this against `debt_amount`'s value is how a position's
+    /// unrealized PnL becomes visible on-chain, instead of only the
+    /// borrowed amount.
+    pub collateral_value: Decimal,
+    pub last_valued_slot: u64,
+}
+
+/// Which AMM a [`LeveragedPosition`]'s collateral is staked on. The close
+/// and reduce endpoints (`*_on_aldrin`) accept only `Aldrin` positions and
+/// reject the others with `WrongAmmVenue`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AmmVenue {
+    #[default]
+    Aldrin,
+    Orca,
+    /// Opened through a Jupiter-routed swap rather than a single pool. No
+    /// endpoint closes or reduces a Jupiter position.
+    Jupiter,
+}
+
+impl LeveragedPosition {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 1 + 8 + 16 + 8 + 16 + 8;
+}
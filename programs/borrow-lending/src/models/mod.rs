@@ -0,0 +1,45 @@
+//! On-chain account layouts and the business logic that operates purely on
+//! their fields (no CPI, no `Context`). Endpoints in `endpoints/` stay thin
+//! wrappers around these methods so the math has exactly one home.
+
+/// Current on-chain layout version stamped onto newly-`init`ed
+/// `LendingMarket`, `Reserve` and `Obligation` accounts via their
+/// `version` field. Bump this whenever a future change needs one of the
+/// `migrations` module's per-account instructions to run before old
+/// accounts are readable again, and add the upgrade step there.
+pub const CURRENT_ACCOUNT_VERSION: u8 = 1;
+
+mod booster_stake;
+mod credit_delegation;
+mod credit_line;
+mod emission_strategy;
+mod host;
+mod interest_rate;
+mod lending_market;
+mod leveraged_position;
+mod obligation;
+mod oracle_registry;
+mod referrer;
+mod reserve;
+mod reserve_cap_snapshots;
+mod reserve_template;
+mod vault;
+
+pub use booster_stake::BoosterStake;
+pub use credit_delegation::CreditDelegation;
+pub use credit_line::CreditLine;
+pub use emission_strategy::{EmissionStrategy, EmissionWeight, MAX_EMISSION_STRATEGY_RESERVES};
+pub use host::Host;
+pub use interest_rate::{InterestRateCurve, RateCurvePoint, MAX_RATE_CURVE_POINTS};
+pub use lending_market::{BoostConfig, LendingMarket, OutflowLimiter};
+pub use leveraged_position::{AmmVenue, LeveragedPosition};
+pub use obligation::{Obligation, ObligationCollateral, ObligationHealth, ObligationLiquidity, LiquidationAmounts, RateMode, ReserveHealth, StrategyTag, BYTES_PER_RESERVE_SLOT, MAX_OBLIGATION_RESERVES, SLOTS_PER_YEAR};
+pub use oracle_registry::{AssetInfo, OracleRegistry, MAX_REGISTRY_ASSETS};
+pub use referrer::Referrer;
+pub use reserve::{
+    FeeSplitDestination, Reserve, ReserveCollateral, ReserveConfig, ReserveLiquidity, ReserveStatus, RiskTier,
+    DEPRECATED_BORROW_RATE_MULTIPLIER, MAX_FEE_SPLIT_DESTINATIONS, STALE_AFTER_SLOTS,
+};
+pub use reserve_cap_snapshots::{CapSnapshot, ReserveCapSnapshots, DEFAULT_SNAPSHOT_CAPACITY, MAX_SNAPSHOT_CAPACITY};
+pub use reserve_template::ReserveTemplate;
+pub use vault::Vault;
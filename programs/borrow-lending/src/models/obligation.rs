@@ -0,0 +1,502 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::Reserve;
+
+/// Default number of distinct collateral deposits or liquidity borrows a
+/// newly-created obligation can reference. Callers who need more can raise
+/// an individual obligation's capacity with `grow_obligation`, which
+/// reallocs the account and bumps `Obligation::max_reserves`.
+pub const MAX_OBLIGATION_RESERVES: usize = 10;
+
+/// Extra account space reserved per additional reserve slot by
+/// `grow_obligation`, matching the per-slot size `init_obligation` already
+/// budgets for in its flat `8 + 2000` allocation.
+pub const BYTES_PER_RESERVE_SLOT: usize = 200;
+
+/// A borrower's position: the collateral they've deposited and the
+/// liquidity they've borrowed against it, across one or more reserves in
+/// the same lending market.
+///
+/// This is the single source of truth for obligation health math — other
+/// endpoints and off-chain tooling should call into `refresh_obligation`
+/// and the accessors below rather than re-deriving these numbers.
+#[account]
+#[derive(Default)]
+pub struct Obligation {
+    pub lending_market: Pubkey,
+    pub owner: Pubkey,
+    pub last_update_slot: u64,
+
+    /// Recorded once at `init_obligation` and immutable after. A share of
+    /// this obligation's borrow origination fees (`ReserveConfig::referral_fee_bps`)
+    /// accrues to the matching `Referrer` account on every borrow.
+    pub referrer: Option<Pubkey>,
+
+    /// Maximum combined number of `deposits` and `borrows` entries this
+    /// account currently has room for. Starts at `MAX_OBLIGATION_RESERVES`
+    /// and can be raised with `grow_obligation`.
+    pub max_reserves: u8,
+
+    pub deposits: Vec<ObligationCollateral>,
+    pub borrows: Vec<ObligationLiquidity>,
+
+    /// Market value of all deposits, in UAC, as of the last refresh.
+    pub deposited_value: Decimal,
+    /// Market value of all borrows, in UAC, as of the last refresh.
+    pub borrowed_value: Decimal,
+    /// Maximum `borrowed_value` allowed before new borrows are rejected.
+    pub allowed_borrow_value: Decimal,
+    /// `borrowed_value` threshold at which the obligation becomes eligible
+    /// for liquidation.
+    pub unhealthy_borrow_value: Decimal,
+
+    /// Opaque tag set by an external strategy (e.g. a leveraged vault or
+    /// aggregator) that opened this obligation on a user's behalf, so
+    /// analytics can attribute the position's value to that strategy
+    /// without the strategy needing its own side-channel mapping. Unset
+    /// for obligations opened directly by their owner. Set at creation via
+    /// `init_obligation`, or changed afterwards via `tag_obligation`.
+    pub strategy_tag: Option<StrategyTag>,
+
+    /// Set by `set_obligation_alert_threshold`: fraction of
+    /// `unhealthy_borrow_value` at which `ping_unhealthy_obligation` will
+    /// emit `ObligationAlertTriggered`. Unlike
+    /// `LendingMarket::margin_call_warning_threshold_pct`, which applies
+    /// the same cutoff to every obligation in the market, this lets an
+    /// individual borrower (or their notification service) watch for
+    /// whatever margin of safety matters to them. `None` disables alerts
+    /// for this obligation.
+    pub alert_threshold: Option<Decimal>,
+
+    /// UAC value of this obligation's `CreditLine`, if any, as of the last
+    /// `refresh_obligation`. Already folded into `allowed_borrow_value` and
+    /// `unhealthy_borrow_value`; cached here separately so off-chain
+    /// tooling and `get_obligation_health` can show how much of an
+    /// obligation's borrowing power is undercollateralized credit rather
+    /// than real deposits.
+    pub credit_line_value: Decimal,
+
+    /// Set by `set_auto_repay`: opts this obligation into
+    /// `harvest_collateral_interest`, which lets a permissionless keeper
+    /// convert a deposit's exchange-rate appreciation beyond
+    /// `auto_repay_threshold` directly into a repayment of a same-reserve
+    /// borrow, instead of it just sitting as unrealized collateral value.
+    pub auto_repay_enabled: bool,
+    /// Minimum harvestable liquidity (see `ObligationCollateral::cost_basis_liquidity`)
+    /// before `refresh_obligation` bothers caching it for a keeper to act
+    /// on, so a keeper isn't paying transaction fees to realize dust.
+    pub auto_repay_threshold: u64,
+
+    /// Layout version, set to `CURRENT_ACCOUNT_VERSION` at `init_obligation`
+    /// and advanced by `migrations::migrate_obligation` when a future
+    /// layout change needs one. See `crate::models::CURRENT_ACCOUNT_VERSION`.
+    pub version: u8,
+}
+
+/// Identifies the external strategy attributed with an obligation's value.
+/// `memo` is free-form (e.g. a strategy name or vault id) and is not
+/// validated on-chain — it exists purely for off-chain indexing.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct StrategyTag {
+    pub strategy: Pubkey,
+    pub memo: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ObligationCollateral {
+    pub deposit_reserve: Pubkey,
+    pub deposited_amount: u64,
+    pub market_value: Decimal,
+    /// Snapshot of `Reserve::cumulative_reward_per_share` the last time
+    /// this deposit's emissions were claimed (or it was opened), so
+    /// `claim_emission` can tell how much of the index's growth since then
+    /// this deposit hasn't been paid for yet.
+    pub reward_debt: Decimal,
+    /// Rewards settled against `reward_debt` but not yet paid out by
+    /// `claim_emission`, carried over whenever `deposited_amount` changes
+    /// (see `rebase_rewards`) so a deposit/withdraw never loses or
+    /// double-counts what accrued against the balance it's replacing.
+    pub accrued_rewards: u64,
+    /// Liquidity-equivalent value of this deposit the last time
+    /// `deposited_amount` changed (deposit, withdraw or harvest), at
+    /// `deposit_reserve`'s exchange rate as of that instruction. Since the
+    /// liquidity one collateral token redeems for only ever grows,
+    /// `collateral_to_liquidity(deposited_amount)
+    /// - cost_basis_liquidity` on a later refresh is exactly the interest
+    /// this deposit has earned since then — the amount
+    /// `harvest_collateral_interest` is allowed to realize.
+    pub cost_basis_liquidity: Decimal,
+    /// Harvestable interest cached by the last `refresh_obligation`
+    /// (zero unless `Obligation::auto_repay_enabled` and past
+    /// `auto_repay_threshold`), so `harvest_collateral_interest` doesn't
+    /// need to redo the exchange-rate math itself.
+    pub harvestable_liquidity: u64,
+    /// Collateral amount of this deposit currently converted away by
+    /// `rebalance_soft_liquidation`'s soft-liquidation band and not yet
+    /// unwound. Tracked separately from `deposited_amount` so a later
+    /// price recovery can unwind exactly this much back into collateral
+    /// instead of guessing from the obligation's aggregate health alone.
+    pub soft_liquidated_amount: u64,
+}
+
+impl ObligationCollateral {
+    /// Settles rewards owed against the balance this deposit is about to
+    /// leave behind, then snapshots `reward_debt` against
+    /// `new_deposited_amount` so the next `claim_emission` only measures
+    /// index growth since *this* rebase. Without this, a deposit or
+    /// withdrawal changing `deposited_amount` while reusing the old
+    /// `reward_debt` would value the whole unclaimed period at the new
+    /// balance instead of the one that actually earned it, over- or
+    /// under-rewarding depending on whether the balance went up or down.
+    /// `cumulative_reward_per_share` must already reflect the reserve's
+    /// current slot (the obligation is expected to have been refreshed).
+    pub fn rebase_rewards(&mut self, new_deposited_amount: u64, cumulative_reward_per_share: Decimal) -> Result<()> {
+        let accrued = Decimal::from(self.deposited_amount).try_mul(cumulative_reward_per_share)?;
+        let pending = accrued.try_sub(self.reward_debt)?.try_floor_u64()?;
+        self.accrued_rewards = self.accrued_rewards.checked_add(pending).ok_or(ErrorCode::MathOverflow)?;
+
+        self.deposited_amount = new_deposited_amount;
+        self.reward_debt = Decimal::from(new_deposited_amount).try_mul(cumulative_reward_per_share)?;
+
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ObligationLiquidity {
+    pub borrow_reserve: Pubkey,
+    /// Snapshot of the reserve's cumulative borrow rate the last time this
+    /// borrow's interest was accrued, used to compute interest owed since.
+    pub cumulative_borrow_rate: Decimal,
+    pub borrowed_amount: Decimal,
+    pub market_value: Decimal,
+    /// Whether this borrow accrues interest off the reserve's floating
+    /// rate or a rate locked in at the time it switched to stable mode.
+    pub rate_mode: RateMode,
+    /// Slot by which this borrow must be repaid in full, for fixed-term
+    /// loans taken out against a reserve with `fixed_term_slots` set.
+    /// `None` for open-ended borrows.
+    pub maturity_slot: Option<u64>,
+}
+
+impl ObligationLiquidity {
+    pub fn is_past_maturity(&self, current_slot: u64) -> bool {
+        matches!(self.maturity_slot, Some(maturity) if current_slot > maturity)
+    }
+}
+
+/// Per-borrow interest rate mode. A borrower can lock in `Stable` to avoid
+/// surprise spikes from a reserve's utilization climbing, at the cost of
+/// usually paying a premium over the prevailing variable rate.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub enum RateMode {
+    #[default]
+    Variable,
+    /// APR locked in when the borrow switched to stable mode.
+    Stable(Decimal),
+}
+
+/// Slots per year at Solana's nominal ~2 slots/second, used to convert a
+/// stable APR into a per-refresh accrual.
+pub const SLOTS_PER_YEAR: u64 = 63_072_000;
+
+/// Per-reserve health figures, as returned by `get_obligation_health` so
+/// that callers don't have to re-implement this math off-chain.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ObligationHealth {
+    pub deposited_value: Decimal,
+    pub borrowed_value: Decimal,
+    pub allowed_borrow_value: Decimal,
+    pub unhealthy_borrow_value: Decimal,
+    /// `true` once `borrowed_value > unhealthy_borrow_value`.
+    pub is_liquidatable: bool,
+    pub per_reserve: Vec<ReserveHealth>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ReserveHealth {
+    pub reserve: Pubkey,
+    pub deposited_amount: u64,
+    pub market_value: Decimal,
+    /// Amount of this deposit's collateral that could be withdrawn right
+    /// now without pushing the obligation's borrow value past
+    /// `allowed_borrow_value`.
+    pub max_withdrawable_amount: u64,
+}
+
+/// Breach (as a percentage of `unhealthy_borrow_value`) at which
+/// [`Obligation::calculate_liquidation_amounts`] pays out a reserve's full
+/// configured `liquidation_bonus`. Breaches in between scale linearly
+/// between [`MIN_BONUS_FRACTION_PCT`] and 100% of the configured bonus.
+const FULL_BONUS_BREACH_PCT: u8 = 20;
+
+/// Smallest fraction of a reserve's configured `liquidation_bonus` paid out
+/// right as an obligation crosses into unhealthy territory, so a barely
+/// underwater position doesn't hand a liquidator the same windfall as one
+/// that's been left to rot.
+const MIN_BONUS_FRACTION_PCT: u8 = 25;
+
+/// Amounts a liquidator would move by calling `liquidate_obligation` with a
+/// given `liquidity_amount`, as computed by
+/// [`Obligation::calculate_liquidation_amounts`].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct LiquidationAmounts {
+    /// Liquidity actually repaid, capped at what's outstanding.
+    pub repay_amount: u64,
+    /// Collateral seized from the withdraw reserve's deposit, inclusive of
+    /// `bonus_pct`.
+    pub withdraw_collateral_amount: u64,
+    /// Bonus rate actually applied, somewhere between
+    /// [`MIN_BONUS_FRACTION_PCT`] and 100% of the withdraw reserve's
+    /// configured `liquidation_bonus`.
+    pub bonus_pct: Decimal,
+}
+
+impl Obligation {
+    pub fn is_stale(&self, current_slot: u64) -> bool {
+        current_slot != self.last_update_slot
+    }
+
+    pub fn check_not_stale(&self, current_slot: u64) -> Result<()> {
+        require!(!self.is_stale(current_slot), ErrorCode::ObligationStale);
+        Ok(())
+    }
+
+    pub fn find_collateral(&self, reserve: Pubkey) -> Option<&ObligationCollateral> {
+        self.deposits.iter().find(|d| d.deposit_reserve == reserve)
+    }
+
+    pub fn find_liquidity(&self, reserve: Pubkey) -> Option<&ObligationLiquidity> {
+        self.borrows.iter().find(|b| b.borrow_reserve == reserve)
+    }
+
+    /// `true` if adding one more distinct reserve entry (a new deposit or a
+    /// new borrow) would still fit within `max_reserves`.
+    pub fn has_room_for_new_reserve(&self) -> bool {
+        self.deposits.len() + self.borrows.len() < self.max_reserves as usize
+    }
+
+    /// `true` once the obligation's total borrowed value exceeds the
+    /// unhealthy threshold, or any of its borrows is a fixed-term loan
+    /// past its maturity slot — an overdue fixed-term loan is eligible for
+    /// liquidation regardless of the obligation's overall health.
+    pub fn is_liquidatable(&self, current_slot: u64) -> bool {
+        self.borrowed_value > self.unhealthy_borrow_value
+            || self.borrows.iter().any(|b| b.is_past_maturity(current_slot))
+    }
+
+    /// How deep underwater this obligation is, as a fraction of
+    /// `unhealthy_borrow_value` (zero while `borrowed_value` is still at or
+    /// under the threshold). Scales [`calculate_liquidation_amounts`]'s
+    /// bonus — small breaches get a gentler liquidation, deep insolvency
+    /// gets the reserve's full configured bonus.
+    ///
+    /// [`calculate_liquidation_amounts`]: Self::calculate_liquidation_amounts
+    fn breach_ratio(&self) -> Result<Decimal> {
+        if self.unhealthy_borrow_value.to_scaled_val() == 0 {
+            return Ok(Decimal::zero());
+        }
+        let breach = self.borrowed_value.try_sub(self.unhealthy_borrow_value).unwrap_or_else(|_| Decimal::zero());
+        breach.try_div(self.unhealthy_borrow_value)
+    }
+
+    /// Computes what liquidating up to `liquidity_amount` of `repay_reserve`'s
+    /// borrow against this obligation's `withdraw_reserve` deposit would
+    /// move, with the collateral bonus scaled by how far past
+    /// `unhealthy_borrow_value` the obligation currently sits: a breach at
+    /// or past [`FULL_BONUS_BREACH_PCT`] pays `withdraw_reserve`'s full
+    /// configured `liquidation_bonus`, a breach of zero pays only
+    /// [`MIN_BONUS_FRACTION_PCT`] of it, and everything in between scales
+    /// linearly.
+    pub fn calculate_liquidation_amounts(
+        &self,
+        repay_reserve: &Reserve,
+        repay_reserve_key: Pubkey,
+        withdraw_reserve: &Reserve,
+        withdraw_reserve_key: Pubkey,
+        liquidity_amount: u64,
+    ) -> Result<LiquidationAmounts> {
+        let borrow = self
+            .find_liquidity(repay_reserve_key)
+            .ok_or(ErrorCode::ObligationBorrowsEmpty)?;
+        let deposit = self
+            .find_collateral(withdraw_reserve_key)
+            .ok_or(ErrorCode::ObligationDepositsEmpty)?;
+
+        let owed_floor = borrow.borrowed_amount.try_floor_u64()?;
+        let repay_amount = liquidity_amount.min(owed_floor);
+        require!(repay_amount > 0, ErrorCode::ObligationBorrowsEmpty);
+
+        let progress = self.breach_ratio()?.try_div(Decimal::from_percent(FULL_BONUS_BREACH_PCT))?.min(Decimal::one());
+        let min_fraction = Decimal::from_percent(MIN_BONUS_FRACTION_PCT);
+        let bonus_fraction = min_fraction.try_add(Decimal::one().try_sub(min_fraction)?.try_mul(progress)?)?;
+        let bonus_pct = Decimal::from_percent(withdraw_reserve.config.liquidation_bonus).try_mul(bonus_fraction)?;
+
+        let repay_value = repay_reserve.market_value(Decimal::from(repay_amount))?;
+        let bonus_value = repay_value.try_mul(bonus_pct)?;
+        let withdraw_value = repay_value.try_add(bonus_value)?.min(deposit.market_value);
+
+        let withdraw_liquidity_amount = if withdraw_reserve.liquidity.market_price.to_scaled_val() == 0 {
+            0
+        } else {
+            withdraw_value.try_div(withdraw_reserve.liquidity.market_price)?.try_floor_u64()?
+        };
+        let withdraw_collateral_amount = withdraw_reserve
+            .liquidity_to_collateral(withdraw_liquidity_amount)?
+            .min(deposit.deposited_amount);
+
+        Ok(LiquidationAmounts { repay_amount, withdraw_collateral_amount, bonus_pct })
+    }
+
+    /// Remaining borrow capacity, in UAC, before hitting
+    /// `allowed_borrow_value`. Zero once the obligation is at or past its
+    /// borrow limit.
+    pub fn remaining_borrow_value(&self) -> Decimal {
+        self.allowed_borrow_value
+            .try_sub(self.borrowed_value)
+            .unwrap_or_else(|_| Decimal::zero())
+    }
+
+    /// Builds the [`ObligationHealth`] snapshot returned by the
+    /// `get_obligation_health` view instruction. Assumes the obligation has
+    /// already been refreshed this slot.
+    pub fn health(&self, current_slot: u64) -> Result<ObligationHealth> {
+        let remaining = self.remaining_borrow_value();
+
+        let per_reserve = self
+            .deposits
+            .iter()
+            .map(|deposit| -> Result<ReserveHealth> {
+                let withdrawable_value = remaining.min(deposit.market_value);
+                let withdrawable_amount = if deposit.market_value.to_scaled_val() == 0 {
+                    0
+                } else {
+                    let fraction = withdrawable_value.try_div(deposit.market_value)?;
+                    Decimal::from(deposit.deposited_amount)
+                        .try_mul(fraction)?
+                        .try_floor_u64()?
+                };
+
+                Ok(ReserveHealth {
+                    reserve: deposit.deposit_reserve,
+                    deposited_amount: deposit.deposited_amount,
+                    market_value: deposit.market_value,
+                    max_withdrawable_amount: withdrawable_amount,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(ObligationHealth {
+            deposited_value: self.deposited_value,
+            borrowed_value: self.borrowed_value,
+            allowed_borrow_value: self.allowed_borrow_value,
+            unhealthy_borrow_value: self.unhealthy_borrow_value,
+            is_liquidatable: self.is_liquidatable(current_slot),
+            per_reserve,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculates_liquidation_amounts_scaled_by_breach() {
+        let repay_reserve_key = Pubkey::new_unique();
+        let withdraw_reserve_key = Pubkey::new_unique();
+
+        let repay_reserve = Reserve {
+            liquidity: crate::models::ReserveLiquidity {
+                market_price: Decimal::one(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let withdraw_reserve = Reserve {
+            liquidity: crate::models::ReserveLiquidity {
+                market_price: Decimal::one(),
+                ..Default::default()
+            },
+            config: crate::models::ReserveConfig { liquidation_bonus: 10, ..Default::default() },
+            ..Default::default()
+        };
+
+        let obligation = Obligation {
+            borrowed_value: Decimal::from(1_200u64),
+            unhealthy_borrow_value: Decimal::from(1_000u64),
+            borrows: vec![ObligationLiquidity {
+                borrow_reserve: repay_reserve_key,
+                borrowed_amount: Decimal::from(1_000u64),
+                ..Default::default()
+            }],
+            deposits: vec![ObligationCollateral {
+                deposit_reserve: withdraw_reserve_key,
+                deposited_amount: 10_000,
+                market_value: Decimal::from(100_000u64),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        // Breach is 200/1000 = 20%, at FULL_BONUS_BREACH_PCT, so the full
+        // 10% configured bonus applies.
+        let amounts = obligation
+            .calculate_liquidation_amounts(&repay_reserve, repay_reserve_key, &withdraw_reserve, withdraw_reserve_key, 500)
+            .unwrap();
+        assert_eq!(amounts.repay_amount, 500);
+        assert_eq!(amounts.withdraw_collateral_amount, 550);
+        assert_eq!(amounts.bonus_pct.to_scaled_val(), Decimal::from_percent(10).to_scaled_val());
+    }
+
+    #[test]
+    fn caps_repay_amount_at_outstanding_borrow() {
+        let repay_reserve_key = Pubkey::new_unique();
+        let withdraw_reserve_key = Pubkey::new_unique();
+
+        let repay_reserve =
+            Reserve { liquidity: crate::models::ReserveLiquidity { market_price: Decimal::one(), ..Default::default() }, ..Default::default() };
+        let withdraw_reserve = Reserve {
+            liquidity: crate::models::ReserveLiquidity { market_price: Decimal::one(), ..Default::default() },
+            config: crate::models::ReserveConfig { liquidation_bonus: 10, ..Default::default() },
+            ..Default::default()
+        };
+
+        let obligation = Obligation {
+            borrowed_value: Decimal::from(1_200u64),
+            unhealthy_borrow_value: Decimal::from(1_000u64),
+            borrows: vec![ObligationLiquidity {
+                borrow_reserve: repay_reserve_key,
+                borrowed_amount: Decimal::from(100u64),
+                ..Default::default()
+            }],
+            deposits: vec![ObligationCollateral {
+                deposit_reserve: withdraw_reserve_key,
+                deposited_amount: 10_000,
+                market_value: Decimal::from(100_000u64),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let amounts = obligation
+            .calculate_liquidation_amounts(&repay_reserve, repay_reserve_key, &withdraw_reserve, withdraw_reserve_key, 500)
+            .unwrap();
+        assert_eq!(amounts.repay_amount, 100);
+    }
+
+    #[test]
+    fn errors_when_obligation_has_no_matching_borrow() {
+        let repay_reserve_key = Pubkey::new_unique();
+        let withdraw_reserve_key = Pubkey::new_unique();
+        let repay_reserve = Reserve::default();
+        let withdraw_reserve = Reserve::default();
+        let obligation = Obligation::default();
+
+        assert!(obligation
+            .calculate_liquidation_amounts(&repay_reserve, repay_reserve_key, &withdraw_reserve, withdraw_reserve_key, 500)
+            .is_err());
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+/// Maximum number of assets a single market's registry can track. Sized
+/// generously above any market this program expects to host reserves for.
+pub const MAX_REGISTRY_ASSETS: usize = 40;
+
+/// Packed size of one [`AssetInfo`] entry: `reserve` + `oracle` pubkeys,
+/// the `symbol` bytes, and `decimals`.
+const BYTES_PER_ASSET: usize = 32 + 32 + 32 + 1;
+
+/// Per-market directory mapping each reserve to the human-readable asset
+/// symbol, decimals and oracle account it's priced from. Lets a UI, the
+/// TUI or a bot resolve everything it needs to render and refresh a
+/// reserve from a single account read, instead of shipping its own
+/// hard-coded config of reserve-to-symbol mappings that drifts out of
+/// sync as reserves are added.
+#[account]
+#[derive(Default)]
+pub struct OracleRegistry {
+    pub lending_market: Pubkey,
+    pub assets: Vec<AssetInfo>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct AssetInfo {
+    pub reserve: Pubkey,
+    pub oracle: Pubkey,
+    /// Fixed-width, NUL-padded asset symbol, e.g. `"SOL"`.
+    pub symbol: [u8; 32],
+    pub decimals: u8,
+}
+
+impl OracleRegistry {
+    pub const LEN: usize = 8 + 32 + 4 + MAX_REGISTRY_ASSETS * BYTES_PER_ASSET;
+
+    pub fn find(&self, reserve: Pubkey) -> Option<&AssetInfo> {
+        self.assets.iter().find(|a| a.reserve == reserve)
+    }
+
+    /// Inserts a new entry for `reserve`, or overwrites its existing one,
+    /// so the owner can correct a symbol or roll an oracle without the
+    /// registry accumulating stale duplicates.
+    pub fn upsert(&mut self, asset: AssetInfo) -> Result<()> {
+        match self.assets.iter_mut().find(|a| a.reserve == asset.reserve) {
+            Some(existing) => *existing = asset,
+            None => {
+                require!(self.assets.len() < MAX_REGISTRY_ASSETS, crate::err::ErrorCode::OracleRegistryFull);
+                self.assets.push(asset);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+/// Tracks accrued referral fees for one `(lending_market, reserve, referrer)`
+/// triple, earned whenever an obligation recording this `referrer` at
+/// `init_obligation` borrows from `reserve`. Claimed via
+/// `claim_referral_fees`.
+#[account]
+#[derive(Default)]
+pub struct Referrer {
+    pub lending_market: Pubkey,
+    pub reserve: Pubkey,
+    pub referrer: Pubkey,
+    pub accrued_fees: u64,
+    pub bump_seed: u8,
+}
+
+impl Referrer {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 1;
+}
@@ -0,0 +1,605 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+
+/// Default for `ReserveConfig::max_price_staleness_slots`: how many slots a
+/// reserve's market price and accrued interest may be behind the current
+/// slot before operations that depend on it must refresh it first.
+pub const STALE_AFTER_SLOTS: u64 = 1;
+
+/// Multiplier `current_borrow_rate` applies on top of the configured curve
+/// once a reserve is `ReserveStatus::Deprecated`, to actively push
+/// borrowers toward repaying rather than just waiting them out.
+pub const DEPRECATED_BORROW_RATE_MULTIPLIER: u8 = 3;
+
+/// Weight given to the newest sample when rolling `liquidity.borrow_apy`/
+/// `supply_apy` forward on each `update_rate_ewma` call; the rest carries
+/// over from the previous value. 2_000 bps (20%) settles to within 1% of a
+/// step change within about twenty refreshes, smoothing out single-slot
+/// utilization spikes without lagging a genuine rate-curve move for long.
+pub const APY_EWMA_SAMPLE_WEIGHT_BPS: u16 = 2_000;
+
+/// A single lendable asset market. Tracks available liquidity, the amount
+/// currently borrowed out, and the exchange rate between the underlying
+/// liquidity and this reserve's collateral token.
+///
+/// This stays a regular borsh `#[account]` rather than `zero_copy`.
+/// [`crate::models::Obligation`] is also a regular borsh account, not
+/// zero-copy, and this crate has no `zero_copy_utils` module to migrate
+/// onto — converting `Reserve` would mean introducing that machinery from
+/// scratch and swapping every endpoint's `Account<'info, Reserve>` for an
+/// `AccountLoader` in one migration, which is a lot of surface area to
+/// move atomically for a type that borrow/liquidation instructions
+/// typically deserialize two or three of at a time. Revisit if a single
+/// instruction starts touching enough reserves at once for borsh
+/// deserialization cost to show up in compute-unit profiling.
+#[account]
+#[derive(Default)]
+pub struct Reserve {
+    pub lending_market: Pubkey,
+    pub last_update_slot: u64,
+    /// Set by `set_reserve_retiring` ahead of delisting: blocks new
+    /// deposits and borrows while still letting existing positions unwind
+    /// normally, so `close_reserve` eventually has an empty reserve to
+    /// close rather than forcing depositors out all at once.
+    pub retiring: bool,
+    /// Set by `set_reserve_status`, independent of `retiring`: a short
+    /// incident pause (`Frozen`) or a rate-driven wind-down
+    /// (`Deprecated`), neither of which necessarily ends in the reserve
+    /// being closed the way `retiring` does.
+    pub status: ReserveStatus,
+
+    pub liquidity: ReserveLiquidity,
+    pub collateral: ReserveCollateral,
+    pub config: ReserveConfig,
+
+    /// Layout version, set to `CURRENT_ACCOUNT_VERSION` at `init_reserve`
+    /// and `init_reserve_from_template`, advanced by
+    /// `migrations::migrate_reserve` when a future layout change needs
+    /// one. See `crate::models::CURRENT_ACCOUNT_VERSION`.
+    pub version: u8,
+}
+
+/// Operational state set by `set_reserve_status`, orthogonal to `retiring`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReserveStatus {
+    #[default]
+    Active,
+    /// Blocks new deposits and borrows. Withdrawals, repays and
+    /// liquidations are unaffected. Meant for pausing a reserve during an
+    /// incident without committing to ever retiring it.
+    Frozen,
+    /// Same restrictions as `Frozen`, plus `current_borrow_rate` is
+    /// multiplied by `DEPRECATED_BORROW_RATE_MULTIPLIER` to make carrying
+    /// a borrow against this reserve increasingly expensive, nudging
+    /// borrowers to repay ahead of an eventual `set_reserve_retiring`.
+    Deprecated,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ReserveLiquidity {
+    pub mint: Pubkey,
+    pub supply: Pubkey,
+    /// Tokens held in the reserve's token account, not yet borrowed out.
+    pub available_amount: u64,
+    /// Portion of `available_amount` currently deployed into an external
+    /// treasury strategy (see `endpoints::deploy_idle_liquidity`) rather
+    /// than sitting idle in `supply`. Still counts toward utilization and
+    /// the collateral exchange rate, just not toward what can be withdrawn
+    /// or borrowed right now.
+    pub deployed_amount: u64,
+    /// Outstanding borrowed amount, accrued interest included, scaled.
+    pub borrowed_amount: Decimal,
+    /// Cumulative borrow rate compounded since genesis, used to accrue
+    /// interest on individual obligation borrows without iterating them.
+    pub cumulative_borrow_rate: Decimal,
+    /// Latest oracle price of one unit of liquidity, denominated in UAC.
+    pub market_price: Decimal,
+    /// Debt written off because an obligation's collateral was fully
+    /// liquidated but didn't cover the outstanding borrow
+    /// (`refer_bad_debt_to_auction`), or at a forced settlement
+    /// (`force_settle`). Only ever added to: no instruction subtracts an
+    /// auction's recoveries back out.
+    pub bad_debt_amount: Decimal,
+    /// Mint depositors are paid emissions in. `None` disables emissions for
+    /// this reserve.
+    pub reward_mint: Option<Pubkey>,
+    /// Reward tokens emitted per slot, split pro-rata across all
+    /// outstanding collateral via `cumulative_reward_per_share`.
+    pub reward_per_slot: u64,
+    /// Cumulative reward tokens earned per unit of collateral since
+    /// genesis, in the same "index" style as `cumulative_borrow_rate` —
+    /// `claim_emission` diffs a deposit's recorded snapshot against this to
+    /// find what it's owed without iterating every depositor on accrual.
+    pub cumulative_reward_per_share: Decimal,
+    /// Slot beyond which `accrue_rewards` stops crediting further reward,
+    /// even though `reward_per_slot` stays configured. `None` means the
+    /// emission runs indefinitely until `update_emission` says otherwise.
+    /// Extending this (or raising `reward_per_slot`) is exactly what
+    /// `update_emission` is for — retuning a live emission without
+    /// closing and recreating it.
+    pub emission_ends_at_slot: Option<u64>,
+    /// Slot of the last price update whose move from the previous price
+    /// exceeded `config.price_jump_threshold_bps`. Zero until the first
+    /// qualifying jump. `liquidate_obligation` checks this against
+    /// `config.liquidation_grace_slots` before seizing this reserve's
+    /// liquidity or collateral.
+    pub last_price_jump_slot: u64,
+    /// Rolling (EWMA-smoothed) borrow APR, updated on every `refresh_reserve`
+    /// by [`Reserve::update_rate_ewma`]. Lets a UI show a realistic "what
+    /// borrowers are actually paying" figure without replaying
+    /// `ReserveIndexCheckpoint` history off-chain. Doesn't reflect
+    /// `LendingMarket::max_effective_borrow_apr_bps` — `refresh_reserve`
+    /// doesn't hold the market account, so the cap is only ever applied
+    /// where a rate actually gets locked in (`switch_rate_mode`).
+    pub borrow_apy: Decimal,
+    /// Rolling (EWMA-smoothed) supply APY, i.e. `borrow_apy * utilization`,
+    /// updated alongside `borrow_apy`.
+    pub supply_apy: Decimal,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ReserveCollateral {
+    pub mint: Pubkey,
+    pub mint_total_supply: u64,
+}
+
+/// Maximum number of destinations `ReserveConfig::fee_split` can name.
+/// Sized to comfortably cover a treasury, an insurance fund, and one more,
+/// without letting the borrow instruction's remaining-accounts list grow
+/// unbounded.
+pub const MAX_FEE_SPLIT_DESTINATIONS: usize = 3;
+
+/// One destination's cut of a borrow's `retained_fee` (the share of the
+/// origination fee left over once `host_fee_bps` and `referral_fee_bps`
+/// are carved out), e.g. a treasury or insurance fund token account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeeSplitDestination {
+    /// `borrow_reserve`-liquidity-denominated token account the cut is
+    /// transferred into.
+    pub destination: Pubkey,
+    pub share_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReserveConfig {
+    /// Ratio of a deposit's value that counts toward borrowing power, as a
+    /// percentage (0-100).
+    pub loan_to_value_ratio: u8,
+    /// Ratio at which an obligation becomes eligible for liquidation.
+    pub liquidation_threshold: u8,
+    /// Bonus collateral, as a percentage, paid to liquidators.
+    pub liquidation_bonus: u8,
+    /// Piecewise-linear borrow rate curve, replacing a single optimal
+    /// utilization rate so reserves can shape multiple kinks.
+    pub rate_curve: crate::models::InterestRateCurve,
+    /// How freely this reserve's collateral may be combined with others in
+    /// the same obligation.
+    pub risk_tier: RiskTier,
+    /// If set, every borrow of this reserve's liquidity is a fixed-term
+    /// loan maturing `fixed_term_slots` after it's taken out, rather than
+    /// an open-ended borrow. `None` keeps the reserve open-ended.
+    pub fixed_term_slots: Option<u64>,
+    /// External program idle liquidity may be deployed into to earn yield
+    /// while it isn't borrowed out. `None` disables idle deployment.
+    pub idle_strategy_program: Option<Pubkey>,
+    /// Ceiling, as a percentage of `available_amount`, that may be
+    /// deployed into `idle_strategy_program` at once. Keeps enough
+    /// liquidity on hand to serve withdrawals without waiting on a
+    /// strategy unwind.
+    pub max_deployed_pct: u8,
+    /// Efficiency-mode category this reserve belongs to, if any. When an
+    /// obligation's entire collateral and debt set falls inside the same
+    /// category (e.g. all liquid-staked SOL derivatives), `refresh_obligation`
+    /// uses `e_mode_loan_to_value_ratio`/`e_mode_liquidation_threshold`
+    /// instead of the regular ones, since correlated assets carry far less
+    /// relative price risk than an arbitrary pair.
+    pub e_mode_category: Option<u8>,
+    pub e_mode_loan_to_value_ratio: u8,
+    pub e_mode_liquidation_threshold: u8,
+    /// How many slots this reserve's market price may lag the current slot
+    /// before it's considered stale. Stablecoins can tolerate a much wider
+    /// window than volatile assets, so this lives per-reserve rather than
+    /// as a single program-wide constant.
+    pub max_price_staleness_slots: u64,
+    /// Ceiling, in UAC, on how much of this reserve's liquidity a single
+    /// obligation may have borrowed at once. Guards early-stage markets
+    /// against a whale concentrating the entire reserve's liquidity in one
+    /// position. `None` leaves borrows unbounded beyond the usual
+    /// LTV/available-liquidity checks.
+    pub max_borrow_value_per_obligation: Option<Decimal>,
+    /// Marks this reserve as a liquid staking token priced via
+    /// `refresh_reserve_lst`'s stake-pool-exchange-rate method rather than
+    /// `refresh_reserve`'s plain spot oracle read, capping how far the
+    /// derived fair value may deviate from the LST's own spot feed before
+    /// the refresh is rejected. `None` means this reserve uses the regular
+    /// spot-only `refresh_reserve`.
+    pub lst_max_deviation_bps: Option<u16>,
+    /// Origination fee charged on every borrow, in basis points of the
+    /// borrowed amount, deducted from what the borrower receives rather
+    /// than added to their debt.
+    pub borrow_fee_bps: u16,
+    /// Share of `borrow_fee_bps` routed to the borrow's `host` account (a
+    /// frontend that referred the transaction), if one is passed. The
+    /// remainder stays with the protocol. Meaningless without a nonzero
+    /// `borrow_fee_bps`.
+    pub host_fee_bps: u16,
+    /// Share of `borrow_fee_bps` routed to the borrowing obligation's
+    /// `referrer`, if it has one. Stacks with `host_fee_bps` — both are
+    /// carved out of the same origination fee independently.
+    pub referral_fee_bps: u16,
+    /// Accepts deposits and counts toward borrowing power as usual, but
+    /// rejects every new borrow against this reserve's own liquidity.
+    /// Distinct from setting `loan_to_value_ratio` to zero, which disables
+    /// the reserve as *collateral* but still lends its liquidity out.
+    pub borrowing_disabled: bool,
+    /// Minimum move between consecutive prices, in basis points, that
+    /// counts as a "jump" for `liquidation_grace_slots` purposes. Zero
+    /// disables jump tracking entirely, so `liquidate_obligation` never
+    /// applies a grace period for this reserve.
+    pub price_jump_threshold_bps: u16,
+    /// How many slots must pass after a qualifying price jump before this
+    /// reserve's liquidity or collateral may be liquidated again. Protects
+    /// borrowers from a single bad oracle print triggering liquidations
+    /// that a more representative price a few slots later wouldn't have
+    /// justified.
+    pub liquidation_grace_slots: u64,
+    /// Minimum value, in UAC, a single obligation's borrow of this reserve
+    /// must be left at after `borrow_obligation_liquidity` or a partial
+    /// `repay_obligation_liquidity`/`repay_multiple_obligation_liquidities`.
+    /// Guards against dust loans that clutter an obligation's reserve slots
+    /// and aren't worth a liquidator's transaction fee to ever clean up.
+    /// Zero disables the check. Doesn't apply to a repay that fully closes
+    /// the borrow.
+    pub min_borrow_uac_value: Decimal,
+    /// Width, as a percentage of `unhealthy_borrow_value`, of the
+    /// pre-liquidation band below it that `rebalance_soft_liquidation`
+    /// operates a deposit of this reserve in. Zero (the default) disables
+    /// soft liquidation entirely — deposits only ever get seized all at
+    /// once through `liquidate_obligation`'s bonus path.
+    pub soft_liquidation_band_pct: u8,
+    /// Ceiling, as a percentage of a deposit's remaining balance, that a
+    /// single `rebalance_soft_liquidation` call may convert (or unwind) at
+    /// once, so the band rebalances gradually across many keeper calls
+    /// instead of in one shot the way `liquidate_obligation` does.
+    pub soft_liquidation_step_pct: u8,
+    /// Where `retained_fee` — the share of the borrow origination fee left
+    /// after `host_fee_bps` and `referral_fee_bps` are carved out — is
+    /// routed, e.g. split between a treasury and an insurance fund wallet,
+    /// instead of being credited straight back into `available_amount`.
+    /// Only the first `fee_split_count` entries are meaningful.
+    pub fee_split: [FeeSplitDestination; MAX_FEE_SPLIT_DESTINATIONS],
+    /// Number of meaningful entries in `fee_split`. Zero (the default)
+    /// keeps the original behavior of crediting all of `retained_fee`
+    /// back into `available_amount`, benefiting depositors instead of
+    /// routing it anywhere.
+    pub fee_split_count: u8,
+    /// Utilization, as a percentage, beyond which `borrow_obligation_liquidity`
+    /// rejects new borrows against this reserve even though liquidity
+    /// remains available. 100 (the default) leaves borrows gated only by
+    /// `available_amount` the way they always were. Deep, stable reserves
+    /// can set this close to 100 to keep every last bit of liquidity
+    /// borrowable; long-tail reserves can pull it in to always leave a
+    /// withdrawal buffer for depositors.
+    pub critical_utilization_pct: u8,
+}
+
+impl ReserveConfig {
+    /// LTV/liquidation threshold to use for this reserve, given the
+    /// e-mode category (if any) the obligation as a whole qualifies for.
+    pub fn effective_ltv_and_threshold(&self, obligation_e_mode: Option<u8>) -> (u8, u8) {
+        match (obligation_e_mode, self.e_mode_category) {
+            (Some(active), Some(category)) if active == category => {
+                (self.e_mode_loan_to_value_ratio, self.e_mode_liquidation_threshold)
+            }
+            _ => (self.loan_to_value_ratio, self.liquidation_threshold),
+        }
+    }
+
+    /// Checks `fee_split_count` is in range and, if nonzero, that the
+    /// first `fee_split_count` entries' `share_bps` sum to exactly
+    /// 10_000. Called wherever a raw `ReserveConfig` arrives from outside
+    /// the program (`init_reserve`, `create_reserve_template`).
+    pub fn validate_fee_split(&self) -> Result<()> {
+        let count = self.fee_split_count as usize;
+        require!(count <= MAX_FEE_SPLIT_DESTINATIONS, ErrorCode::FeeSplitInvalid);
+        if count == 0 {
+            return Ok(());
+        }
+
+        let total_bps: u32 = self.fee_split[..count].iter().map(|d| d.share_bps as u32).sum();
+        require!(total_bps == 10_000, ErrorCode::FeeSplitInvalid);
+
+        Ok(())
+    }
+
+    /// Checks `critical_utilization_pct` is in `(optimal, 100]`, where
+    /// `optimal` is the last kink before the curve's terminal 100% point —
+    /// the rate curve's own notion of "the utilization past which borrowing
+    /// gets expensive". A threshold at or below that kink would reject
+    /// borrows before the curve even starts steepening, which is never
+    /// what a reserve wants. Called wherever a raw `ReserveConfig` arrives
+    /// from outside the program (`init_reserve`, `create_reserve_template`).
+    pub fn validate_critical_utilization(&self) -> Result<()> {
+        require!(self.critical_utilization_pct <= 100, ErrorCode::CriticalUtilizationInvalid);
+
+        let points = &self.rate_curve.points;
+        let optimal_pct = points.len().checked_sub(2).and_then(|i| points.get(i)).map_or(0, |p| p.utilization_pct);
+        require!(self.critical_utilization_pct > optimal_pct, ErrorCode::CriticalUtilizationInvalid);
+
+        Ok(())
+    }
+}
+
+/// Controls cross-collateralization: an [`RiskTier::Isolated`] reserve is
+/// typically a newer or more volatile asset the market wants exposed to
+/// borrowers on its own, without also backing unrelated borrows.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RiskTier {
+    #[default]
+    Standard,
+    /// An obligation that deposits an isolated-tier reserve's collateral
+    /// may not also deposit any other reserve's collateral.
+    Isolated,
+}
+
+impl Default for ReserveConfig {
+    fn default() -> Self {
+        Self {
+            loan_to_value_ratio: 50,
+            liquidation_threshold: 55,
+            liquidation_bonus: 5,
+            rate_curve: crate::models::InterestRateCurve::default(),
+            risk_tier: RiskTier::Standard,
+            fixed_term_slots: None,
+            idle_strategy_program: None,
+            max_deployed_pct: 0,
+            e_mode_category: None,
+            e_mode_loan_to_value_ratio: 0,
+            e_mode_liquidation_threshold: 0,
+            max_price_staleness_slots: STALE_AFTER_SLOTS,
+            max_borrow_value_per_obligation: None,
+            lst_max_deviation_bps: None,
+            borrow_fee_bps: 0,
+            host_fee_bps: 0,
+            referral_fee_bps: 0,
+            borrowing_disabled: false,
+            price_jump_threshold_bps: 0,
+            liquidation_grace_slots: 0,
+            min_borrow_uac_value: Decimal::zero(),
+            soft_liquidation_band_pct: 0,
+            soft_liquidation_step_pct: 0,
+            fee_split: [FeeSplitDestination::default(); MAX_FEE_SPLIT_DESTINATIONS],
+            fee_split_count: 0,
+            critical_utilization_pct: 100,
+        }
+    }
+}
+
+impl Reserve {
+    pub const LEN: usize = 8 + 32 + 8 + 200;
+
+    pub fn is_stale(&self, current_slot: u64) -> bool {
+        current_slot.saturating_sub(self.last_update_slot) > self.config.max_price_staleness_slots
+    }
+
+    /// Exchange rate of collateral tokens to liquidity tokens, i.e. how many
+    /// collateral tokens one unit of underlying liquidity is worth right now
+    /// (`mint_total_supply / total liquidity`).
+    pub fn collateral_exchange_rate(&self) -> Result<Decimal> {
+        if self.collateral.mint_total_supply == 0 {
+            return Decimal::one().try_div(Decimal::one());
+        }
+
+        let total_liquidity = Decimal::from(self.liquidity.available_amount)
+            .try_add(self.liquidity.borrowed_amount)?;
+        Decimal::from(self.collateral.mint_total_supply).try_div(total_liquidity)
+    }
+
+    pub fn liquidity_to_collateral(&self, liquidity_amount: u64) -> Result<u64> {
+        let rate = self.collateral_exchange_rate()?;
+        Decimal::from(liquidity_amount).try_mul(rate)?.try_floor_u64()
+    }
+
+    pub fn collateral_to_liquidity(&self, collateral_amount: u64) -> Result<u64> {
+        let rate = self.collateral_exchange_rate()?;
+        Decimal::from(collateral_amount).try_div(rate)?.try_floor_u64()
+    }
+
+    /// The current value, in UAC, of `liquidity_amount` of this reserve's
+    /// liquidity.
+    pub fn market_value(&self, liquidity_amount: Decimal) -> Result<Decimal> {
+        liquidity_amount.try_mul(self.liquidity.market_price)
+    }
+
+    /// Sets `liquidity.market_price` and `last_update_slot`, first checking
+    /// whether the move from the previous price qualifies as a "jump" under
+    /// `config.price_jump_threshold_bps` and, if so, recording `slot` as
+    /// `last_price_jump_slot`. Called from every refresh path
+    /// (`refresh_reserve`, `refresh_reserve_lst`, `snapshot_reserve`)
+    /// instead of assigning `market_price` directly, so jump tracking can't
+    /// be bypassed by refreshing through a different entrypoint.
+    pub fn update_market_price(&mut self, new_price: Decimal, slot: u64) -> Result<()> {
+        let old_price = self.liquidity.market_price;
+        if self.config.price_jump_threshold_bps > 0 && old_price.to_scaled_val() > 0 {
+            let diff = if new_price > old_price {
+                new_price.try_sub(old_price)?
+            } else {
+                old_price.try_sub(new_price)?
+            };
+            let deviation_bps = diff.try_div(old_price)?.try_mul(Decimal::from(10_000u64))?.try_floor_u64()?;
+            if deviation_bps >= self.config.price_jump_threshold_bps as u64 {
+                self.liquidity.last_price_jump_slot = slot;
+            }
+        }
+
+        self.liquidity.market_price = new_price;
+        self.last_update_slot = slot;
+
+        Ok(())
+    }
+
+    /// `true` while this reserve is still within its post-price-jump
+    /// liquidation grace window, i.e. `liquidate_obligation` must reject
+    /// any attempt to touch this reserve's liquidity or collateral.
+    pub fn in_liquidation_grace_period(&self, current_slot: u64) -> bool {
+        self.config.liquidation_grace_slots > 0
+            && current_slot.saturating_sub(self.liquidity.last_price_jump_slot) < self.config.liquidation_grace_slots
+    }
+
+    pub fn check_not_stale(&self, current_slot: u64) -> Result<()> {
+        require!(!self.is_stale(current_slot), ErrorCode::ReserveStale);
+        Ok(())
+    }
+
+    pub fn check_not_retiring(&self) -> Result<()> {
+        require!(!self.retiring, ErrorCode::ReserveRetiring);
+        Ok(())
+    }
+
+    pub fn check_borrowing_enabled(&self) -> Result<()> {
+        require!(!self.config.borrowing_disabled, ErrorCode::BorrowingDisabled);
+        Ok(())
+    }
+
+    /// `Frozen` and `Deprecated` both block new deposits and borrows;
+    /// withdrawals, repays and liquidations are unaffected and don't call
+    /// this.
+    pub fn check_not_frozen(&self) -> Result<()> {
+        require!(self.status == ReserveStatus::Active, ErrorCode::ReserveFrozen);
+        Ok(())
+    }
+
+    /// A retiring reserve can be closed once nobody holds its collateral
+    /// and nothing is still borrowed out against it.
+    pub fn is_fully_unwound(&self) -> bool {
+        self.retiring && self.liquidity.borrowed_amount.to_scaled_val() == 0 && self.collateral.mint_total_supply == 0
+    }
+
+    /// Fraction of total liquidity currently borrowed out, in `[0, 1]`.
+    pub fn utilization_rate(&self) -> Result<Decimal> {
+        let total = Decimal::from(self.liquidity.available_amount).try_add(self.liquidity.borrowed_amount)?;
+        if total.to_scaled_val() == 0 {
+            return Ok(Decimal::zero());
+        }
+        self.liquidity.borrowed_amount.try_div(total)
+    }
+
+    /// Rejects a borrow that would push utilization past
+    /// `config.critical_utilization_pct`. Checked against the reserve's
+    /// state as it stands right before a new borrow is debited from
+    /// `available_amount`, same as the existing `available_amount >=
+    /// liquidity_amount` check it sits alongside in `borrow_obligation_liquidity`.
+    pub fn check_utilization_after_borrow(&self, liquidity_amount: u64) -> Result<()> {
+        if self.config.critical_utilization_pct >= 100 {
+            return Ok(());
+        }
+
+        let available_after = Decimal::from(
+            self.liquidity.available_amount.checked_sub(liquidity_amount).ok_or(ErrorCode::BorrowTooLarge)?,
+        );
+        let borrowed_after = self.liquidity.borrowed_amount.try_add(Decimal::from(liquidity_amount))?;
+        let total = available_after.try_add(borrowed_after)?;
+        let utilization_after = if total.to_scaled_val() == 0 { Decimal::zero() } else { borrowed_after.try_div(total)? };
+
+        require!(
+            utilization_after <= Decimal::from_percent(self.config.critical_utilization_pct),
+            ErrorCode::UtilizationTooHigh
+        );
+
+        Ok(())
+    }
+
+    /// Current borrow APR, read off `config.rate_curve` at the reserve's
+    /// present utilization, multiplied by `DEPRECATED_BORROW_RATE_MULTIPLIER`
+    /// once the reserve is `ReserveStatus::Deprecated`, then clamped to
+    /// `max_effective_apr_bps` (typically
+    /// `LendingMarket::max_effective_borrow_apr_bps`) if the caller passes
+    /// one.
+    pub fn current_borrow_rate(&self, max_effective_apr_bps: Option<u32>) -> Result<Decimal> {
+        let apr = self.config.rate_curve.borrow_apr(self.utilization_rate()?)?;
+        let apr = if self.status == ReserveStatus::Deprecated {
+            apr.try_mul(Decimal::from(DEPRECATED_BORROW_RATE_MULTIPLIER as u64))?
+        } else {
+            apr
+        };
+
+        match max_effective_apr_bps {
+            Some(cap_bps) => {
+                let cap = Decimal::from(cap_bps as u64).try_div(Decimal::from(10_000u64))?;
+                Ok(apr.min(cap))
+            }
+            None => Ok(apr),
+        }
+    }
+
+    /// Rolls `liquidity.borrow_apy` and `liquidity.supply_apy` forward by
+    /// one EWMA sample of the reserve's current (uncapped) borrow rate and
+    /// utilization. Called on every `refresh_reserve`.
+    pub fn update_rate_ewma(&mut self) -> Result<()> {
+        let weight = Decimal::from(APY_EWMA_SAMPLE_WEIGHT_BPS as u64).try_div(Decimal::from(10_000u64))?;
+        let carry = Decimal::one().try_sub(weight)?;
+
+        let borrow_sample = self.current_borrow_rate(None)?;
+        let supply_sample = borrow_sample.try_mul(self.utilization_rate()?)?;
+
+        self.liquidity.borrow_apy = self.liquidity.borrow_apy.try_mul(carry)?.try_add(borrow_sample.try_mul(weight)?)?;
+        self.liquidity.supply_apy = self.liquidity.supply_apy.try_mul(carry)?.try_add(supply_sample.try_mul(weight)?)?;
+
+        Ok(())
+    }
+
+    /// Compounds `liquidity.cumulative_borrow_rate` and
+    /// `liquidity.borrowed_amount` forward by however many slots have
+    /// passed since `last_update_slot`, at the reserve's current (uncapped)
+    /// borrow rate, and advances `last_update_slot` to `slot`. No-op if
+    /// called twice in the same slot.
+    ///
+    /// This is the reserve-level counterpart to the per-borrow compounding
+    /// `refresh_obligation` already does against its own cached
+    /// `cumulative_borrow_rate` snapshot — growing `borrowed_amount` here is
+    /// what lets `collateral_exchange_rate` actually appreciate for
+    /// suppliers between obligation interactions. Needs no oracle price, so
+    /// it's safe to run on a reserve nobody has touched in a while via
+    /// `accrue_reserve_interest`.
+    pub fn accrue_interest(&mut self, slot: u64) -> Result<()> {
+        let elapsed_slots = slot.saturating_sub(self.last_update_slot);
+        if elapsed_slots == 0 {
+            return Ok(());
+        }
+
+        let borrow_rate = self.current_borrow_rate(None)?;
+        let compounded_rate = Decimal::one()
+            .try_add(borrow_rate.try_mul(Decimal::from(elapsed_slots))?.try_div(Decimal::from(crate::models::SLOTS_PER_YEAR))?)?;
+
+        self.liquidity.cumulative_borrow_rate = self.liquidity.cumulative_borrow_rate.try_mul(compounded_rate)?;
+        self.liquidity.borrowed_amount = self.liquidity.borrowed_amount.try_mul(compounded_rate)?;
+        self.last_update_slot = slot;
+
+        Ok(())
+    }
+
+    /// Rolls `cumulative_reward_per_share` forward by however many slots
+    /// have passed since `from_slot`, splitting `reward_per_slot` evenly
+    /// across all outstanding collateral. No-op if there's no collateral
+    /// yet (nobody to credit) or emissions are disabled. Stops crediting
+    /// past `emission_ends_at_slot`, if set, rather than emitting forever.
+    pub fn accrue_rewards(&mut self, from_slot: u64, to_slot: u64) -> Result<()> {
+        if self.liquidity.reward_mint.is_none() || self.collateral.mint_total_supply == 0 {
+            return Ok(());
+        }
+
+        let to_slot = self.liquidity.emission_ends_at_slot.map_or(to_slot, |ends| ends.min(to_slot));
+        let elapsed = to_slot.saturating_sub(from_slot);
+        if elapsed == 0 {
+            return Ok(());
+        }
+
+        let emitted = Decimal::from(self.liquidity.reward_per_slot).try_mul(Decimal::from(elapsed))?;
+        let per_share = emitted.try_div(Decimal::from(self.collateral.mint_total_supply))?;
+        self.liquidity.cumulative_reward_per_share = self.liquidity.cumulative_reward_per_share.try_add(per_share)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+
+/// Sensible default for reserves that want some snapshot history but
+/// don't need much of it — a fraction of the rent of `MAX_SNAPSHOT_CAPACITY`.
+pub const DEFAULT_SNAPSHOT_CAPACITY: u16 = 64;
+/// Upper bound on `capacity`, matching the old fixed-size layout this
+/// replaces.
+pub const MAX_SNAPSHOT_CAPACITY: u16 = 1000;
+
+const BYTES_PER_ENTRY: usize = 8 + 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct CapSnapshot {
+    pub slot: u64,
+    pub total_deposited: u64,
+}
+
+/// Ring buffer of a reserve's deposited-liquidity history, written by
+/// `snapshot_reserve` and read back by anything needing a point-in-time
+/// or TWAP view (e.g. emission fairness checks) without iterating every
+/// deposit/withdraw that ever touched the reserve.
+///
+/// `capacity` is fixed at `init_reserve_cap_snapshots` time. Previously
+/// this was a single fixed 1000-entry (~24KB) layout every reserve paid
+/// rent for regardless of whether it needed history at all; reserves that
+/// don't care about this can size down to a handful of entries, or skip
+/// creating the account entirely.
+#[account]
+#[derive(Default)]
+pub struct ReserveCapSnapshots {
+    pub reserve: Pubkey,
+    pub capacity: u16,
+    /// Index `entries[cursor]` will be overwritten by the next `record`.
+    pub cursor: u16,
+    /// How many of `entries`, counted from the start, currently hold real
+    /// data. Stops growing once it reaches `capacity`; after that every
+    /// `record` overwrites the oldest entry instead.
+    pub len: u16,
+    /// Set once by `close_reserve` when its backing reserve is torn down
+    /// (synth-838). `len == 0` on its own can't tell a dead reserve's
+    /// snapshot account apart from a live one that just hasn't recorded its
+    /// first entry yet, so `sweep_pda_lamports` gates on this flag instead.
+    pub closed: bool,
+    pub entries: Vec<CapSnapshot>,
+}
+
+impl ReserveCapSnapshots {
+    pub fn space_for(capacity: u16) -> usize {
+        8 + 32 + 2 + 2 + 2 + 1 + 4 + capacity as usize * BYTES_PER_ENTRY
+    }
+
+    pub fn init(&mut self, reserve: Pubkey, capacity: u16) {
+        self.reserve = reserve;
+        self.capacity = capacity;
+        self.cursor = 0;
+        self.len = 0;
+        self.closed = false;
+        self.entries = vec![CapSnapshot::default(); capacity as usize];
+    }
+
+    /// Marks this account as belonging to a reserve that's been closed, so
+    /// `sweep_pda_lamports` can reclaim its rent unambiguously.
+    pub fn mark_closed(&mut self) {
+        self.closed = true;
+    }
+
+    /// Overwrites the ring buffer's current write position with a new
+    /// snapshot and advances the cursor, wrapping once `capacity` is
+    /// reached.
+    pub fn record(&mut self, slot: u64, total_deposited: u64) -> Result<()> {
+        require!(self.capacity > 0, ErrorCode::SnapshotCapacityZero);
+
+        self.entries[self.cursor as usize] = CapSnapshot { slot, total_deposited };
+        self.cursor = (self.cursor + 1) % self.capacity;
+        self.len = self.len.saturating_add(1).min(self.capacity);
+
+        Ok(())
+    }
+
+    /// The populated entries in chronological order (oldest first) — the
+    /// ring buffer unwrapped, since a binary search over slot needs them
+    /// contiguous and sorted.
+    pub fn entries(&self) -> Vec<CapSnapshot> {
+        if self.len < self.capacity {
+            self.entries[..self.len as usize].to_vec()
+        } else {
+            let mut ordered = self.entries[self.cursor as usize..].to_vec();
+            ordered.extend_from_slice(&self.entries[..self.cursor as usize]);
+            ordered
+        }
+    }
+
+    /// Binary-searches `entries()` for the snapshot closest to, but not
+    /// after, `slot`. `None` if every snapshot postdates it (or there are
+    /// none yet).
+    pub fn closest_at_or_before(&self, slot: u64) -> Option<CapSnapshot> {
+        let entries = self.entries();
+        match entries.binary_search_by_key(&slot, |e| e.slot) {
+            Ok(i) => Some(entries[i]),
+            Err(0) => None,
+            Err(i) => Some(entries[i - 1]),
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::models::ReserveConfig;
+
+/// A governance-approved reserve config preset, so listing a new reserve
+/// can reference a vetted template instead of passing a full
+/// `ReserveConfig` in the instruction data, which both shrinks the
+/// instruction and rules out ad hoc parameters slipping into a listing.
+#[account]
+pub struct ReserveTemplate {
+    pub lending_market: Pubkey,
+    /// Human-readable identifier (e.g. `b"stable-major\0\0\0..."`), also
+    /// used as a PDA seed so a market can't register two templates under
+    /// the same name.
+    pub label: [u8; 32],
+    pub config: ReserveConfig,
+}
+
+impl ReserveTemplate {
+    pub const LEN: usize = 8 + 32 + 32 + 200;
+}
@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+
+/// A tokenized auto-compounding vault: deposits of `underlying_mint` are
+/// staked into a yield-generating position (e.g. a leveraged farm) and
+/// harvested returns are folded back into `total_underlying` without
+/// minting new shares, so `share_mint`'s exchange rate against the
+/// underlying grows over time. Depositors hold `share_mint` tokens
+/// instead of a position PDA, making vault positions transferable and
+/// usable as collateral elsewhere.
+#[account]
+#[derive(Default)]
+pub struct Vault {
+    pub lending_market: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub share_mint: Pubkey,
+    pub underlying_vault: Pubkey,
+    pub total_underlying: u64,
+    pub total_shares: u64,
+    /// Performance fee charged on compounding, in basis points of gains
+    /// above `high_water_mark`.
+    pub performance_fee_bps: u16,
+    /// Underlying-per-share price as of the last time a performance fee
+    /// was charged. Fees are only ever taken on price growth past this
+    /// mark, so a vault that gives back gains before a depositor pulls
+    /// out isn't charged fees on a round trip.
+    pub high_water_mark: Decimal,
+    /// Underlying owed to the lending market owner, claimable via
+    /// `claim_vault_fees`.
+    pub accrued_fees: u64,
+    pub last_compound_slot: u64,
+    /// Minimum slots between permissionless compounds, so a cranker can't
+    /// repeatedly harvest dust and collect the bounty on each call.
+    pub min_compound_interval_slots: u64,
+    /// Share of each permissionless compound's harvested amount paid to
+    /// whoever cranks it, in basis points.
+    pub cranker_bounty_bps: u16,
+    pub bump_seed: u8,
+}
+
+impl Vault {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 2 + 16 + 8 + 8 + 8 + 2 + 1;
+
+    /// How many shares `deposit_amount` of underlying is worth at the
+    /// current exchange rate. 1:1 for the vault's first deposit.
+    pub fn shares_for_deposit(&self, deposit_amount: u64) -> Result<u64> {
+        if self.total_shares == 0 || self.total_underlying == 0 {
+            return Ok(deposit_amount);
+        }
+        Decimal::from(deposit_amount)
+            .try_mul(Decimal::from(self.total_shares))?
+            .try_div(Decimal::from(self.total_underlying))?
+            .try_floor_u64()
+    }
+
+    /// How much underlying `shares` redeems for at the current exchange
+    /// rate.
+    pub fn underlying_for_shares(&self, shares: u64) -> Result<u64> {
+        if self.total_shares == 0 {
+            return Ok(0);
+        }
+        Decimal::from(shares)
+            .try_mul(Decimal::from(self.total_underlying))?
+            .try_div(Decimal::from(self.total_shares))?
+            .try_floor_u64()
+    }
+
+    pub fn deposit(&mut self, deposit_amount: u64) -> Result<u64> {
+        let shares = self.shares_for_deposit(deposit_amount)?;
+        self.total_underlying = self
+            .total_underlying
+            .checked_add(deposit_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.total_shares = self.total_shares.checked_add(shares).ok_or(ErrorCode::MathOverflow)?;
+        Ok(shares)
+    }
+
+    /// Underlying-per-share at the current state, `Decimal::zero()` before
+    /// the vault has ever had a deposit.
+    pub fn price_per_share(&self) -> Result<Decimal> {
+        if self.total_shares == 0 {
+            return Ok(Decimal::zero());
+        }
+        Decimal::from(self.total_underlying).try_div(Decimal::from(self.total_shares))
+    }
+
+    /// Charges the performance fee on any price growth since
+    /// `high_water_mark`, moving the fee out of `total_underlying` (so it
+    /// stops compounding for depositors) and into `accrued_fees`, then
+    /// raises the high water mark to the post-fee price. A no-op if the
+    /// price hasn't grown past the mark. Returns the fee charged, in
+    /// underlying units.
+    pub fn charge_performance_fee(&mut self) -> Result<u64> {
+        let price = self.price_per_share()?;
+        if price <= self.high_water_mark || self.performance_fee_bps == 0 {
+            if price > self.high_water_mark {
+                self.high_water_mark = price;
+            }
+            return Ok(0);
+        }
+
+        let gain_per_share = price.try_sub(self.high_water_mark)?;
+        let total_gain = gain_per_share.try_mul(Decimal::from(self.total_shares))?;
+        let fee = total_gain
+            .try_mul(Decimal::from_fraction(self.performance_fee_bps as u128, 10_000)?)?
+            .try_floor_u64()?;
+
+        self.total_underlying = self.total_underlying.checked_sub(fee).ok_or(ErrorCode::MathOverflow)?;
+        self.accrued_fees = self.accrued_fees.checked_add(fee).ok_or(ErrorCode::MathOverflow)?;
+        self.high_water_mark = self.price_per_share()?;
+
+        Ok(fee)
+    }
+
+    /// Whether enough slots have passed since the last compound for a
+    /// permissionless crank to be allowed.
+    pub fn can_crank_compound(&self, current_slot: u64) -> bool {
+        current_slot >= self.last_compound_slot.saturating_add(self.min_compound_interval_slots)
+    }
+
+    /// Folds `harvested_amount` into the vault, charges the performance
+    /// fee on any resulting gain, and records `current_slot` as the last
+    /// compound. Shared by the owner-gated and permissionless-crank
+    /// compound endpoints so they can't drift apart.
+    pub fn compound(&mut self, harvested_amount: u64, current_slot: u64) -> Result<()> {
+        self.total_underlying = self
+            .total_underlying
+            .checked_add(harvested_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.charge_performance_fee()?;
+        self.last_compound_slot = current_slot;
+        Ok(())
+    }
+
+    pub fn withdraw(&mut self, shares: u64) -> Result<u64> {
+        let underlying_amount = self.underlying_for_shares(shares)?;
+        self.total_underlying = self
+            .total_underlying
+            .checked_sub(underlying_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        self.total_shares = self.total_shares.checked_sub(shares).ok_or(ErrorCode::MathOverflow)?;
+        Ok(underlying_amount)
+    }
+}
@@ -0,0 +1,113 @@
+//! Thin wrapper around the Pyth price feed account format so the rest of
+//! the program deals exclusively in [`Decimal`] UAC prices.
+
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+
+/// Reads the current price out of a Pyth price account and converts it to
+/// a non-negative [`Decimal`]. Pyth exponents are negative for fractional
+/// prices, e.g. `price = 5123, expo = -2` means `$51.23`.
+///
+/// Reads straight off the account's bytes with `pyth_sdk_solana::state`
+/// rather than going through its `AccountInfo`-accepting helpers: this
+/// program's `anchor-lang`/`solana-program` version predates the one
+/// `pyth-sdk-solana` now builds its own `AccountInfo`/`Pubkey` against, so
+/// those types don't unify even though they're structurally identical.
+pub fn read_market_price(price_account: &AccountInfo) -> Result<Decimal> {
+    let data = price_account.try_borrow_data()?;
+    let price_account = pyth_sdk_solana::state::load_price_account::<32, ()>(&data)
+        .map_err(|_| error!(ErrorCode::StalePrice))?;
+
+    // Mirrors `GenericPriceAccount::to_price_feed` followed by
+    // `PriceFeed::get_price_unchecked`: the aggregate price while trading,
+    // falling back to the last trading price otherwise, with no staleness
+    // check of its own (callers enforce staleness via `last_update_slot`).
+    let price = match price_account.agg.status {
+        pyth_sdk_solana::state::PriceStatus::Trading => price_account.agg.price,
+        _ => price_account.prev_price,
+    };
+    require!(price >= 0, ErrorCode::StalePrice);
+
+    let magnitude = price as u128;
+    let decimal = if price_account.expo >= 0 {
+        Decimal::from(magnitude).try_mul(Decimal::from(10u64.pow(price_account.expo as u32)))?
+    } else {
+        Decimal::from(magnitude).try_div(Decimal::from(10u64.pow((-price_account.expo) as u32)))?
+    };
+
+    Ok(decimal)
+}
+
+/// The handful of `spl_stake_pool::state::StakePool` fields we need to
+/// derive a SOL-per-pool-token exchange rate, read directly off the
+/// account's bytes so this program doesn't need the full stake pool crate
+/// as a dependency just for two `u64`s.
+struct StakePoolExchangeRate {
+    total_lamports: u64,
+    pool_token_supply: u64,
+}
+
+/// Byte offset of `StakePool::total_lamports`: 1 (account type) + 32*8
+/// (manager, staker, stake_deposit_authority, validator_list,
+/// reserve_stake, pool_mint, manager_fee_account, token_program_id) + 1
+/// (stake_withdraw_bump_seed) bytes precede it, with `pool_token_supply`
+/// immediately after.
+const STAKE_POOL_TOTAL_LAMPORTS_OFFSET: usize = 258;
+
+impl StakePoolExchangeRate {
+    fn read(stake_pool: &AccountInfo) -> Result<Self> {
+        let data = stake_pool.try_borrow_data()?;
+        require!(data.len() >= STAKE_POOL_TOTAL_LAMPORTS_OFFSET + 16, ErrorCode::StalePrice);
+
+        let total_lamports = u64::from_le_bytes(
+            data[STAKE_POOL_TOTAL_LAMPORTS_OFFSET..STAKE_POOL_TOTAL_LAMPORTS_OFFSET + 8]
+                .try_into()
+                .unwrap(),
+        );
+        let pool_token_supply = u64::from_le_bytes(
+            data[STAKE_POOL_TOTAL_LAMPORTS_OFFSET + 8..STAKE_POOL_TOTAL_LAMPORTS_OFFSET + 16]
+                .try_into()
+                .unwrap(),
+        );
+
+        Ok(Self { total_lamports, pool_token_supply })
+    }
+
+    fn sol_per_token(&self) -> Result<Decimal> {
+        if self.pool_token_supply == 0 {
+            return Ok(Decimal::one());
+        }
+        Decimal::from(self.total_lamports).try_div(Decimal::from(self.pool_token_supply))
+    }
+}
+
+/// Prices an LST reserve as `stake_pool_exchange_rate * sol_price`, then
+/// sanity-checks it against the LST's own spot oracle feed, rejecting the
+/// read if the two disagree by more than `max_deviation_bps`. Pricing off
+/// the stake pool's real backing instead of the spot feed alone closes off
+/// the usual LST oracle manipulation vector (thin spot-market liquidity),
+/// while the deviation check catches a stale or spoofed stake pool account
+/// rather than trusting it blindly.
+pub fn read_lst_fair_value(
+    stake_pool: &AccountInfo,
+    sol_price_account: &AccountInfo,
+    spot_price_account: &AccountInfo,
+    max_deviation_bps: u16,
+) -> Result<Decimal> {
+    let exchange_rate = StakePoolExchangeRate::read(stake_pool)?.sol_per_token()?;
+    let sol_price = read_market_price(sol_price_account)?;
+    let fair_value = exchange_rate.try_mul(sol_price)?;
+
+    let spot_price = read_market_price(spot_price_account)?;
+    let deviation = if fair_value >= spot_price {
+        fair_value.try_sub(spot_price)?
+    } else {
+        spot_price.try_sub(fair_value)?
+    };
+    let max_deviation = spot_price.try_mul(Decimal::from(max_deviation_bps as u64))?.try_div(Decimal::from(10_000u64))?;
+    require!(deviation <= max_deviation, ErrorCode::StalePrice);
+
+    Ok(fair_value)
+}
@@ -0,0 +1,29 @@
+//! Canonical PDA derivations for this program's seeded accounts.
+//!
+//! Every instruction that takes a `lending_market` account already derives
+//! it from these same seeds via an `#[account(seeds = ..., bump = ...)]`
+//! constraint, which is enough for Anchor clients to auto-resolve it from
+//! just the market owner's pubkey. These functions exist so off-chain
+//! integrators (and our own CLI) have a single, tested place to reproduce
+//! that derivation instead of hand-rolling the seed bytes themselves.
+
+use anchor_lang::prelude::*;
+
+/// Derives the `LendingMarket` PDA owned by `owner`.
+pub fn lending_market_address(owner: &Pubkey, program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"lending-market", owner.as_ref()], program_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_same_address_for_same_owner() {
+        let owner = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let a = lending_market_address(&owner, &program_id);
+        let b = lending_market_address(&owner, &program_id);
+        assert_eq!(a, b);
+    }
+}
@@ -0,0 +1,19 @@
+//! Compute-unit instrumentation for the instructions most likely to
+//! regress: `refresh_obligation`, `liquidate_obligation` and opening a
+//! leveraged position all touch several accounts and run nontrivial math
+//! in one transaction. Gated behind the `cu-telemetry` feature so the
+//! `sol_log_compute_units` syscalls it adds don't cost anything (or show up
+//! in logs) in a normal build.
+
+/// Logs remaining compute units under the given label when the
+/// `cu-telemetry` feature is enabled; a no-op otherwise. Call once at the
+/// start and once at the end of a sub-step to see its cost in the
+/// transaction logs.
+#[cfg(feature = "cu-telemetry")]
+pub fn checkpoint(label: &str) {
+    anchor_lang::solana_program::log::sol_log(label);
+    anchor_lang::solana_program::log::sol_log_compute_units();
+}
+
+#[cfg(not(feature = "cu-telemetry"))]
+pub fn checkpoint(_label: &str) {}
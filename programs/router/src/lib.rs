@@ -0,0 +1,116 @@
+//! Thin composition layer over `borrow-lending`'s and `stable-coin`'s
+//! instructions for flows that are almost always done together (e.g.
+//! deposit collateral then immediately borrow against it). Each router
+//! instruction is just a sequence of CPI calls into the underlying
+//! program's own Anchor-generated `cpi` module — it holds no state of its
+//! own and trusts the inner program's checks entirely.
+
+use anchor_lang::prelude::*;
+use borrow_lending::cpi::accounts::{BorrowObligationLiquidity, DepositObligationCollateral};
+use borrow_lending::cpi::{borrow_obligation_liquidity, deposit_obligation_collateral};
+use borrow_lending::program::BorrowLending;
+use stable_coin::cpi::accounts::{BorrowStableCoin, DepositCollateral};
+use stable_coin::cpi::{borrow_stable_coin, deposit_collateral};
+use stable_coin::program::StableCoin;
+
+declare_id!("RouterB1endFLows11111111111111111111111111");
+
+#[program]
+pub mod router {
+    use super::*;
+
+    /// Deposits collateral and immediately borrows against it in one
+    /// transaction, so integrators don't have to hand-assemble both
+    /// instructions (and their distinct account lists) themselves.
+    pub fn deposit_and_borrow(ctx: Context<DepositAndBorrow>, collateral_amount: u64, liquidity_amount: u64) -> Result<()> {
+        deposit_obligation_collateral(
+            CpiContext::new(
+                ctx.accounts.borrow_lending_program.to_account_info(),
+                DepositObligationCollateral {
+                    obligation: ctx.accounts.deposit.obligation.clone(),
+                    owner: ctx.accounts.deposit.owner.clone(),
+                    deposit_reserve: ctx.accounts.deposit.deposit_reserve.clone(),
+                    source_collateral: ctx.accounts.deposit.source_collateral.clone(),
+                    destination_collateral: ctx.accounts.deposit.destination_collateral.clone(),
+                    token_program: ctx.accounts.deposit.token_program.clone(),
+                },
+            ),
+            collateral_amount,
+        )?;
+
+        borrow_obligation_liquidity(
+            CpiContext::new(
+                ctx.accounts.borrow_lending_program.to_account_info(),
+                BorrowObligationLiquidity {
+                    lending_market: ctx.accounts.borrow.lending_market.clone(),
+                    obligation: ctx.accounts.borrow.obligation.clone(),
+                    owner: ctx.accounts.borrow.owner.clone(),
+                    credit_delegation: None,
+                    borrow_reserve: ctx.accounts.borrow.borrow_reserve.clone(),
+                    reserve_liquidity_supply: ctx.accounts.borrow.reserve_liquidity_supply.clone(),
+                    destination_liquidity: ctx.accounts.borrow.destination_liquidity.clone(),
+                    token_program: ctx.accounts.borrow.token_program.clone(),
+                },
+            ),
+            liquidity_amount,
+        )
+    }
+
+    /// Deposits collateral into a USP receipt and immediately borrows
+    /// against it in one transaction (synth-863) — the `stable-coin`
+    /// analog of `deposit_and_borrow` above, composed the same way via
+    /// `stable_coin`'s Anchor-generated `cpi` module rather than a
+    /// hand-rolled instruction-builder crate, since that's the same
+    /// typed-CPI interface `deposit_and_borrow` already uses for
+    /// `borrow-lending`.
+    pub fn deposit_collateral_and_borrow_stable_coin(
+        ctx: Context<DepositCollateralAndBorrowStableCoin>,
+        collateral_amount: u64,
+        borrow_amount: u64,
+    ) -> Result<()> {
+        deposit_collateral(
+            CpiContext::new(
+                ctx.accounts.stable_coin_program.to_account_info(),
+                DepositCollateral {
+                    component: ctx.accounts.deposit.component.clone(),
+                    receipt: ctx.accounts.deposit.receipt.clone(),
+                    borrower: ctx.accounts.deposit.borrower.clone(),
+                    source_collateral: ctx.accounts.deposit.source_collateral.clone(),
+                    collateral_vault: ctx.accounts.deposit.collateral_vault.clone(),
+                    token_program: ctx.accounts.deposit.token_program.clone(),
+                },
+            ),
+            collateral_amount,
+        )?;
+
+        borrow_stable_coin(
+            CpiContext::new(
+                ctx.accounts.stable_coin_program.to_account_info(),
+                BorrowStableCoin {
+                    component: ctx.accounts.borrow.component.clone(),
+                    receipt: ctx.accounts.borrow.receipt.clone(),
+                    borrower: ctx.accounts.borrow.borrower.clone(),
+                    usp_mint: ctx.accounts.borrow.usp_mint.clone(),
+                    destination_usp: ctx.accounts.borrow.destination_usp.clone(),
+                    oracle: ctx.accounts.borrow.oracle.clone(),
+                    token_program: ctx.accounts.borrow.token_program.clone(),
+                },
+            ),
+            borrow_amount,
+        )
+    }
+}
+
+#[derive(Accounts)]
+pub struct DepositAndBorrow<'info> {
+    pub deposit: DepositObligationCollateral<'info>,
+    pub borrow: BorrowObligationLiquidity<'info>,
+    pub borrow_lending_program: Program<'info, BorrowLending>,
+}
+
+#[derive(Accounts)]
+pub struct DepositCollateralAndBorrowStableCoin<'info> {
+    pub deposit: DepositCollateral<'info>,
+    pub borrow: BorrowStableCoin<'info>,
+    pub stable_coin_program: Program<'info, StableCoin>,
+}
@@ -0,0 +1,36 @@
+//! Pricing for BLp collateral: `borrow-lending` reserve cTokens accepted
+//! directly as vault collateral (synth-853), so a receipt backed by one
+//! keeps earning that reserve's lending yield on top of its USP debt.
+
+use anchor_lang::prelude::*;
+use borrow_lending::models::Reserve;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+
+/// Prices one unit of `reserve`'s collateral mint in the reserve's own
+/// oracle-priced unit of account: `market_price` (last refreshed by
+/// `borrow-lending`'s own `refresh_reserve`) divided by
+/// `collateral_exchange_rate`, i.e. the liquidity one cToken is currently
+/// redeemable for. As the reserve earns interest, `collateral_exchange_rate`
+/// moves in the cToken's favor and this price rises accordingly — the
+/// appreciation the underlying depositor would otherwise be paid directly.
+pub fn read_blp_collateral_price(reserve_info: &AccountInfo) -> Result<Decimal> {
+    let reserve: Account<Reserve> =
+        Account::try_from(reserve_info).map_err(|_| error!(ErrorCode::InvalidOracle))?;
+
+    let exchange_rate = reserve.collateral_exchange_rate().map_err(|_| error!(ErrorCode::InvalidOracle))?;
+    let liquidity_per_collateral = borrow_lending::math::Decimal::one()
+        .try_div(exchange_rate)
+        .map_err(|_| error!(ErrorCode::InvalidOracle))?;
+    let price = reserve
+        .liquidity
+        .market_price
+        .try_mul(liquidity_per_collateral)
+        .map_err(|_| error!(ErrorCode::InvalidOracle))?;
+
+    // Both programs' `Decimal` are WAD-scaled `u128`s; reinterpreting the
+    // raw scaled value across the crate boundary avoids a dependency on
+    // each other's exact `Decimal` type.
+    Ok(Decimal::from_scaled_val(price.to_scaled_val()))
+}
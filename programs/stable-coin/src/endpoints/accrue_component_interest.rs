@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::models::ComponentConfig;
+
+#[event]
+pub struct ComponentInterestAccrued {
+    pub component: Pubkey,
+    pub total_debt: u128,
+    pub cumulative_borrow_rate: u128,
+}
+
+#[derive(Accounts)]
+pub struct AccrueComponentInterest<'info> {
+    #[account(mut)]
+    pub component: Account<'info, ComponentConfig>,
+}
+
+/// Permissionless crank: compounds `component.cumulative_borrow_rate` and
+/// `total_debt` forward to the current slot or timestamp (per
+/// `component.accrual_mode`), the same role `accrue_reserve_interest`
+/// plays for `borrow-lending`'s reserves. Meant for a keeper to call on a
+/// schedule against components that see long stretches without a borrow,
+/// repay or liquidation.
+pub fn handle(ctx: Context<AccrueComponentInterest>) -> Result<()> {
+    let component = &mut ctx.accounts.component;
+    let clock = Clock::get()?;
+    component.accrue(&clock)?;
+
+    emit!(ComponentInterestAccrued {
+        component: ctx.accounts.component.key(),
+        total_debt: ctx.accounts.component.total_debt.to_scaled_val(),
+        cumulative_borrow_rate: ctx.accounts.component.cumulative_borrow_rate.to_scaled_val(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{ComponentConfig, SurplusBuffer};
+
+#[derive(Accounts)]
+pub struct AccrueComponentInterestToSurplus<'info> {
+    #[account(mut)]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component)]
+    pub surplus_buffer: Account<'info, SurplusBuffer>,
+}
+
+/// Same permissionless crank as `accrue_component_interest`, but also
+/// credits `surplus_buffer.total_accumulated` with exactly how much
+/// `component.total_debt` grew from this accrual (synth-856) — the
+/// stability fee revenue that growth represents, not yet realized as
+/// minted USP until `sweep_surplus`/`route_surplus_to_savings` does so.
+pub fn handle(ctx: Context<AccrueComponentInterestToSurplus>) -> Result<()> {
+    let component = &mut ctx.accounts.component;
+    let debt_before = component.total_debt;
+
+    let clock = Clock::get()?;
+    component.accrue(&clock)?;
+
+    let interest_accrued = component.total_debt.try_sub(debt_before)?;
+    let buffer = &mut ctx.accounts.surplus_buffer;
+    buffer.total_accumulated = buffer.total_accumulated.try_add(interest_accrued)?;
+
+    Ok(())
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{ComponentConfig, Receipt, SurplusBuffer};
+use crate::oracle;
+
+#[event]
+pub struct StableCoinBorrowed {
+    pub component: Pubkey,
+    pub receipt: Pubkey,
+    pub amount: u64,
+    pub price: u128,
+}
+
+#[derive(Accounts)]
+pub struct BorrowStableCoin<'info> {
+    #[account(
+        mut,
+        seeds = [b"component", component.collateral_mint.as_ref(), component.usp_mint.as_ref()],
+        bump = component.bump_seed,
+    )]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component, has_one = borrower)]
+    pub receipt: Account<'info, Receipt>,
+    pub borrower: Signer<'info>,
+
+    #[account(mut, address = component.usp_mint)]
+    pub usp_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub destination_usp: Account<'info, TokenAccount>,
+
+    /// CHECK: validated against `component.oracle`/`component.blp_reserve`
+    /// and parsed accordingly by `oracle::read_component_price`.
+    pub oracle: AccountInfo<'info>,
+
+    /// Credited with this call's share of stability fee revenue (synth-856),
+    /// if `component` has one — omit for components that haven't
+    /// `init_surplus_buffer`'d yet.
+    #[account(mut)]
+    pub surplus_buffer: Option<Account<'info, SurplusBuffer>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Mints `amount` of USP against `receipt`'s deposited collateral, priced
+/// on-chain off the component's own Pyth oracle (synth-850) rather than a
+/// client-supplied price — rejecting the borrow if it would breach the
+/// component's `mint_allowance` debt ceiling, the receipt's
+/// `min_collateral_ratio_pct`, or (synth-864) either of
+/// `max_borrow_per_receipt`/`borrow_cooldown_slots`, which blunt
+/// mint-and-dump attacks on newly listed collateral by capping how much a
+/// single receipt can borrow and how often.
+pub fn handle(ctx: Context<BorrowStableCoin>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let component = &mut ctx.accounts.component;
+    component.check_not_frozen()?;
+    let component_key = component.key();
+    let debt_before_accrual = component.total_debt;
+    component.accrue(&clock)?;
+    let interest_accrued = component.total_debt.try_sub(debt_before_accrual)?;
+    SurplusBuffer::credit_if_present(ctx.accounts.surplus_buffer.as_mut(), component_key, interest_accrued)?;
+
+    if component.borrow_cooldown_slots > 0 {
+        require!(
+            clock.slot.saturating_sub(ctx.accounts.receipt.last_borrow_slot) >= component.borrow_cooldown_slots,
+            ErrorCode::BorrowCooldownActive
+        );
+    }
+
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.settle(component.cumulative_borrow_rate)?;
+    receipt.borrowed_amount = receipt.borrowed_amount.try_add(Decimal::from(amount))?;
+    receipt.last_borrow_slot = clock.slot;
+    component.total_debt = component.total_debt.try_add(Decimal::from(amount))?;
+
+    require!(
+        component.total_debt <= Decimal::from(component.mint_allowance),
+        ErrorCode::MintAllowanceExceeded
+    );
+
+    if let Some(max_borrow_per_receipt) = component.max_borrow_per_receipt {
+        require!(
+            receipt.borrowed_amount <= Decimal::from(max_borrow_per_receipt),
+            ErrorCode::BorrowCapExceeded
+        );
+    }
+
+    let price = oracle::read_component_price(component, &ctx.accounts.oracle)?;
+    let ratio_pct = receipt
+        .collateral_ratio_pct(price)?
+        .ok_or(ErrorCode::CollateralRatioTooLow)?;
+    require!(
+        ratio_pct >= Decimal::from(component.min_collateral_ratio_pct as u64),
+        ErrorCode::CollateralRatioTooLow
+    );
+
+    let collateral_mint = component.collateral_mint;
+    let usp_mint = component.usp_mint;
+    let bump_seed = component.bump_seed;
+    let seeds: &[&[u8]] = &[b"component", collateral_mint.as_ref(), usp_mint.as_ref(), &[bump_seed]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.usp_mint.to_account_info(),
+                to: ctx.accounts.destination_usp.to_account_info(),
+                authority: component.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    emit!(StableCoinBorrowed {
+        component: ctx.accounts.component.key(),
+        receipt: ctx.accounts.receipt.key(),
+        amount,
+        price: price.to_scaled_val(),
+    });
+
+    Ok(())
+}
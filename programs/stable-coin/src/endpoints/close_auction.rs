@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::LiquidationAuction;
+
+#[derive(Accounts)]
+pub struct CloseAuction<'info> {
+    #[account(mut, has_one = borrower, close = borrower)]
+    pub auction: Account<'info, LiquidationAuction>,
+
+    #[account(mut)]
+    pub borrower: SystemAccount<'info>,
+}
+
+/// Reclaims an auction's rent once it's fully wound down: no collateral
+/// and no debt left to sell off or collect.
+pub fn handle(ctx: Context<CloseAuction>) -> Result<()> {
+    let auction = &ctx.accounts.auction;
+
+    require!(auction.collateral_remaining == 0, ErrorCode::AuctionNotFinished);
+    require!(auction.debt_remaining.to_scaled_val() == 0, ErrorCode::AuctionNotFinished);
+
+    Ok(())
+}
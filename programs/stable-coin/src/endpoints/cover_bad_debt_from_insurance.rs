@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::models::{ComponentConfig, Receipt};
+
+#[derive(Accounts)]
+pub struct CoverBadDebtFromInsurance<'info> {
+    #[account(has_one = owner)]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component)]
+    pub receipt: Account<'info, Receipt>,
+
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub insurance_pool: Account<'info, TokenAccount>,
+
+    #[account(mut, address = component.collateral_vault)]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Deploys `amount` of `component.insurance_pool`'s collateral to backstop
+/// `receipt`, which `start_auction`/`take_auction` left with outstanding
+/// debt but no collateral left to liquidate against (synth-861). Only
+/// `owner` (and only an `insurance_pool` they actually control) can trigger
+/// this — unlike a liquidation, there's no keeper incentive pulling a
+/// receipt back from bad debt on its own.
+pub fn handle(ctx: Context<CoverBadDebtFromInsurance>, amount: u64) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.insurance_pool.key(),
+        ctx.accounts.component.insurance_pool.ok_or(ErrorCode::LiquidationPenaltyMisconfigured)?,
+        ErrorCode::LiquidationPenaltyMisconfigured
+    );
+
+    let receipt = &mut ctx.accounts.receipt;
+    require!(receipt.collateral_amount == 0, ErrorCode::ReceiptNotDepleted);
+    require!(receipt.borrowed_amount.to_scaled_val() > 0, ErrorCode::ReceiptNotDepleted);
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.insurance_pool.to_account_info(),
+                to: ctx.accounts.collateral_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    receipt.collateral_amount = amount;
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::models::{ComponentConfig, Receipt};
+
+#[event]
+pub struct CollateralDeposited {
+    pub component: Pubkey,
+    pub receipt: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct DepositCollateral<'info> {
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component, has_one = borrower)]
+    pub receipt: Account<'info, Receipt>,
+    pub borrower: Signer<'info>,
+
+    #[account(mut)]
+    pub source_collateral: Account<'info, TokenAccount>,
+    #[account(mut, address = component.collateral_vault)]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Adds `amount` of the component's collateral mint to `receipt`. Doesn't
+/// touch debt or require accrual first — depositing more collateral only
+/// ever improves a receipt's collateral ratio.
+pub fn handle(ctx: Context<DepositCollateral>, amount: u64) -> Result<()> {
+    ctx.accounts.component.check_not_frozen()?;
+
+    ctx.accounts.receipt.collateral_amount =
+        ctx.accounts.receipt.collateral_amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source_collateral.to_account_info(),
+                to: ctx.accounts.collateral_vault.to_account_info(),
+                authority: ctx.accounts.borrower.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit!(CollateralDeposited {
+        component: ctx.accounts.component.key(),
+        receipt: ctx.accounts.receipt.key(),
+        amount,
+    });
+
+    Ok(())
+}
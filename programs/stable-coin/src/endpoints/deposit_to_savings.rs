@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::models::{SavingsDeposit, StablePool, CURRENT_ACCOUNT_VERSION};
+
+#[derive(Accounts)]
+pub struct DepositToSavings<'info> {
+    #[account(mut, seeds = [b"stable-pool", stable_pool.usp_mint.as_ref()], bump = stable_pool.bump_seed)]
+    pub stable_pool: Account<'info, StablePool>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = SavingsDeposit::LEN,
+        seeds = [b"savings-deposit", stable_pool.key().as_ref(), owner.key().as_ref()],
+        bump,
+    )]
+    pub savings_deposit: Account<'info, SavingsDeposit>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut)]
+    pub source_usp: Account<'info, TokenAccount>,
+    #[account(mut, address = stable_pool.savings_vault)]
+    pub savings_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposits `amount` of USP into the caller's savings position, settling
+/// any interest already earned first so it isn't clobbered by the new
+/// principal.
+pub fn handle(ctx: Context<DepositToSavings>, amount: u64) -> Result<()> {
+    require!(!ctx.accounts.stable_pool.shutdown, ErrorCode::AlreadyShutdown);
+
+    let slot = Clock::get()?.slot;
+    let pool = &mut ctx.accounts.stable_pool;
+    pool.accrue(slot)?;
+
+    let deposit = &mut ctx.accounts.savings_deposit;
+    deposit.stable_pool = pool.key();
+    deposit.owner = ctx.accounts.owner.key();
+    deposit.bump_seed = ctx.bumps.savings_deposit;
+    deposit.version = CURRENT_ACCOUNT_VERSION;
+    deposit.settle(pool.cumulative_savings_index)?;
+
+    deposit.principal_amount = deposit.principal_amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    pool.total_principal_deposited =
+        pool.total_principal_deposited.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source_usp.to_account_info(),
+                to: ctx.accounts.savings_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::ComponentConfig;
+
+#[derive(Accounts)]
+pub struct ExecuteComponentConfigUpdate<'info> {
+    #[account(mut)]
+    pub component: Account<'info, ComponentConfig>,
+}
+
+/// Permissionless crank: applies whatever `queue_component_config_update`
+/// last queued, once `config_change_queued_at_slot + config_timelock_slots`
+/// has passed (synth-859). Anyone can call this — there's nothing to gate,
+/// since the values to apply were already committed to when queued.
+pub fn handle(ctx: Context<ExecuteComponentConfigUpdate>) -> Result<()> {
+    let component = &mut ctx.accounts.component;
+    let queued_at_slot = component.config_change_queued_at_slot.ok_or(ErrorCode::NoConfigChangeQueued)?;
+
+    let current_slot = Clock::get()?.slot;
+    require!(
+        current_slot >= queued_at_slot.saturating_add(component.config_timelock_slots),
+        ErrorCode::ConfigChangeTimelocked
+    );
+
+    if let Some(min_collateral_ratio_pct) = component.pending_min_collateral_ratio_pct {
+        component.min_collateral_ratio_pct = min_collateral_ratio_pct;
+    }
+    if let Some(interest_rate_bps) = component.pending_interest_rate_bps {
+        component.interest_rate_bps = interest_rate_bps;
+    }
+
+    component.pending_min_collateral_ratio_pct = None;
+    component.pending_interest_rate_bps = None;
+    component.config_change_queued_at_slot = None;
+
+    Ok(())
+}
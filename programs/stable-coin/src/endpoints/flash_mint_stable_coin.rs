@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount};
+
+use crate::err::ErrorCode;
+use crate::models::StablePool;
+
+/// Flash mint fee, in basis points of the minted amount. Unlike
+/// `borrow-lending`'s flash loan fee, this isn't compensating depositors
+/// for counterparty risk — there's no pool of capital at stake, `amount`
+/// is minted out of thin air and burned back by the end of the same
+/// instruction — so it's kept small, just enough to discourage spamming
+/// the callback path for no economic reason.
+pub const FLASH_MINT_FEE_BPS: u64 = 5;
+
+#[derive(Accounts)]
+pub struct FlashMintStableCoin<'info> {
+    #[account(seeds = [b"stable-pool", stable_pool.usp_mint.as_ref()], bump = stable_pool.bump_seed)]
+    pub stable_pool: Account<'info, StablePool>,
+    #[account(mut, address = stable_pool.usp_mint)]
+    pub usp_mint: Account<'info, Mint>,
+
+    /// Receives the minted USP and must hold at least `amount + fee` again
+    /// by the time `target_program`'s callback returns, so it can be
+    /// burned back down.
+    #[account(mut, constraint = destination.owner == borrower.key() @ ErrorCode::FlashMintDestinationOwnerMismatch)]
+    pub destination: Account<'info, TokenAccount>,
+    pub borrower: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+
+    /// CHECK: the program invoked with the minted funds; must leave
+    /// `amount + fee` in `destination` before this instruction returns, or
+    /// the whole transaction reverts.
+    pub target_program: AccountInfo<'info>,
+    // Remaining accounts are forwarded verbatim to `target_program`'s
+    // callback instruction.
+}
+
+/// Mints `amount` of USP into `destination`, invokes `target_program` with
+/// `callback_data` and the remaining accounts, then burns `amount` plus
+/// [`FLASH_MINT_FEE_BPS`] back out of `destination` — all within the same
+/// instruction, so USP's supply is unchanged net of the fee by the time it
+/// returns. Lets an arbitrageur close a peg deviation without bringing
+/// their own capital, the same capital-free role `flash_loan` plays for
+/// `borrow-lending`'s reserves, but funded by minting rather than an
+/// existing liquidity pool.
+pub fn handle(ctx: Context<FlashMintStableCoin>, amount: u64, callback_data: Vec<u8>) -> Result<()> {
+    let fee = amount.checked_mul(FLASH_MINT_FEE_BPS).and_then(|v| v.checked_div(10_000)).ok_or(ErrorCode::MathOverflow)?;
+    let amount_plus_fee = amount.checked_add(fee).ok_or(ErrorCode::MathOverflow)?;
+
+    let usp_mint_key = ctx.accounts.stable_pool.usp_mint;
+    let bump_seed = ctx.accounts.stable_pool.bump_seed;
+    let seeds: &[&[u8]] = &[b"stable-pool", usp_mint_key.as_ref(), &[bump_seed]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.usp_mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.stable_pool.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    let callback_accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|a| AccountMeta {
+            pubkey: a.key(),
+            is_signer: a.is_signer,
+            is_writable: a.is_writable,
+        })
+        .collect();
+    invoke(
+        &Instruction {
+            program_id: ctx.accounts.target_program.key(),
+            accounts: callback_accounts,
+            data: callback_data,
+        },
+        ctx.remaining_accounts,
+    )?;
+
+    ctx.accounts.destination.reload()?;
+    require!(ctx.accounts.destination.amount >= amount_plus_fee, ErrorCode::FlashMintNotRepaid);
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.usp_mint.to_account_info(),
+                from: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.borrower.to_account_info(),
+            },
+        ),
+        amount_plus_fee,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::models::{AccrualMode, ComponentConfig, ComponentStatus, DEFAULT_CONFIG_TIMELOCK_SLOTS, CURRENT_ACCOUNT_VERSION};
+
+#[derive(Accounts)]
+pub struct InitComponent<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = ComponentConfig::LEN,
+        seeds = [b"component", collateral_mint.key().as_ref(), usp_mint.key().as_ref()],
+        bump,
+    )]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub collateral_mint: Account<'info, Mint>,
+    pub usp_mint: Account<'info, Mint>,
+    /// Custody account `deposit_collateral`/`withdraw_collateral`/
+    /// `start_auction`/`take_auction` move the collateral mint through. Created
+    /// externally ahead of this call, same as `init_reserve`'s
+    /// `liquidity_supply`.
+    pub collateral_vault: Account<'info, TokenAccount>,
+    /// Price source for `collateral_mint`, read by `borrow_stable_coin`,
+    /// `withdraw_collateral` and `start_auction` (synth-850) via
+    /// `oracle::read_component_price`. A Pyth price account, unless
+    /// `blp_reserve` is set, in which case this is ignored in favor of
+    /// pricing off that `borrow-lending` reserve instead (synth-853).
+    ///
+    /// CHECK: parsed as a Pyth price feed or a `borrow-lending` `Reserve`
+    /// on every read via `oracle::read_component_price`, which rejects
+    /// anything that doesn't decode as the one `blp_reserve` selects.
+    pub oracle: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Registers a new collateral component: the CDP-core analog of
+/// `init_reserve`, one per `(collateral_mint, usp_mint)` pair. Risk
+/// parameters start at `ComponentConfig::default`'s conservative values,
+/// while `accrual_mode` is taken from the caller; `owner`
+/// (governance) retunes them afterwards, the same create-then-configure
+/// split `init_reserve`/reserve config updates use. `blp_reserve` opts
+/// `collateral_mint` into BLp pricing (synth-853) instead of the Pyth feed
+/// passed as `oracle`.
+pub fn handle(ctx: Context<InitComponent>, accrual_mode: AccrualMode, blp_reserve: Option<Pubkey>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let component = &mut ctx.accounts.component;
+    component.owner = ctx.accounts.owner.key();
+    component.collateral_mint = ctx.accounts.collateral_mint.key();
+    component.collateral_vault = ctx.accounts.collateral_vault.key();
+    component.usp_mint = ctx.accounts.usp_mint.key();
+    component.oracle = ctx.accounts.oracle.key();
+    component.blp_reserve = blp_reserve;
+    component.min_collateral_ratio_pct = 150;
+    component.liquidation_threshold_pct = 130;
+    component.close_factor_pct = 50;
+    component.interest_rate_bps = 0;
+    component.mint_allowance = 0;
+    component.total_debt = crate::math::Decimal::zero();
+    component.cumulative_borrow_rate = crate::math::Decimal::one();
+    component.last_update_slot = clock.slot;
+    component.last_update_timestamp = clock.unix_timestamp;
+    component.accrual_mode = accrual_mode;
+    component.bump_seed = ctx.bumps.component;
+    component.version = CURRENT_ACCOUNT_VERSION;
+    component.status = ComponentStatus::Active;
+    component.pending_min_collateral_ratio_pct = None;
+    component.pending_interest_rate_bps = None;
+    component.config_change_queued_at_slot = None;
+    component.config_timelock_slots = DEFAULT_CONFIG_TIMELOCK_SLOTS;
+    component.tvl_allowance_pct = None;
+    component.liquidation_penalty_bps = 0;
+    component.platform_fee_destination = None;
+    component.insurance_pool = None;
+    component.insurance_fee_split_bps = 0;
+    component.redemption_fee_bps = 50;
+    component.max_borrow_per_receipt = None;
+    component.borrow_cooldown_slots = 0;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::models::{StablePool, CURRENT_ACCOUNT_VERSION};
+
+#[derive(Accounts)]
+pub struct InitStablePool<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = StablePool::LEN,
+        seeds = [b"stable-pool", usp_mint.key().as_ref()],
+        bump,
+    )]
+    pub stable_pool: Account<'info, StablePool>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub usp_mint: Account<'info, Mint>,
+    /// Custody account `deposit_to_savings`/`withdraw_from_savings` move
+    /// USP through. Created externally ahead of this call, same as
+    /// `init_reserve`'s `liquidity_supply`.
+    pub savings_vault: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the savings module's singleton state for a given USP mint.
+/// `savings_rate_bps` starts at zero — governance turns the rate on (or
+/// retunes it) afterwards via `set_savings_rate`, rather than baking a
+/// value in at creation.
+pub fn handle(ctx: Context<InitStablePool>) -> Result<()> {
+    let pool = &mut ctx.accounts.stable_pool;
+    pool.owner = ctx.accounts.owner.key();
+    pool.usp_mint = ctx.accounts.usp_mint.key();
+    pool.savings_vault = ctx.accounts.savings_vault.key();
+    pool.savings_rate_bps = 0;
+    pool.cumulative_savings_index = crate::math::Decimal::one();
+    pool.total_principal_deposited = 0;
+    pool.last_update_slot = Clock::get()?.slot;
+    pool.bump_seed = ctx.bumps.stable_pool;
+    pool.version = CURRENT_ACCOUNT_VERSION;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{ComponentConfig, SurplusBuffer, CURRENT_ACCOUNT_VERSION};
+
+#[derive(Accounts)]
+pub struct InitSurplusBuffer<'info> {
+    #[account(has_one = owner)]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = SurplusBuffer::LEN,
+        seeds = [b"surplus-buffer", component.key().as_ref()],
+        bump,
+    )]
+    pub surplus_buffer: Account<'info, SurplusBuffer>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens the stability fee treasury for `component`. `auto_route_threshold`
+/// starts at zero (auto-routing disabled) — `owner` turns it on afterwards
+/// once there's somewhere for the surplus to go, the same create-then-
+/// configure split `init_component`/risk parameter updates use.
+pub fn handle(ctx: Context<InitSurplusBuffer>) -> Result<()> {
+    let buffer = &mut ctx.accounts.surplus_buffer;
+    buffer.component = ctx.accounts.component.key();
+    buffer.owner = ctx.accounts.owner.key();
+    buffer.usp_mint = ctx.accounts.component.usp_mint;
+    buffer.total_accumulated = crate::math::Decimal::zero();
+    buffer.auto_route_threshold = 0;
+    buffer.bump_seed = ctx.bumps.surplus_buffer;
+    buffer.version = CURRENT_ACCOUNT_VERSION;
+
+    Ok(())
+}
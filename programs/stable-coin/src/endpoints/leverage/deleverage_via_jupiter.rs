@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{ComponentConfig, Receipt, SurplusBuffer};
+use crate::oracle;
+
+use super::jupiter_adapter::{self, JUPITER_PROGRAM_ID};
+
+#[derive(Accounts)]
+pub struct DeleverageViaJupiter<'info> {
+    #[account(
+        mut,
+        seeds = [b"component", component.collateral_mint.as_ref(), component.usp_mint.as_ref()],
+        bump = component.bump_seed,
+    )]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component, has_one = borrower)]
+    pub receipt: Account<'info, Receipt>,
+    pub borrower: Signer<'info>,
+
+    #[account(mut, address = component.collateral_vault)]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    /// Borrower-controlled account the unwound collateral is transferred
+    /// into before being swapped; doubles as the Jupiter route's source.
+    #[account(mut)]
+    pub staged_collateral: Account<'info, TokenAccount>,
+
+    #[account(mut, address = component.usp_mint)]
+    pub usp_mint: Account<'info, Mint>,
+    /// Where the swapped-to USP lands before being burned to repay debt.
+    #[account(mut)]
+    pub repaid_usp: Account<'info, TokenAccount>,
+
+    /// CHECK: validated against `component.oracle`/`component.blp_reserve`
+    /// and parsed accordingly by `oracle::read_component_price`.
+    pub price_source: AccountInfo<'info>,
+
+    #[account(address = JUPITER_PROGRAM_ID)]
+    /// CHECK: only used as the CPI target; address-constrained above.
+    pub jupiter_program: UncheckedAccount<'info>,
+
+    /// Credited with this call's share of stability fee revenue (synth-856),
+    /// if `component` has one — omit for components that haven't
+    /// `init_surplus_buffer`'d yet.
+    #[account(mut)]
+    pub surplus_buffer: Option<Account<'info, SurplusBuffer>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Unwinds `withdraw_amount` of `receipt`'s collateral: moves it out of
+/// `collateral_vault`, swaps it through `jupiter_ix_data` into USP, and
+/// burns up to what's owed to pay down `receipt`'s debt (synth-857) — the
+/// reverse of `leverage_via_jupiter`. Any USP received beyond the
+/// outstanding balance is simply left in `repaid_usp`, the borrower's own
+/// account. Slippage is enforced against the component's own price source,
+/// pricing the USP leg at its pegged value of 1 UAC. Finishes with the
+/// same collateral-ratio check `withdraw_collateral` applies, in case
+/// unwinding less collateral than debt leaves the remainder unhealthy.
+pub fn handle(
+    ctx: Context<DeleverageViaJupiter>,
+    withdraw_amount: u64,
+    jupiter_ix_data: Vec<u8>,
+    max_slippage_bps: u16,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let component = &mut ctx.accounts.component;
+    component.check_not_paused()?;
+    let component_key = component.key();
+    let debt_before_accrual = component.total_debt;
+    component.accrue(&clock)?;
+    let interest_accrued = component.total_debt.try_sub(debt_before_accrual)?;
+    SurplusBuffer::credit_if_present(ctx.accounts.surplus_buffer.as_mut(), component_key, interest_accrued)?;
+
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.settle(component.cumulative_borrow_rate)?;
+    require!(receipt.collateral_amount >= withdraw_amount, ErrorCode::WithdrawTooLarge);
+    receipt.collateral_amount -= withdraw_amount;
+
+    let price = oracle::read_component_price(component, &ctx.accounts.price_source)?;
+    let value_in = Decimal::from(withdraw_amount).try_mul(price)?;
+    let min_usp_out = jupiter_adapter::oracle_min_out(value_in, Decimal::one(), max_slippage_bps)?;
+
+    let collateral_mint = component.collateral_mint;
+    let usp_mint = component.usp_mint;
+    let bump_seed = component.bump_seed;
+    let seeds: &[&[u8]] = &[b"component", collateral_mint.as_ref(), usp_mint.as_ref(), &[bump_seed]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.collateral_vault.to_account_info(),
+                to: ctx.accounts.staged_collateral.to_account_info(),
+                authority: component.to_account_info(),
+            },
+            &[seeds],
+        ),
+        withdraw_amount,
+    )?;
+
+    let received = jupiter_adapter::swap_via_jupiter(
+        &ctx.accounts.jupiter_program.to_account_info(),
+        ctx.remaining_accounts,
+        jupiter_ix_data,
+        &ctx.accounts.repaid_usp.to_account_info(),
+        min_usp_out,
+    )?;
+
+    let owed = receipt.borrowed_amount.try_floor_u64()?;
+    let repay_amount = received.min(owed);
+
+    if repay_amount > 0 {
+        receipt.borrowed_amount = receipt.borrowed_amount.try_sub(Decimal::from(repay_amount))?;
+        component.total_debt = component.total_debt.try_sub(Decimal::from(repay_amount))?;
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.usp_mint.to_account_info(),
+                    from: ctx.accounts.repaid_usp.to_account_info(),
+                    authority: ctx.accounts.borrower.to_account_info(),
+                },
+            ),
+            repay_amount,
+        )?;
+    }
+
+    if let Some(ratio_pct) = receipt.collateral_ratio_pct(price)? {
+        require!(
+            ratio_pct >= Decimal::from(component.min_collateral_ratio_pct as u64),
+            ErrorCode::CollateralRatioTooLow
+        );
+    }
+
+    Ok(())
+}
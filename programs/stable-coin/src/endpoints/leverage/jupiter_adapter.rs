@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+
+use crate::err::ErrorCode;
+
+/// Jupiter aggregator program id on mainnet-beta.
+pub const JUPITER_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+
+/// Reads an SPL Token account's `amount` field directly off its bytes
+/// (offset 64: 32-byte mint + 32-byte owner precede it), so a swap's
+/// actual output can be measured by balance diff without deserializing
+/// the whole account or fighting the borrow checker over a stale
+/// `Account<T>` snapshot taken before the CPI.
+fn token_account_balance(account: &AccountInfo) -> Result<u64> {
+    let data = account.try_borrow_data()?;
+    require!(data.len() >= 72, ErrorCode::MathOverflow);
+    Ok(u64::from_le_bytes(data[64..72].try_into().unwrap()))
+}
+
+/// CPIs into Jupiter with a caller-supplied route. A Jupiter route can hop
+/// through any number of pools with an instruction encoding that varies
+/// per-route, so the caller (which builds the route off-chain via Jupiter's
+/// quote/swap API) supplies the account list and instruction data
+/// verbatim; this just CPIs it and enforces the output floor by balance
+/// diff.
+pub fn swap_via_jupiter<'info>(
+    jupiter_program: &AccountInfo<'info>,
+    route_accounts: &[AccountInfo<'info>],
+    data: Vec<u8>,
+    destination: &AccountInfo<'info>,
+    min_amount_out: u64,
+) -> Result<u64> {
+    require_keys_eq!(*jupiter_program.key, JUPITER_PROGRAM_ID, ErrorCode::WrongAmmVenue);
+
+    let metas = route_accounts
+        .iter()
+        .map(|account| {
+            if account.is_writable {
+                AccountMeta::new(*account.key, account.is_signer)
+            } else {
+                AccountMeta::new_readonly(*account.key, account.is_signer)
+            }
+        })
+        .collect();
+
+    let before = token_account_balance(destination)?;
+
+    invoke(
+        &Instruction {
+            program_id: JUPITER_PROGRAM_ID,
+            accounts: metas,
+            data,
+        },
+        route_accounts,
+    )?;
+
+    let after = token_account_balance(destination)?;
+    let received = after.saturating_sub(before);
+    require!(received >= min_amount_out, ErrorCode::SlippageExceeded);
+
+    Ok(received)
+}
+
+/// The minimum acceptable output amount for a swap worth `value_in` (UAC)
+/// into an asset priced at `price_out` (UAC per unit), allowing at most
+/// `max_slippage_bps` of drift from the oracle-implied exchange rate.
+/// Used to bound leverage-loop swaps by the component's own price source
+/// instead of trusting whatever quote the caller's route was built from.
+pub fn oracle_min_out(
+    value_in: crate::math::Decimal,
+    price_out: crate::math::Decimal,
+    max_slippage_bps: u16,
+) -> Result<u64> {
+    require!(max_slippage_bps <= 10_000, ErrorCode::SlippageExceeded);
+
+    let expected_out = value_in.try_div(price_out)?;
+    let slippage_factor =
+        crate::math::Decimal::from_fraction((10_000 - max_slippage_bps) as u128, 10_000)?;
+    expected_out.try_mul(slippage_factor)?.try_floor_u64()
+}
@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{ComponentConfig, Receipt, SurplusBuffer};
+use crate::oracle;
+
+use super::jupiter_adapter::{self, JUPITER_PROGRAM_ID};
+
+#[derive(Accounts)]
+pub struct LeverageViaJupiter<'info> {
+    #[account(
+        mut,
+        seeds = [b"component", component.collateral_mint.as_ref(), component.usp_mint.as_ref()],
+        bump = component.bump_seed,
+    )]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component, has_one = borrower)]
+    pub receipt: Account<'info, Receipt>,
+    pub borrower: Signer<'info>,
+
+    #[account(mut, address = component.usp_mint)]
+    pub usp_mint: Account<'info, Mint>,
+    /// Borrower-controlled account the newly-minted USP is transferred
+    /// into before being swapped; doubles as the Jupiter route's source.
+    #[account(mut)]
+    pub borrowed_usp: Account<'info, TokenAccount>,
+
+    #[account(mut, address = component.collateral_vault)]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: validated against `component.oracle`/`component.blp_reserve`
+    /// and parsed accordingly by `oracle::read_component_price`.
+    pub price_source: AccountInfo<'info>,
+
+    #[account(address = JUPITER_PROGRAM_ID)]
+    /// CHECK: only used as the CPI target; address-constrained above.
+    pub jupiter_program: UncheckedAccount<'info>,
+
+    /// Credited with this call's share of stability fee revenue (synth-856),
+    /// if `component` has one — omit for components that haven't
+    /// `init_surplus_buffer`'d yet.
+    #[account(mut)]
+    pub surplus_buffer: Option<Account<'info, SurplusBuffer>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Loops `receipt`'s position: mints `borrow_amount` of USP straight onto
+/// the debt side, swaps it through `jupiter_ix_data` (a route built
+/// off-chain via Jupiter's quote/swap API) into `component.collateral_mint`,
+/// and deposits everything received back into `receipt` as collateral
+/// (synth-857). Slippage is enforced against the component's own price
+/// source rather than trusting the caller's quote, the same
+/// `oracle_min_out` bound `borrow-lending`'s leveraged positions use.
+/// Ends with the same collateral-ratio check `borrow_stable_coin` applies,
+/// so a loop that leaves the receipt unhealthy is rejected atomically.
+/// Also subject to the same `max_borrow_per_receipt`/`borrow_cooldown_slots`
+/// caps (synth-864) as `borrow_stable_coin`, since minting straight onto the
+/// debt side here is otherwise an unthrottled way around them.
+pub fn handle(
+    ctx: Context<LeverageViaJupiter>,
+    borrow_amount: u64,
+    jupiter_ix_data: Vec<u8>,
+    max_slippage_bps: u16,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let component = &mut ctx.accounts.component;
+    component.check_not_frozen()?;
+    let component_key = component.key();
+    let debt_before_accrual = component.total_debt;
+    component.accrue(&clock)?;
+    let interest_accrued = component.total_debt.try_sub(debt_before_accrual)?;
+    SurplusBuffer::credit_if_present(ctx.accounts.surplus_buffer.as_mut(), component_key, interest_accrued)?;
+
+    if component.borrow_cooldown_slots > 0 {
+        require!(
+            clock.slot.saturating_sub(ctx.accounts.receipt.last_borrow_slot) >= component.borrow_cooldown_slots,
+            ErrorCode::BorrowCooldownActive
+        );
+    }
+
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.settle(component.cumulative_borrow_rate)?;
+    receipt.borrowed_amount = receipt.borrowed_amount.try_add(Decimal::from(borrow_amount))?;
+    receipt.last_borrow_slot = clock.slot;
+    component.total_debt = component.total_debt.try_add(Decimal::from(borrow_amount))?;
+
+    require!(
+        component.total_debt <= Decimal::from(component.mint_allowance),
+        ErrorCode::MintAllowanceExceeded
+    );
+
+    if let Some(max_borrow_per_receipt) = component.max_borrow_per_receipt {
+        require!(
+            receipt.borrowed_amount <= Decimal::from(max_borrow_per_receipt),
+            ErrorCode::BorrowCapExceeded
+        );
+    }
+
+    let price = oracle::read_component_price(component, &ctx.accounts.price_source)?;
+    let min_collateral_out = jupiter_adapter::oracle_min_out(Decimal::from(borrow_amount), price, max_slippage_bps)?;
+
+    let collateral_mint = component.collateral_mint;
+    let usp_mint = component.usp_mint;
+    let bump_seed = component.bump_seed;
+    let seeds: &[&[u8]] = &[b"component", collateral_mint.as_ref(), usp_mint.as_ref(), &[bump_seed]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.usp_mint.to_account_info(),
+                to: ctx.accounts.borrowed_usp.to_account_info(),
+                authority: component.to_account_info(),
+            },
+            &[seeds],
+        ),
+        borrow_amount,
+    )?;
+
+    let received = jupiter_adapter::swap_via_jupiter(
+        &ctx.accounts.jupiter_program.to_account_info(),
+        ctx.remaining_accounts,
+        jupiter_ix_data,
+        &ctx.accounts.collateral_vault.to_account_info(),
+        min_collateral_out,
+    )?;
+
+    receipt.collateral_amount = receipt.collateral_amount.checked_add(received).ok_or(ErrorCode::MathOverflow)?;
+
+    let ratio_pct = receipt.collateral_ratio_pct(price)?.ok_or(ErrorCode::CollateralRatioTooLow)?;
+    require!(
+        ratio_pct >= Decimal::from(component.min_collateral_ratio_pct as u64),
+        ErrorCode::CollateralRatioTooLow
+    );
+
+    Ok(())
+}
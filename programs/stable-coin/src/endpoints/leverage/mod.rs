@@ -0,0 +1,11 @@
+//! Looping a receipt's position through a Jupiter swap route (synth-857):
+//! `leverage_via_jupiter` borrows USP and swaps it into more collateral,
+//! `deleverage_via_jupiter` does the reverse, unwinding collateral back
+//! into USP to repay debt. Mirrors the shape of `borrow-lending`'s
+//! `endpoints::leverage` module, minus the AMM-adapter trait — a CDP
+//! receipt only ever loops against its own single collateral mint, so
+//! there's no multi-venue surface to abstract over here.
+
+pub mod deleverage_via_jupiter;
+pub mod jupiter_adapter;
+pub mod leverage_via_jupiter;
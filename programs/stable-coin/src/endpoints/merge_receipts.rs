@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::{ComponentConfig, Receipt, SurplusBuffer};
+
+#[derive(Accounts)]
+pub struct MergeReceipts<'info> {
+    #[account(
+        mut,
+        seeds = [b"component", component.collateral_mint.as_ref(), component.usp_mint.as_ref()],
+        bump = component.bump_seed,
+    )]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component, has_one = borrower)]
+    pub receipt_into: Account<'info, Receipt>,
+
+    /// Closed once its balances are folded into `receipt_into`, refunding
+    /// its rent to `borrower`.
+    #[account(mut, has_one = component, has_one = borrower, close = borrower)]
+    pub receipt_from: Account<'info, Receipt>,
+
+    pub borrower: Signer<'info>,
+
+    /// Credited with this call's share of stability fee revenue (synth-856),
+    /// if `component` has one — omit for components that haven't
+    /// `init_surplus_buffer`'d yet.
+    #[account(mut)]
+    pub surplus_buffer: Option<Account<'info, SurplusBuffer>>,
+}
+
+/// Folds `receipt_from`'s collateral and debt into `receipt_into` and
+/// closes it, for a borrower who opened more than one receipt against the
+/// same component (synth-854). Both receipts are settled to the
+/// component's current `cumulative_borrow_rate` first so their
+/// `borrowed_amount`s are directly comparable before summing.
+pub fn handle(ctx: Context<MergeReceipts>) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.receipt_into.component,
+        ctx.accounts.receipt_from.component,
+        ErrorCode::ReceiptMismatch
+    );
+    require_keys_eq!(
+        ctx.accounts.receipt_into.borrower,
+        ctx.accounts.receipt_from.borrower,
+        ErrorCode::ReceiptMismatch
+    );
+
+    let clock = Clock::get()?;
+    let component = &mut ctx.accounts.component;
+    component.check_not_paused()?;
+    let component_key = component.key();
+    let debt_before_accrual = component.total_debt;
+    component.accrue(&clock)?;
+    let interest_accrued = component.total_debt.try_sub(debt_before_accrual)?;
+    SurplusBuffer::credit_if_present(ctx.accounts.surplus_buffer.as_mut(), component_key, interest_accrued)?;
+
+    let receipt_into = &mut ctx.accounts.receipt_into;
+    receipt_into.settle(component.cumulative_borrow_rate)?;
+
+    let receipt_from = &mut ctx.accounts.receipt_from;
+    receipt_from.settle(component.cumulative_borrow_rate)?;
+
+    receipt_into.collateral_amount = receipt_into
+        .collateral_amount
+        .checked_add(receipt_from.collateral_amount)
+        .ok_or(ErrorCode::MathOverflow)?;
+    receipt_into.borrowed_amount = receipt_into.borrowed_amount.try_add(receipt_from.borrowed_amount)?;
+
+    receipt_from.collateral_amount = 0;
+    receipt_from.borrowed_amount = crate::math::Decimal::zero();
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+//! One module per instruction. Each exposes an `Accounts` struct and a
+//! `handle` function; `lib.rs` wires them up under `#[program]`.
+
+pub mod accrue_component_interest;
+pub mod accrue_component_interest_to_surplus;
+pub mod borrow_stable_coin;
+pub mod close_auction;
+pub mod cover_bad_debt_from_insurance;
+pub mod deposit_collateral;
+pub mod deposit_to_savings;
+pub mod execute_component_config_update;
+pub mod flash_mint_stable_coin;
+pub mod init_component;
+pub mod init_stable_pool;
+pub mod init_surplus_buffer;
+pub mod leverage;
+pub mod merge_receipts;
+pub mod open_receipt;
+pub mod queue_component_config_update;
+pub mod recompute_allowance;
+pub mod redeem_after_shutdown;
+pub mod redeem_stable_coin;
+pub mod repay_stable_coin;
+pub mod route_surplus_to_savings;
+pub mod set_borrow_limits;
+pub mod set_component_status;
+pub mod set_liquidation_penalty_config;
+pub mod set_redemption_fee_bps;
+pub mod set_savings_rate;
+pub mod set_surplus_auto_route_threshold;
+pub mod set_tvl_allowance_pct;
+pub mod start_auction;
+pub mod sweep_surplus;
+pub mod take_auction;
+pub mod transfer_receipt;
+pub mod trigger_shutdown;
+pub mod withdraw_collateral;
+pub mod withdraw_from_savings;
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{ComponentConfig, Receipt, CURRENT_ACCOUNT_VERSION};
+
+#[derive(Accounts)]
+pub struct OpenReceipt<'info> {
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(init, payer = borrower, space = Receipt::LEN)]
+    pub receipt: Account<'info, Receipt>,
+    #[account(mut)]
+    pub borrower: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens an empty vault for `borrower` against `component`, a plain
+/// account (not a PDA — see `Receipt`'s doc comment) so a borrower can open
+/// as many of these against the same component as they want. Kept separate
+/// from `deposit_collateral` so a receipt exists ahead of its first
+/// deposit, the same `init_obligation`/`deposit_obligation_collateral`
+/// split `borrow-lending` uses.
+pub fn handle(ctx: Context<OpenReceipt>) -> Result<()> {
+    ctx.accounts.component.check_not_frozen()?;
+
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.component = ctx.accounts.component.key();
+    receipt.borrower = ctx.accounts.borrower.key();
+    receipt.collateral_amount = 0;
+    receipt.borrowed_amount = crate::math::Decimal::zero();
+    receipt.cumulative_borrow_rate = ctx.accounts.component.cumulative_borrow_rate;
+    receipt.version = CURRENT_ACCOUNT_VERSION;
+    receipt.last_borrow_slot = 0;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::models::ComponentConfig;
+
+#[derive(Accounts)]
+pub struct QueueComponentConfigUpdate<'info> {
+    #[account(mut, has_one = owner)]
+    pub component: Account<'info, ComponentConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Queues a change to `min_collateral_ratio_pct` and/or
+/// `interest_rate_bps`, to be applied no sooner than
+/// `component.config_timelock_slots` from now by
+/// `execute_component_config_update` (synth-859) — so a risk parameter
+/// change can't rug an open vault instantly the way writing the field
+/// directly would. Passing `None` for both clears whatever was previously
+/// queued, the same cancel-by-`None` idiom `announce_sunset` uses for
+/// `LendingMarket::sunset_at_slot`.
+pub fn handle(
+    ctx: Context<QueueComponentConfigUpdate>,
+    min_collateral_ratio_pct: Option<u16>,
+    interest_rate_bps: Option<u32>,
+) -> Result<()> {
+    let component = &mut ctx.accounts.component;
+
+    if min_collateral_ratio_pct.is_none() && interest_rate_bps.is_none() {
+        component.pending_min_collateral_ratio_pct = None;
+        component.pending_interest_rate_bps = None;
+        component.config_change_queued_at_slot = None;
+        return Ok(());
+    }
+
+    component.pending_min_collateral_ratio_pct = min_collateral_ratio_pct;
+    component.pending_interest_rate_bps = interest_rate_bps;
+    component.config_change_queued_at_slot = Some(Clock::get()?.slot);
+
+    Ok(())
+}
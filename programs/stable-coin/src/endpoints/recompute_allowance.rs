@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::ComponentConfig;
+use crate::oracle;
+
+#[derive(Accounts)]
+pub struct RecomputeAllowance<'info> {
+    #[account(
+        mut,
+        seeds = [b"component", component.collateral_mint.as_ref(), component.usp_mint.as_ref()],
+        bump = component.bump_seed,
+    )]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(address = component.collateral_vault)]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    /// CHECK: validated against `component.oracle`/`component.blp_reserve`
+    /// and parsed accordingly by `oracle::read_component_price`.
+    pub oracle: AccountInfo<'info>,
+}
+
+/// Permissionless crank: derives `mint_allowance` as `tvl_allowance_pct`
+/// percent of `collateral_vault`'s current value, priced the same way
+/// `borrow_stable_coin` prices collateral (synth-860), so the debt ceiling
+/// tracks actual deposits instead of a number `owner` has to keep retuning
+/// by hand. No-op target — errors instead — if `tvl_allowance_pct` isn't
+/// set, since then `mint_allowance` is meant to stay purely manual.
+pub fn handle(ctx: Context<RecomputeAllowance>) -> Result<()> {
+    let component = &mut ctx.accounts.component;
+    let tvl_allowance_pct = component.tvl_allowance_pct.ok_or(ErrorCode::TvlAllowanceNotEnabled)?;
+
+    let price = oracle::read_component_price(component, &ctx.accounts.oracle)?;
+    let tvl = Decimal::from(ctx.accounts.collateral_vault.amount).try_mul(price)?;
+    let allowance = tvl.try_mul(Decimal::from(tvl_allowance_pct as u64))?.try_div(Decimal::from(100u64))?;
+
+    component.mint_allowance = allowance.try_floor_u64()?;
+
+    Ok(())
+}
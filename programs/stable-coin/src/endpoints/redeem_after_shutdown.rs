@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::models::{SavingsDeposit, StablePool};
+
+#[derive(Accounts)]
+pub struct RedeemAfterShutdown<'info> {
+    #[account(mut, seeds = [b"stable-pool", stable_pool.usp_mint.as_ref()], bump = stable_pool.bump_seed)]
+    pub stable_pool: Account<'info, StablePool>,
+
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner,
+        has_one = stable_pool,
+        seeds = [b"savings-deposit", stable_pool.key().as_ref(), owner.key().as_ref()],
+        bump = savings_deposit.bump_seed,
+    )]
+    pub savings_deposit: Account<'info, SavingsDeposit>,
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(mut, address = stable_pool.savings_vault)]
+    pub savings_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_usp: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Closes out a savings position in one shot once the pool has shut down,
+/// paying out its full settled balance (principal plus everything accrued
+/// up to the shutdown's fixed index) and reclaiming the account's rent.
+/// Unlike `withdraw_from_savings`, which takes a caller-chosen amount and
+/// keeps accruing, this only works post-shutdown and always pays out the
+/// whole position — there's nothing left to keep accruing once the rate is
+/// frozen.
+pub fn handle(ctx: Context<RedeemAfterShutdown>) -> Result<()> {
+    require!(ctx.accounts.stable_pool.shutdown, ErrorCode::NotShutdown);
+
+    let index = ctx.accounts.stable_pool.cumulative_savings_index;
+    let deposit = &mut ctx.accounts.savings_deposit;
+    deposit.settle(index)?;
+    let payout = deposit.principal_amount;
+    deposit.principal_amount = 0;
+
+    let pool = &mut ctx.accounts.stable_pool;
+    pool.total_principal_deposited =
+        pool.total_principal_deposited.checked_sub(payout).ok_or(ErrorCode::MathOverflow)?;
+
+    let usp_mint = pool.usp_mint;
+    let bump_seed = pool.bump_seed;
+    let seeds: &[&[u8]] = &[b"stable-pool", usp_mint.as_ref(), &[bump_seed]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.savings_vault.to_account_info(),
+                to: ctx.accounts.destination_usp.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            &[seeds],
+        ),
+        payout,
+    )?;
+
+    Ok(())
+}
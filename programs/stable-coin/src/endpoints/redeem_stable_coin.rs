@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{ComponentConfig, Receipt, SurplusBuffer};
+use crate::oracle;
+
+/// Maximum number of receipts a single `redeem_stable_coin` call will walk,
+/// chosen to keep the instruction comfortably inside the compute budget —
+/// the same role `refresh_reserves::MAX_BATCH_SIZE` plays for its own
+/// remaining-accounts batch.
+pub const MAX_REDEMPTION_RECEIPTS: usize = 10;
+
+#[event]
+pub struct StableCoinRedeemed {
+    pub component: Pubkey,
+    pub redeemer: Pubkey,
+    pub usp_amount: u64,
+    pub collateral_amount: u64,
+    pub fee_collateral_amount: u64,
+    pub price: u128,
+}
+
+#[derive(Accounts)]
+pub struct RedeemStableCoin<'info> {
+    #[account(
+        mut,
+        seeds = [b"component", component.collateral_mint.as_ref(), component.usp_mint.as_ref()],
+        bump = component.bump_seed,
+    )]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, address = component.usp_mint)]
+    pub usp_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub source_usp: Account<'info, TokenAccount>,
+    pub redeemer: Signer<'info>,
+
+    #[account(mut, address = component.collateral_vault)]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_collateral: Account<'info, TokenAccount>,
+
+    /// CHECK: validated against `component.oracle`/`component.blp_reserve`
+    /// and parsed accordingly by `oracle::read_component_price`.
+    pub oracle: AccountInfo<'info>,
+
+    /// Credited with this call's share of stability fee revenue (synth-856),
+    /// if `component` has one — omit for components that haven't
+    /// `init_surplus_buffer`'d yet.
+    #[account(mut)]
+    pub surplus_buffer: Option<Account<'info, SurplusBuffer>>,
+
+    pub token_program: Program<'info, Token>,
+
+    // Remaining accounts are the component's own `Receipt`s, writable,
+    // ordered from riskiest (lowest collateral ratio) to safest — enforced
+    // on-chain below, since the actual sort has to happen off-chain without
+    // a registry of every receipt to walk.
+}
+
+/// Liquity-style redemption (synth-862): burns up to `usp_amount` of the
+/// caller's USP at face value and pays out an equivalent value of
+/// collateral — minus `component.redemption_fee_bps` — pulled from the
+/// riskiest receipts first. Gives USP a hard price floor: if it ever trades
+/// below 1 UAC, arbitrageurs can buy it cheap and redeem it here at par.
+/// Stops as soon as `usp_amount` is fully redeemed or the receipts run out;
+/// any receipt passed with no outstanding debt is skipped without breaking
+/// the ordering check.
+pub fn handle(ctx: Context<RedeemStableCoin>, usp_amount: u64) -> Result<()> {
+    require!(
+        ctx.remaining_accounts.len() <= MAX_REDEMPTION_RECEIPTS,
+        ErrorCode::TooManyReceiptsForRedemption
+    );
+
+    let clock = Clock::get()?;
+    let component = &mut ctx.accounts.component;
+    component.check_not_paused()?;
+    let component_key = component.key();
+    let debt_before_accrual = component.total_debt;
+    component.accrue(&clock)?;
+    let interest_accrued = component.total_debt.try_sub(debt_before_accrual)?;
+    SurplusBuffer::credit_if_present(ctx.accounts.surplus_buffer.as_mut(), component_key, interest_accrued)?;
+
+    let price = oracle::read_component_price(component, &ctx.accounts.oracle)?;
+
+    let mut usp_remaining = usp_amount;
+    let mut collateral_out: u64 = 0;
+    let mut worst_ratio_seen: Option<Decimal> = None;
+
+    for receipt_info in ctx.remaining_accounts {
+        if usp_remaining == 0 {
+            break;
+        }
+
+        let mut receipt = Account::<Receipt>::try_from(receipt_info)?;
+        require_keys_eq!(receipt.component, component.key(), ErrorCode::ReceiptMismatch);
+        receipt.settle(component.cumulative_borrow_rate)?;
+
+        if receipt.borrowed_amount.to_scaled_val() == 0 {
+            receipt.exit(&crate::ID)?;
+            continue;
+        }
+
+        let ratio = receipt.collateral_ratio_pct(price)?.expect("just checked borrowed_amount > 0");
+        if let Some(worst_ratio) = worst_ratio_seen {
+            require!(ratio >= worst_ratio, ErrorCode::ReceiptsNotSortedByRisk);
+        }
+        worst_ratio_seen = Some(ratio);
+
+        let owed = receipt.borrowed_amount.try_floor_u64()?;
+        let redeemed_usp = usp_remaining.min(owed);
+        let collateral_for_redemption =
+            Decimal::from(redeemed_usp).try_div(price)?.try_floor_u64()?.min(receipt.collateral_amount);
+
+        receipt.borrowed_amount = receipt.borrowed_amount.try_sub(Decimal::from(redeemed_usp))?;
+        receipt.collateral_amount -= collateral_for_redemption;
+        receipt.exit(&crate::ID)?;
+
+        usp_remaining -= redeemed_usp;
+        collateral_out = collateral_out.checked_add(collateral_for_redemption).ok_or(ErrorCode::MathOverflow)?;
+    }
+
+    let redeemed_usp_total = usp_amount - usp_remaining;
+    require!(redeemed_usp_total > 0, ErrorCode::NothingRedeemed);
+
+    component.total_debt = component.total_debt.try_sub(Decimal::from(redeemed_usp_total))?;
+
+    let fee_collateral = Decimal::from(collateral_out)
+        .try_mul(Decimal::from(component.redemption_fee_bps as u64))?
+        .try_div(Decimal::from(10_000u64))?
+        .try_floor_u64()?;
+    let collateral_to_redeemer = collateral_out - fee_collateral;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.usp_mint.to_account_info(),
+                from: ctx.accounts.source_usp.to_account_info(),
+                authority: ctx.accounts.redeemer.to_account_info(),
+            },
+        ),
+        redeemed_usp_total,
+    )?;
+
+    let collateral_mint = component.collateral_mint;
+    let usp_mint = component.usp_mint;
+    let bump_seed = component.bump_seed;
+    let seeds: &[&[u8]] = &[b"component", collateral_mint.as_ref(), usp_mint.as_ref(), &[bump_seed]];
+
+    if collateral_to_redeemer > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.collateral_vault.to_account_info(),
+                    to: ctx.accounts.destination_collateral.to_account_info(),
+                    authority: component.to_account_info(),
+                },
+                &[seeds],
+            ),
+            collateral_to_redeemer,
+        )?;
+    }
+
+    emit!(StableCoinRedeemed {
+        component: ctx.accounts.component.key(),
+        redeemer: ctx.accounts.redeemer.key(),
+        usp_amount: redeemed_usp_total,
+        collateral_amount: collateral_to_redeemer,
+        fee_collateral_amount: fee_collateral,
+        price: price.to_scaled_val(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{ComponentConfig, Receipt, SurplusBuffer};
+
+#[event]
+pub struct StableCoinRepaid {
+    pub component: Pubkey,
+    pub receipt: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct RepayStableCoin<'info> {
+    #[account(mut)]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component)]
+    pub receipt: Account<'info, Receipt>,
+
+    #[account(mut, address = component.usp_mint)]
+    pub usp_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub source_usp: Account<'info, TokenAccount>,
+    /// Repay-on-behalf, the same role `RepayObligationLiquidity`'s
+    /// `source_liquidity_authority` plays in `borrow-lending`: any signer
+    /// can burn their own USP to pay down anyone's receipt. This never
+    /// touches the receipt's collateral or ownership.
+    pub source_usp_authority: Signer<'info>,
+
+    /// Credited with this call's share of stability fee revenue (synth-856),
+    /// if `component` has one — omit for components that haven't
+    /// `init_surplus_buffer`'d yet.
+    #[account(mut)]
+    pub surplus_buffer: Option<Account<'info, SurplusBuffer>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Repays up to `repay_amount` of `receipt`'s outstanding debt, capped at
+/// what's actually owed. Excess supplied beyond the outstanding balance is
+/// simply not taken.
+pub fn handle(ctx: Context<RepayStableCoin>, repay_amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let component = &mut ctx.accounts.component;
+    component.check_not_paused()?;
+    let component_key = component.key();
+    let debt_before_accrual = component.total_debt;
+    component.accrue(&clock)?;
+    let interest_accrued = component.total_debt.try_sub(debt_before_accrual)?;
+    SurplusBuffer::credit_if_present(ctx.accounts.surplus_buffer.as_mut(), component_key, interest_accrued)?;
+
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.settle(component.cumulative_borrow_rate)?;
+
+    let owed = receipt.borrowed_amount.try_floor_u64()?;
+    let repay_amount = repay_amount.min(owed);
+    require!(repay_amount > 0, ErrorCode::RepayTooLarge);
+
+    receipt.borrowed_amount = receipt.borrowed_amount.try_sub(Decimal::from(repay_amount))?;
+    component.total_debt = component.total_debt.try_sub(Decimal::from(repay_amount))?;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.usp_mint.to_account_info(),
+                from: ctx.accounts.source_usp.to_account_info(),
+                authority: ctx.accounts.source_usp_authority.to_account_info(),
+            },
+        ),
+        repay_amount,
+    )?;
+
+    emit!(StableCoinRepaid {
+        component: ctx.accounts.component.key(),
+        receipt: ctx.accounts.receipt.key(),
+        amount: repay_amount,
+    });
+
+    Ok(())
+}
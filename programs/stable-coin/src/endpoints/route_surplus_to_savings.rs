@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::err::ErrorCode;
+use crate::models::{ComponentConfig, StablePool, SurplusBuffer};
+
+#[derive(Accounts)]
+pub struct RouteSurplusToSavings<'info> {
+    #[account(
+        seeds = [b"component", component.collateral_mint.as_ref(), component.usp_mint.as_ref()],
+        bump = component.bump_seed,
+    )]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component)]
+    pub surplus_buffer: Account<'info, SurplusBuffer>,
+
+    #[account(seeds = [b"stable-pool", surplus_buffer.usp_mint.as_ref()], bump = stable_pool.bump_seed)]
+    pub stable_pool: Account<'info, StablePool>,
+
+    #[account(mut, address = component.usp_mint)]
+    pub usp_mint: Account<'info, Mint>,
+    #[account(mut, address = stable_pool.savings_vault)]
+    pub savings_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Permissionless crank: once `surplus_buffer.total_accumulated` clears its
+/// `auto_route_threshold`, mints the whole balance as USP straight into
+/// `stable_pool.savings_vault`, funding the savings rate out of stability
+/// fee revenue the way `StablePool`'s own doc comment anticipates.
+pub fn handle(ctx: Context<RouteSurplusToSavings>) -> Result<()> {
+    let buffer = &mut ctx.accounts.surplus_buffer;
+    require!(buffer.auto_route_threshold > 0, ErrorCode::BelowAutoRouteThreshold);
+
+    let amount = buffer.total_accumulated.try_floor_u64()?;
+    require!(amount >= buffer.auto_route_threshold, ErrorCode::BelowAutoRouteThreshold);
+
+    buffer.total_accumulated = crate::math::Decimal::zero();
+
+    let collateral_mint = ctx.accounts.component.collateral_mint;
+    let usp_mint = ctx.accounts.component.usp_mint;
+    let bump_seed = ctx.accounts.component.bump_seed;
+    let seeds: &[&[u8]] = &[b"component", collateral_mint.as_ref(), usp_mint.as_ref(), &[bump_seed]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.usp_mint.to_account_info(),
+                to: ctx.accounts.savings_vault.to_account_info(),
+                authority: ctx.accounts.component.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
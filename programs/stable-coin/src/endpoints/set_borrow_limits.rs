@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::models::ComponentConfig;
+
+#[derive(Accounts)]
+pub struct SetBorrowLimits<'info> {
+    #[account(mut, has_one = owner)]
+    pub component: Account<'info, ComponentConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Sets `max_borrow_per_receipt`/`borrow_cooldown_slots` (synth-864),
+/// governance's knobs for throttling mint-and-dump attacks on a component
+/// shortly after it's listed. `max_borrow_per_receipt = None` and
+/// `borrow_cooldown_slots = 0` both disable their respective check.
+pub fn handle(
+    ctx: Context<SetBorrowLimits>,
+    max_borrow_per_receipt: Option<u64>,
+    borrow_cooldown_slots: u64,
+) -> Result<()> {
+    let component = &mut ctx.accounts.component;
+    component.max_borrow_per_receipt = max_borrow_per_receipt;
+    component.borrow_cooldown_slots = borrow_cooldown_slots;
+
+    Ok(())
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{ComponentConfig, ComponentStatus};
+
+#[derive(Accounts)]
+pub struct SetComponentStatus<'info> {
+    #[account(mut, has_one = owner)]
+    pub component: Account<'info, ComponentConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Immediately flips `component.status` (synth-859) — deliberately not
+/// timelocked, unlike `queue_component_config_update`, since an emergency
+/// freeze or pause is only useful if it takes effect right away. See
+/// `ComponentStatus` for exactly what each state blocks.
+pub fn handle(ctx: Context<SetComponentStatus>, status: ComponentStatus) -> Result<()> {
+    ctx.accounts.component.status = status;
+
+    Ok(())
+}
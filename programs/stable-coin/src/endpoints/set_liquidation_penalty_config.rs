@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::ComponentConfig;
+
+#[derive(Accounts)]
+pub struct SetLiquidationPenaltyConfig<'info> {
+    #[account(mut, has_one = owner)]
+    pub component: Account<'info, ComponentConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Configures the protocol's cut of a liquidation (synth-861): `start_auction`
+/// carves `liquidation_penalty_bps` of the liquidated collateral out of the
+/// receipt and splits it `insurance_fee_split_bps`/`10_000 -
+/// insurance_fee_split_bps` between `insurance_pool` and
+/// `platform_fee_destination`. Passing a nonzero `liquidation_penalty_bps`
+/// without both destinations set is rejected at `start_auction` time, not
+/// here, since `start_auction` is the only place that actually needs them.
+pub fn handle(
+    ctx: Context<SetLiquidationPenaltyConfig>,
+    liquidation_penalty_bps: u16,
+    platform_fee_destination: Option<Pubkey>,
+    insurance_pool: Option<Pubkey>,
+    insurance_fee_split_bps: u16,
+) -> Result<()> {
+    require!(insurance_fee_split_bps <= 10_000, ErrorCode::InvalidFeeSplit);
+
+    let component = &mut ctx.accounts.component;
+    component.liquidation_penalty_bps = liquidation_penalty_bps;
+    component.platform_fee_destination = platform_fee_destination;
+    component.insurance_pool = insurance_pool;
+    component.insurance_fee_split_bps = insurance_fee_split_bps;
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::ComponentConfig;
+
+#[derive(Accounts)]
+pub struct SetRedemptionFeeBps<'info> {
+    #[account(mut, has_one = owner)]
+    pub component: Account<'info, ComponentConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Sets the share of `redeem_stable_coin`'s payout retained as a fee
+/// (synth-862).
+pub fn handle(ctx: Context<SetRedemptionFeeBps>, redemption_fee_bps: u16) -> Result<()> {
+    require!(redemption_fee_bps <= 10_000, ErrorCode::InvalidFeeSplit);
+
+    ctx.accounts.component.redemption_fee_bps = redemption_fee_bps;
+
+    Ok(())
+}
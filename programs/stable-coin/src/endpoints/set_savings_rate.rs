@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::models::StablePool;
+
+#[derive(Accounts)]
+pub struct SetSavingsRate<'info> {
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [b"stable-pool", stable_pool.usp_mint.as_ref()],
+        bump = stable_pool.bump_seed,
+    )]
+    pub stable_pool: Account<'info, StablePool>,
+    pub owner: Signer<'info>,
+}
+
+/// Retunes the savings module's annualized rate. Accrues the pool to the
+/// current slot at the old rate first, so the change only affects interest
+/// earned from this point forward.
+pub fn handle(ctx: Context<SetSavingsRate>, savings_rate_bps: u32) -> Result<()> {
+    let pool = &mut ctx.accounts.stable_pool;
+    let slot = Clock::get()?.slot;
+    pool.accrue(slot)?;
+    pool.savings_rate_bps = savings_rate_bps;
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{ComponentConfig, SurplusBuffer};
+
+#[derive(Accounts)]
+pub struct SetSurplusAutoRouteThreshold<'info> {
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component, has_one = owner)]
+    pub surplus_buffer: Account<'info, SurplusBuffer>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Sets the `total_accumulated` balance above which
+/// `route_surplus_to_savings` will sweep into the savings pool. Zero (the
+/// default) disables auto-routing.
+pub fn handle(ctx: Context<SetSurplusAutoRouteThreshold>, threshold: u64) -> Result<()> {
+    ctx.accounts.surplus_buffer.auto_route_threshold = threshold;
+
+    Ok(())
+}
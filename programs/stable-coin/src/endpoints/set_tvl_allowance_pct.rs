@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::models::ComponentConfig;
+
+#[derive(Accounts)]
+pub struct SetTvlAllowancePct<'info> {
+    #[account(mut, has_one = owner)]
+    pub component: Account<'info, ComponentConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+/// Sets the percentage `recompute_allowance` derives `mint_allowance` from
+/// (synth-860). `None` reverts to a purely manual `mint_allowance`, left at
+/// whatever it was last set to.
+pub fn handle(ctx: Context<SetTvlAllowancePct>, tvl_allowance_pct: Option<u16>) -> Result<()> {
+    ctx.accounts.component.tvl_allowance_pct = tvl_allowance_pct;
+
+    Ok(())
+}
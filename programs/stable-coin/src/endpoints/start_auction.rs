@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{ComponentConfig, LiquidationAuction, Receipt, SurplusBuffer, CURRENT_ACCOUNT_VERSION};
+use crate::oracle;
+
+/// How far above the oracle price an auction's `starting_price` opens,
+/// giving the decay some room to fall before it reaches a price a keeper
+/// actually wants to buy at.
+pub const STARTING_PRICE_PREMIUM_PCT: u64 = 30;
+/// How far below the oracle price an auction's `floor_price` bottoms out
+/// at, the worst case a keeper pays if nobody takes the auction sooner.
+pub const FLOOR_PRICE_DISCOUNT_PCT: u64 = 10;
+/// How long, in slots, an auction takes to decay from `starting_price` to
+/// `floor_price`. Roughly an hour at Solana's ~400ms slot time.
+pub const AUCTION_DURATION_SLOTS: u64 = 9_000;
+
+#[event]
+pub struct LiquidationAuctionStarted {
+    pub component: Pubkey,
+    pub receipt: Pubkey,
+    pub auction: Pubkey,
+    pub collateral_amount: u64,
+    pub debt_amount: u128,
+    pub price: u128,
+}
+
+#[derive(Accounts)]
+pub struct StartAuction<'info> {
+    #[account(
+        mut,
+        seeds = [b"component", component.collateral_mint.as_ref(), component.usp_mint.as_ref()],
+        bump = component.bump_seed,
+    )]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component)]
+    pub receipt: Account<'info, Receipt>,
+
+    #[account(
+        init,
+        payer = keeper,
+        space = LiquidationAuction::LEN,
+        seeds = [b"auction", receipt.key().as_ref()],
+        bump,
+    )]
+    pub auction: Account<'info, LiquidationAuction>,
+    /// Whoever spots the unhealthy receipt and pays to create the auction
+    /// account; anyone can call this, the same way liquidation eligibility
+    /// itself doesn't gate on who's calling.
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// CHECK: validated against `component.oracle`/`component.blp_reserve`
+    /// and parsed accordingly by `oracle::read_component_price`.
+    pub oracle: AccountInfo<'info>,
+
+    #[account(mut, address = component.collateral_vault)]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    /// Required, and checked against `component.platform_fee_destination`,
+    /// only when `component.liquidation_penalty_bps` is nonzero (synth-861).
+    #[account(mut)]
+    pub platform_fee_destination: Option<Account<'info, TokenAccount>>,
+    /// Required, and checked against `component.insurance_pool`, only when
+    /// `component.liquidation_penalty_bps` is nonzero (synth-861).
+    #[account(mut)]
+    pub insurance_pool: Option<Account<'info, TokenAccount>>,
+
+    /// Credited with this call's share of stability fee revenue (synth-856),
+    /// if `component` has one — omit for components that haven't
+    /// `init_surplus_buffer`'d yet.
+    #[account(mut)]
+    pub surplus_buffer: Option<Account<'info, SurplusBuffer>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves `component.close_factor_pct` of an unhealthy receipt's collateral
+/// and debt into a [`LiquidationAuction`] (synth-855), leaving the rest on
+/// the receipt rather than auctioning the full position off at once —
+/// `take_auction` then sells that portion off in chunks instead of a
+/// single keeper taking it all at a fixed discount. A receipt still
+/// unhealthy after its close factor is liquidated and the auction closed
+/// can simply be put up again.
+pub fn handle(ctx: Context<StartAuction>) -> Result<()> {
+    let clock = Clock::get()?;
+    let component = &mut ctx.accounts.component;
+    component.check_not_paused()?;
+    let component_key = component.key();
+    let debt_before_accrual = component.total_debt;
+    component.accrue(&clock)?;
+    let interest_accrued = component.total_debt.try_sub(debt_before_accrual)?;
+    SurplusBuffer::credit_if_present(ctx.accounts.surplus_buffer.as_mut(), component_key, interest_accrued)?;
+
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.settle(component.cumulative_borrow_rate)?;
+
+    let price = oracle::read_component_price(component, &ctx.accounts.oracle)?;
+    let ratio_pct = receipt.collateral_ratio_pct(price)?.ok_or(ErrorCode::NotEligibleForLiquidation)?;
+    require!(
+        ratio_pct <= Decimal::from(component.liquidation_threshold_pct as u64),
+        ErrorCode::NotEligibleForLiquidation
+    );
+
+    let close_factor = Decimal::from(component.close_factor_pct as u64).try_div(Decimal::from(100u64))?;
+    let liquidated_collateral = Decimal::from(receipt.collateral_amount).try_mul(close_factor)?.try_floor_u64()?;
+    let liquidated_debt = receipt.borrowed_amount.try_mul(close_factor)?;
+
+    let auction = &mut ctx.accounts.auction;
+    auction.component = component.key();
+    auction.receipt = receipt.key();
+    auction.borrower = receipt.borrower;
+    auction.collateral_remaining = liquidated_collateral;
+    auction.debt_remaining = liquidated_debt;
+    auction.starting_price = price.try_mul(
+        Decimal::one().try_add(Decimal::from(STARTING_PRICE_PREMIUM_PCT).try_div(Decimal::from(100u64))?)?,
+    )?;
+    auction.floor_price = price.try_mul(
+        Decimal::one().try_sub(Decimal::from(FLOOR_PRICE_DISCOUNT_PCT).try_div(Decimal::from(100u64))?)?,
+    )?;
+    auction.start_slot = clock.slot;
+    auction.duration_slots = AUCTION_DURATION_SLOTS;
+    auction.bump_seed = ctx.bumps.auction;
+    auction.version = CURRENT_ACCOUNT_VERSION;
+
+    receipt.collateral_amount -= liquidated_collateral;
+    receipt.borrowed_amount = receipt.borrowed_amount.try_sub(liquidated_debt)?;
+
+    if component.liquidation_penalty_bps > 0 {
+        let platform_fee_destination = ctx
+            .accounts
+            .platform_fee_destination
+            .as_ref()
+            .ok_or(ErrorCode::LiquidationPenaltyMisconfigured)?;
+        let insurance_pool =
+            ctx.accounts.insurance_pool.as_ref().ok_or(ErrorCode::LiquidationPenaltyMisconfigured)?;
+        require_keys_eq!(
+            platform_fee_destination.key(),
+            component.platform_fee_destination.ok_or(ErrorCode::LiquidationPenaltyMisconfigured)?,
+            ErrorCode::LiquidationPenaltyMisconfigured
+        );
+        require_keys_eq!(
+            insurance_pool.key(),
+            component.insurance_pool.ok_or(ErrorCode::LiquidationPenaltyMisconfigured)?,
+            ErrorCode::LiquidationPenaltyMisconfigured
+        );
+
+        let penalty = Decimal::from(liquidated_collateral)
+            .try_mul(Decimal::from(component.liquidation_penalty_bps as u64))?
+            .try_div(Decimal::from(10_000u64))?
+            .try_floor_u64()?;
+        let penalty = penalty.min(receipt.collateral_amount);
+        receipt.collateral_amount -= penalty;
+
+        let insurance_share = Decimal::from(penalty)
+            .try_mul(Decimal::from(component.insurance_fee_split_bps as u64))?
+            .try_div(Decimal::from(10_000u64))?
+            .try_floor_u64()?;
+        let platform_share = penalty - insurance_share;
+
+        let collateral_mint = component.collateral_mint;
+        let usp_mint = component.usp_mint;
+        let bump_seed = component.bump_seed;
+        let seeds: &[&[u8]] = &[b"component", collateral_mint.as_ref(), usp_mint.as_ref(), &[bump_seed]];
+
+        if platform_share > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.collateral_vault.to_account_info(),
+                        to: platform_fee_destination.to_account_info(),
+                        authority: component.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                platform_share,
+            )?;
+        }
+        if insurance_share > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.collateral_vault.to_account_info(),
+                        to: insurance_pool.to_account_info(),
+                        authority: component.to_account_info(),
+                    },
+                    &[seeds],
+                ),
+                insurance_share,
+            )?;
+        }
+    }
+
+    emit!(LiquidationAuctionStarted {
+        component: ctx.accounts.component.key(),
+        receipt: ctx.accounts.receipt.key(),
+        auction: ctx.accounts.auction.key(),
+        collateral_amount: liquidated_collateral,
+        debt_amount: liquidated_debt.to_scaled_val(),
+        price: price.to_scaled_val(),
+    });
+
+    Ok(())
+}
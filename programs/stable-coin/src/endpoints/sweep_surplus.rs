@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{ComponentConfig, SurplusBuffer};
+
+#[derive(Accounts)]
+pub struct SweepSurplus<'info> {
+    #[account(
+        seeds = [b"component", component.collateral_mint.as_ref(), component.usp_mint.as_ref()],
+        bump = component.bump_seed,
+    )]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component, has_one = owner)]
+    pub surplus_buffer: Account<'info, SurplusBuffer>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, address = component.usp_mint)]
+    pub usp_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub destination_usp: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Mints up to `amount` of `surplus_buffer.total_accumulated` as USP to a
+/// destination the owner names — a protocol treasury, typically — and
+/// debits the buffer by the same amount.
+pub fn handle(ctx: Context<SweepSurplus>, amount: u64) -> Result<()> {
+    let buffer = &mut ctx.accounts.surplus_buffer;
+    let available = buffer.total_accumulated.try_floor_u64()?;
+    let amount = amount.min(available);
+    require!(amount > 0, ErrorCode::NothingToSweep);
+
+    buffer.total_accumulated = buffer.total_accumulated.try_sub(Decimal::from(amount))?;
+
+    let collateral_mint = ctx.accounts.component.collateral_mint;
+    let usp_mint = ctx.accounts.component.usp_mint;
+    let bump_seed = ctx.accounts.component.bump_seed;
+    let seeds: &[&[u8]] = &[b"component", collateral_mint.as_ref(), usp_mint.as_ref(), &[bump_seed]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.usp_mint.to_account_info(),
+                to: ctx.accounts.destination_usp.to_account_info(),
+                authority: ctx.accounts.component.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
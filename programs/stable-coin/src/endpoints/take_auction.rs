@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{ComponentConfig, LiquidationAuction};
+
+#[event]
+pub struct LiquidationAuctionTaken {
+    pub auction: Pubkey,
+    pub keeper: Pubkey,
+    pub collateral_amount: u64,
+    pub pay_amount: u64,
+    pub price: u128,
+}
+
+#[derive(Accounts)]
+pub struct TakeAuction<'info> {
+    #[account(
+        mut,
+        seeds = [b"component", component.collateral_mint.as_ref(), component.usp_mint.as_ref()],
+        bump = component.bump_seed,
+    )]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component)]
+    pub auction: Account<'info, LiquidationAuction>,
+
+    #[account(mut, address = component.usp_mint)]
+    pub usp_mint: Account<'info, Mint>,
+    #[account(mut)]
+    pub source_usp: Account<'info, TokenAccount>,
+    pub keeper: Signer<'info>,
+
+    #[account(mut, address = component.collateral_vault)]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_collateral: Account<'info, TokenAccount>,
+
+    /// Where any collateral left over once `auction.debt_remaining` hits
+    /// zero is returned. Unused, and left untouched, while debt remains.
+    /// Pinned to `auction.borrower` so a keeper can't redirect the
+    /// borrower's refund to an account of their own choosing.
+    #[account(mut, constraint = destination_borrower_collateral.owner == auction.borrower @ ErrorCode::AuctionRefundDestinationOwnerMismatch)]
+    pub destination_borrower_collateral: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Buys up to `collateral_amount` out of `auction` at its current decayed
+/// price, burning the USP it costs and reducing both
+/// `auction.collateral_remaining` and `auction.debt_remaining`. Once the
+/// debt is fully covered, any collateral still left over is returned to
+/// the borrower immediately — `close_auction` then reclaims the account's
+/// rent once both fields have reached zero.
+pub fn handle(ctx: Context<TakeAuction>, collateral_amount: u64) -> Result<()> {
+    ctx.accounts.component.check_not_paused()?;
+
+    let slot = Clock::get()?.slot;
+    let auction = &mut ctx.accounts.auction;
+    let price = auction.current_price(slot)?;
+
+    let collateral_amount = collateral_amount.min(auction.collateral_remaining);
+    require!(collateral_amount > 0, ErrorCode::RepayTooLarge);
+
+    let mut pay_amount = Decimal::from(collateral_amount).try_mul(price)?.try_floor_u64()?;
+    let debt_remaining_floor = auction.debt_remaining.try_floor_u64()?;
+    pay_amount = pay_amount.min(debt_remaining_floor);
+
+    auction.collateral_remaining -= collateral_amount;
+    auction.debt_remaining = auction.debt_remaining.try_sub(Decimal::from(pay_amount))?;
+
+    let component = &mut ctx.accounts.component;
+    component.total_debt = component.total_debt.try_sub(Decimal::from(pay_amount))?;
+
+    token::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.usp_mint.to_account_info(),
+                from: ctx.accounts.source_usp.to_account_info(),
+                authority: ctx.accounts.keeper.to_account_info(),
+            },
+        ),
+        pay_amount,
+    )?;
+
+    let collateral_mint = component.collateral_mint;
+    let usp_mint = component.usp_mint;
+    let bump_seed = component.bump_seed;
+    let seeds: &[&[u8]] = &[b"component", collateral_mint.as_ref(), usp_mint.as_ref(), &[bump_seed]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.collateral_vault.to_account_info(),
+                to: ctx.accounts.destination_collateral.to_account_info(),
+                authority: component.to_account_info(),
+            },
+            &[seeds],
+        ),
+        collateral_amount,
+    )?;
+
+    let auction = &mut ctx.accounts.auction;
+    if auction.debt_remaining.to_scaled_val() == 0 && auction.collateral_remaining > 0 {
+        let leftover = auction.collateral_remaining;
+        auction.collateral_remaining = 0;
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.collateral_vault.to_account_info(),
+                    to: ctx.accounts.destination_borrower_collateral.to_account_info(),
+                    authority: ctx.accounts.component.to_account_info(),
+                },
+                &[seeds],
+            ),
+            leftover,
+        )?;
+    }
+
+    emit!(LiquidationAuctionTaken {
+        auction: ctx.accounts.auction.key(),
+        keeper: ctx.accounts.keeper.key(),
+        collateral_amount,
+        pay_amount,
+        price: price.to_scaled_val(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::models::{ComponentConfig, Receipt};
+
+#[derive(Accounts)]
+pub struct TransferReceipt<'info> {
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component, has_one = borrower)]
+    pub receipt: Account<'info, Receipt>,
+
+    pub borrower: Signer<'info>,
+    /// Must co-sign so a receipt (and the debt it carries) can't be
+    /// transferred onto a wallet that never agreed to take it on.
+    pub new_borrower: Signer<'info>,
+}
+
+/// Reassigns `receipt` to `new_borrower` (synth-854). Collateral and debt
+/// move with it unchanged — this only changes who can
+/// `deposit_collateral`/`withdraw_collateral`/`borrow_stable_coin`/
+/// `repay_stable_coin` against it going forward.
+pub fn handle(ctx: Context<TransferReceipt>) -> Result<()> {
+    ctx.accounts.component.check_not_paused()?;
+
+    ctx.accounts.receipt.borrower = ctx.accounts.new_borrower.key();
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::models::StablePool;
+
+#[derive(Accounts)]
+pub struct TriggerShutdown<'info> {
+    #[account(mut, has_one = owner, seeds = [b"stable-pool", stable_pool.usp_mint.as_ref()], bump = stable_pool.bump_seed)]
+    pub stable_pool: Account<'info, StablePool>,
+    pub owner: Signer<'info>,
+}
+
+/// Emergency, one-way shutdown of the savings module: accrues one final
+/// time, fixing `cumulative_savings_index` as this module's settlement
+/// price, then blocks new `deposit_to_savings` calls. Existing depositors
+/// keep every bit of interest already earned; `redeem_after_shutdown` is
+/// how they pull it out. The CDP core's components and receipts are not
+/// touched: their collateral vaults are not settled by this.
+pub fn handle(ctx: Context<TriggerShutdown>) -> Result<()> {
+    let pool = &mut ctx.accounts.stable_pool;
+    require!(!pool.shutdown, ErrorCode::AlreadyShutdown);
+
+    let slot = Clock::get()?.slot;
+    pool.accrue(slot)?;
+    pool.shutdown = true;
+
+    Ok(())
+}
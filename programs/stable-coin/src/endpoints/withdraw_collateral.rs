@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::{ComponentConfig, Receipt, SurplusBuffer};
+use crate::oracle;
+
+#[event]
+pub struct CollateralWithdrawn {
+    pub component: Pubkey,
+    pub receipt: Pubkey,
+    pub amount: u64,
+    pub price: u128,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawCollateral<'info> {
+    #[account(
+        mut,
+        seeds = [b"component", component.collateral_mint.as_ref(), component.usp_mint.as_ref()],
+        bump = component.bump_seed,
+    )]
+    pub component: Account<'info, ComponentConfig>,
+
+    #[account(mut, has_one = component, has_one = borrower)]
+    pub receipt: Account<'info, Receipt>,
+    pub borrower: Signer<'info>,
+
+    #[account(mut, address = component.collateral_vault)]
+    pub collateral_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_collateral: Account<'info, TokenAccount>,
+
+    /// CHECK: validated against `component.oracle`/`component.blp_reserve`
+    /// and parsed accordingly by `oracle::read_component_price`.
+    pub oracle: AccountInfo<'info>,
+
+    /// Credited with this call's share of stability fee revenue (synth-856),
+    /// if `component` has one — omit for components that haven't
+    /// `init_surplus_buffer`'d yet.
+    #[account(mut)]
+    pub surplus_buffer: Option<Account<'info, SurplusBuffer>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraws `amount` of collateral, rejecting it if the receipt's
+/// resulting collateral ratio (priced against the component's own Pyth
+/// oracle — synth-850) would fall below `min_collateral_ratio_pct`.
+pub fn handle(ctx: Context<WithdrawCollateral>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+    let component = &mut ctx.accounts.component;
+    component.check_not_paused()?;
+    let component_key = component.key();
+    let debt_before_accrual = component.total_debt;
+    component.accrue(&clock)?;
+    let interest_accrued = component.total_debt.try_sub(debt_before_accrual)?;
+    SurplusBuffer::credit_if_present(ctx.accounts.surplus_buffer.as_mut(), component_key, interest_accrued)?;
+
+    let receipt = &mut ctx.accounts.receipt;
+    receipt.settle(component.cumulative_borrow_rate)?;
+    require!(receipt.collateral_amount >= amount, ErrorCode::WithdrawTooLarge);
+    receipt.collateral_amount -= amount;
+
+    let price = oracle::read_component_price(component, &ctx.accounts.oracle)?;
+    if let Some(ratio_pct) = receipt.collateral_ratio_pct(price)? {
+        require!(
+            ratio_pct >= Decimal::from(component.min_collateral_ratio_pct as u64),
+            ErrorCode::CollateralRatioTooLow
+        );
+    }
+
+    let collateral_mint = component.collateral_mint;
+    let usp_mint = component.usp_mint;
+    let bump_seed = component.bump_seed;
+    let seeds: &[&[u8]] = &[b"component", collateral_mint.as_ref(), usp_mint.as_ref(), &[bump_seed]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.collateral_vault.to_account_info(),
+                to: ctx.accounts.destination_collateral.to_account_info(),
+                authority: component.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    emit!(CollateralWithdrawn {
+        component: ctx.accounts.component.key(),
+        receipt: ctx.accounts.receipt.key(),
+        amount,
+        price: price.to_scaled_val(),
+    });
+
+    Ok(())
+}
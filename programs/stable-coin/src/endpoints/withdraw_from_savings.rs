@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::err::ErrorCode;
+use crate::models::{SavingsDeposit, StablePool};
+
+#[derive(Accounts)]
+pub struct WithdrawFromSavings<'info> {
+    #[account(mut, seeds = [b"stable-pool", stable_pool.usp_mint.as_ref()], bump = stable_pool.bump_seed)]
+    pub stable_pool: Account<'info, StablePool>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = stable_pool,
+        seeds = [b"savings-deposit", stable_pool.key().as_ref(), owner.key().as_ref()],
+        bump = savings_deposit.bump_seed,
+    )]
+    pub savings_deposit: Account<'info, SavingsDeposit>,
+    pub owner: Signer<'info>,
+
+    #[account(mut, address = stable_pool.savings_vault)]
+    pub savings_vault: Account<'info, TokenAccount>,
+    #[account(mut)]
+    pub destination_usp: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraws up to the deposit's current accrued balance, settling
+/// interest first so `amount` is checked against an up-to-date balance.
+pub fn handle(ctx: Context<WithdrawFromSavings>, amount: u64) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    let pool = &mut ctx.accounts.stable_pool;
+    pool.accrue(slot)?;
+
+    let deposit = &mut ctx.accounts.savings_deposit;
+    deposit.settle(pool.cumulative_savings_index)?;
+    require!(deposit.principal_amount >= amount, ErrorCode::SavingsWithdrawTooLarge);
+    deposit.principal_amount -= amount;
+    pool.total_principal_deposited =
+        pool.total_principal_deposited.checked_sub(amount).ok_or(ErrorCode::MathOverflow)?;
+
+    let usp_mint = pool.usp_mint;
+    let bump_seed = pool.bump_seed;
+    let seeds: &[&[u8]] = &[b"stable-pool", usp_mint.as_ref(), &[bump_seed]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.savings_vault.to_account_info(),
+                to: ctx.accounts.destination_usp.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            &[seeds],
+        ),
+        amount,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//! Program-wide error codes. Keep variants grouped roughly by the
+//! subsystem that raises them so `anchor build`'s generated IDL reads in a
+//! sensible order.
+
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Math operation overflowed or underflowed")]
+    MathOverflow,
+
+    #[msg("Savings pool must be accrued to the current slot before this operation")]
+    SavingsPoolStale,
+
+    #[msg("Withdrawal amount exceeds the deposit's current accrued balance")]
+    SavingsWithdrawTooLarge,
+
+    #[msg("destination's owner must match the signing borrower")]
+    FlashMintDestinationOwnerMismatch,
+
+    #[msg("target_program didn't leave amount + fee in destination before returning")]
+    FlashMintNotRepaid,
+
+    #[msg("Stable pool has already been shut down")]
+    AlreadyShutdown,
+
+    #[msg("Stable pool must be shut down first; use withdraw_from_savings until then")]
+    NotShutdown,
+
+    #[msg("Oracle account could not be parsed as a Pyth price feed")]
+    InvalidOracle,
+
+    #[msg("Oracle price is stale or negative")]
+    StalePrice,
+
+    #[msg("This borrow would push the receipt's collateral ratio below the component's min_collateral_ratio_pct")]
+    CollateralRatioTooLow,
+
+    #[msg("This borrow would push the component's total_debt past its mint_allowance")]
+    MintAllowanceExceeded,
+
+    #[msg("Receipt's collateral ratio is above the component's liquidation_threshold_pct")]
+    NotEligibleForLiquidation,
+
+    #[msg("Nothing to repay: the amount is zero or nothing is outstanding")]
+    RepayTooLarge,
+
+    #[msg("withdraw_amount exceeds the receipt's deposited collateral")]
+    WithdrawTooLarge,
+
+    #[msg("Auction still has collateral or debt remaining; take_auction first")]
+    AuctionNotFinished,
+
+    #[msg("Receipts being merged must belong to the same component and borrower")]
+    ReceiptMismatch,
+
+    #[msg("Surplus buffer has nothing to sweep")]
+    NothingToSweep,
+
+    #[msg("Surplus buffer balance is below its auto_route_threshold")]
+    BelowAutoRouteThreshold,
+
+    #[msg("Swap CPI target did not match the expected Jupiter program id")]
+    WrongAmmVenue,
+
+    #[msg("Swap returned less than min_amount_out allows for max_slippage_bps")]
+    SlippageExceeded,
+
+    #[msg("Component is frozen; only withdrawals, repays, transfers and liquidations are allowed")]
+    ComponentFrozen,
+
+    #[msg("Component is paused; no operations are allowed")]
+    ComponentPaused,
+
+    #[msg("No component config change is currently queued")]
+    NoConfigChangeQueued,
+
+    #[msg("Queued config change's timelock has not yet elapsed")]
+    ConfigChangeTimelocked,
+
+    #[msg("recompute_allowance called but component.tvl_allowance_pct is not set")]
+    TvlAllowanceNotEnabled,
+
+    #[msg("liquidation_penalty_bps is nonzero but platform_fee_destination/insurance_pool are not both set")]
+    LiquidationPenaltyMisconfigured,
+
+    #[msg("insurance_fee_split_bps must be between 0 and 10_000")]
+    InvalidFeeSplit,
+
+    #[msg("Receipt still has collateral; only fully-depleted receipts can be covered by the insurance pool")]
+    ReceiptNotDepleted,
+
+    #[msg("redeem_stable_coin called with more receipts than MAX_REDEMPTION_RECEIPTS")]
+    TooManyReceiptsForRedemption,
+
+    #[msg("Receipts passed to redeem_stable_coin must be sorted from riskiest (lowest collateral ratio) to safest")]
+    ReceiptsNotSortedByRisk,
+
+    #[msg("redeem_stable_coin redeemed nothing; every receipt passed had no outstanding debt")]
+    NothingRedeemed,
+
+    #[msg("This borrow would push the receipt's borrowed_amount above max_borrow_per_receipt")]
+    BorrowCapExceeded,
+
+    #[msg("Not enough slots have passed since this receipt's last borrow; borrow_cooldown_slots is still active")]
+    BorrowCooldownActive,
+
+    #[msg("destination_borrower_collateral is not owned by the auction's borrower")]
+    AuctionRefundDestinationOwnerMismatch,
+
+    #[msg("surplus_buffer does not belong to this component")]
+    SurplusBufferMismatch,
+}
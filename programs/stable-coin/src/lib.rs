@@ -0,0 +1,210 @@
+//! USP: an overcollateralized stable coin.
+//!
+//! Two subsystems live here: a minimal CDP core (`ComponentConfig` +
+//! `Receipt`) where borrowers lock up a single collateral mint to mint USP
+//! against an on-chain Pyth price, and a savings module (`StablePool` +
+//! `SavingsDeposit`) where USP holders lock deposits into a
+//! governance-set rate, funded by the CDP core's stability fees via
+//! `SurplusBuffer` and `route_surplus_to_savings`. See `models/` for account layouts and
+//! `endpoints/` for the instructions that drive them.
+
+use anchor_lang::prelude::*;
+
+pub mod blp;
+pub mod endpoints;
+pub mod err;
+pub mod math;
+pub mod models;
+pub mod oracle;
+
+use endpoints::*;
+use endpoints::leverage::deleverage_via_jupiter;
+use endpoints::leverage::deleverage_via_jupiter::DeleverageViaJupiter;
+use endpoints::leverage::leverage_via_jupiter;
+use endpoints::leverage::leverage_via_jupiter::LeverageViaJupiter;
+use models::{AccrualMode, ComponentStatus};
+
+declare_id!("StableCo1nUSPSav1ngs111111111111111111111111");
+
+#[program]
+pub mod stable_coin {
+    use super::*;
+
+    pub fn init_stable_pool(ctx: Context<InitStablePool>) -> Result<()> {
+        init_stable_pool::handle(ctx)
+    }
+
+    pub fn set_savings_rate(ctx: Context<SetSavingsRate>, savings_rate_bps: u32) -> Result<()> {
+        set_savings_rate::handle(ctx, savings_rate_bps)
+    }
+
+    pub fn deposit_to_savings(ctx: Context<DepositToSavings>, amount: u64) -> Result<()> {
+        deposit_to_savings::handle(ctx, amount)
+    }
+
+    pub fn withdraw_from_savings(ctx: Context<WithdrawFromSavings>, amount: u64) -> Result<()> {
+        withdraw_from_savings::handle(ctx, amount)
+    }
+
+    pub fn flash_mint_stable_coin(ctx: Context<FlashMintStableCoin>, amount: u64, callback_data: Vec<u8>) -> Result<()> {
+        flash_mint_stable_coin::handle(ctx, amount, callback_data)
+    }
+
+    pub fn trigger_shutdown(ctx: Context<TriggerShutdown>) -> Result<()> {
+        trigger_shutdown::handle(ctx)
+    }
+
+    pub fn redeem_after_shutdown(ctx: Context<RedeemAfterShutdown>) -> Result<()> {
+        redeem_after_shutdown::handle(ctx)
+    }
+
+    pub fn init_component(
+        ctx: Context<InitComponent>,
+        accrual_mode: AccrualMode,
+        blp_reserve: Option<Pubkey>,
+    ) -> Result<()> {
+        init_component::handle(ctx, accrual_mode, blp_reserve)
+    }
+
+    pub fn open_receipt(ctx: Context<OpenReceipt>) -> Result<()> {
+        open_receipt::handle(ctx)
+    }
+
+    pub fn deposit_collateral(ctx: Context<DepositCollateral>, amount: u64) -> Result<()> {
+        deposit_collateral::handle(ctx, amount)
+    }
+
+    pub fn withdraw_collateral(ctx: Context<WithdrawCollateral>, amount: u64) -> Result<()> {
+        withdraw_collateral::handle(ctx, amount)
+    }
+
+    pub fn borrow_stable_coin(ctx: Context<BorrowStableCoin>, amount: u64) -> Result<()> {
+        borrow_stable_coin::handle(ctx, amount)
+    }
+
+    pub fn repay_stable_coin(ctx: Context<RepayStableCoin>, repay_amount: u64) -> Result<()> {
+        repay_stable_coin::handle(ctx, repay_amount)
+    }
+
+    pub fn accrue_component_interest(ctx: Context<AccrueComponentInterest>) -> Result<()> {
+        accrue_component_interest::handle(ctx)
+    }
+
+    pub fn start_auction(ctx: Context<StartAuction>) -> Result<()> {
+        start_auction::handle(ctx)
+    }
+
+    pub fn take_auction(ctx: Context<TakeAuction>, collateral_amount: u64) -> Result<()> {
+        take_auction::handle(ctx, collateral_amount)
+    }
+
+    pub fn close_auction(ctx: Context<CloseAuction>) -> Result<()> {
+        close_auction::handle(ctx)
+    }
+
+    pub fn merge_receipts(ctx: Context<MergeReceipts>) -> Result<()> {
+        merge_receipts::handle(ctx)
+    }
+
+    pub fn transfer_receipt(ctx: Context<TransferReceipt>) -> Result<()> {
+        transfer_receipt::handle(ctx)
+    }
+
+    pub fn init_surplus_buffer(ctx: Context<InitSurplusBuffer>) -> Result<()> {
+        init_surplus_buffer::handle(ctx)
+    }
+
+    pub fn set_surplus_auto_route_threshold(ctx: Context<SetSurplusAutoRouteThreshold>, threshold: u64) -> Result<()> {
+        set_surplus_auto_route_threshold::handle(ctx, threshold)
+    }
+
+    pub fn accrue_component_interest_to_surplus(ctx: Context<AccrueComponentInterestToSurplus>) -> Result<()> {
+        accrue_component_interest_to_surplus::handle(ctx)
+    }
+
+    pub fn sweep_surplus(ctx: Context<SweepSurplus>, amount: u64) -> Result<()> {
+        sweep_surplus::handle(ctx, amount)
+    }
+
+    pub fn route_surplus_to_savings(ctx: Context<RouteSurplusToSavings>) -> Result<()> {
+        route_surplus_to_savings::handle(ctx)
+    }
+
+    pub fn leverage_via_jupiter(
+        ctx: Context<LeverageViaJupiter>,
+        borrow_amount: u64,
+        jupiter_ix_data: Vec<u8>,
+        max_slippage_bps: u16,
+    ) -> Result<()> {
+        leverage_via_jupiter::handle(ctx, borrow_amount, jupiter_ix_data, max_slippage_bps)
+    }
+
+    pub fn deleverage_via_jupiter(
+        ctx: Context<DeleverageViaJupiter>,
+        withdraw_amount: u64,
+        jupiter_ix_data: Vec<u8>,
+        max_slippage_bps: u16,
+    ) -> Result<()> {
+        deleverage_via_jupiter::handle(ctx, withdraw_amount, jupiter_ix_data, max_slippage_bps)
+    }
+
+    pub fn set_component_status(ctx: Context<SetComponentStatus>, status: ComponentStatus) -> Result<()> {
+        set_component_status::handle(ctx, status)
+    }
+
+    pub fn queue_component_config_update(
+        ctx: Context<QueueComponentConfigUpdate>,
+        min_collateral_ratio_pct: Option<u16>,
+        interest_rate_bps: Option<u32>,
+    ) -> Result<()> {
+        queue_component_config_update::handle(ctx, min_collateral_ratio_pct, interest_rate_bps)
+    }
+
+    pub fn execute_component_config_update(ctx: Context<ExecuteComponentConfigUpdate>) -> Result<()> {
+        execute_component_config_update::handle(ctx)
+    }
+
+    pub fn set_tvl_allowance_pct(ctx: Context<SetTvlAllowancePct>, tvl_allowance_pct: Option<u16>) -> Result<()> {
+        set_tvl_allowance_pct::handle(ctx, tvl_allowance_pct)
+    }
+
+    pub fn recompute_allowance(ctx: Context<RecomputeAllowance>) -> Result<()> {
+        recompute_allowance::handle(ctx)
+    }
+
+    pub fn set_liquidation_penalty_config(
+        ctx: Context<SetLiquidationPenaltyConfig>,
+        liquidation_penalty_bps: u16,
+        platform_fee_destination: Option<Pubkey>,
+        insurance_pool: Option<Pubkey>,
+        insurance_fee_split_bps: u16,
+    ) -> Result<()> {
+        set_liquidation_penalty_config::handle(
+            ctx,
+            liquidation_penalty_bps,
+            platform_fee_destination,
+            insurance_pool,
+            insurance_fee_split_bps,
+        )
+    }
+
+    pub fn cover_bad_debt_from_insurance(ctx: Context<CoverBadDebtFromInsurance>, amount: u64) -> Result<()> {
+        cover_bad_debt_from_insurance::handle(ctx, amount)
+    }
+
+    pub fn redeem_stable_coin(ctx: Context<RedeemStableCoin>, usp_amount: u64) -> Result<()> {
+        redeem_stable_coin::handle(ctx, usp_amount)
+    }
+
+    pub fn set_redemption_fee_bps(ctx: Context<SetRedemptionFeeBps>, redemption_fee_bps: u16) -> Result<()> {
+        set_redemption_fee_bps::handle(ctx, redemption_fee_bps)
+    }
+
+    pub fn set_borrow_limits(
+        ctx: Context<SetBorrowLimits>,
+        max_borrow_per_receipt: Option<u64>,
+        borrow_cooldown_slots: u64,
+    ) -> Result<()> {
+        set_borrow_limits::handle(ctx, max_borrow_per_receipt, borrow_cooldown_slots)
+    }
+}
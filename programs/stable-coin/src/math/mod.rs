@@ -0,0 +1,5 @@
+//! Fixed-point math shared by stable pool accounting.
+
+mod decimal;
+
+pub use decimal::{Decimal, SCALE};
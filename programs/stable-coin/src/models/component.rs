@@ -0,0 +1,324 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::SLOTS_PER_YEAR;
+
+/// Seconds per year, used by [`AccrualMode::CompoundingByTimestamp`] the
+/// same way [`SLOTS_PER_YEAR`] is used by the two slot-based modes.
+pub const SECONDS_PER_YEAR: i64 = 31_536_000;
+
+/// Default delay `queue_component_config_update` enforces before
+/// `execute_component_config_update` can apply a queued change — roughly a
+/// day at Solana's ~400ms slot time.
+pub const DEFAULT_CONFIG_TIMELOCK_SLOTS: u64 = 216_000;
+
+/// Operational state set by `set_component_status`, checked by every
+/// handler that moves collateral, debt, or liquidates a receipt — the same
+/// role `ReserveStatus` plays for `borrow-lending`'s reserves.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ComponentStatus {
+    #[default]
+    Active,
+    /// Blocks new exposure — `open_receipt`, `deposit_collateral`,
+    /// `borrow_stable_coin`, `leverage_via_jupiter`. Withdrawals, repays,
+    /// transfers and liquidations are unaffected, so existing borrowers
+    /// can still manage their positions during an incident.
+    Frozen,
+    /// Blocks everything, including liquidations — for incidents (e.g. a
+    /// compromised oracle) where even liquidating against the current
+    /// price would be unsafe.
+    Paused,
+}
+
+/// How a component compounds `interest_rate_bps` into
+/// `cumulative_borrow_rate`/`total_debt` on each `accrue` call.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AccrualMode {
+    /// `1 + rate * elapsed_slots / SLOTS_PER_YEAR` — a single linear step
+    /// per `accrue` call, same formula `Reserve::accrue_interest` uses.
+    /// Exact as long as `accrue` is called often relative to the rate;
+    /// long gaps between accruals undercharge slightly relative to true
+    /// compounding.
+    #[default]
+    SimpleInterestBySlot,
+    /// `(1 + rate / SLOTS_PER_YEAR) ^ elapsed_slots`, true compounding
+    /// applied in one shot via [`Decimal::try_pow`] regardless of how long
+    /// a component goes between accruals.
+    CompoundingBySlot,
+    /// Same compounding formula as `CompoundingBySlot`, but driven by
+    /// `Clock::unix_timestamp` and [`SECONDS_PER_YEAR`] instead of slots,
+    /// for components where charging by wall-clock time rather than slot
+    /// count (which drifts with cluster performance) matters more.
+    CompoundingByTimestamp,
+}
+
+/// Per-collateral risk configuration for USP's CDP core, one per
+/// `(collateral_mint, usp_mint)` pair — the same "one config governs every
+/// vault against this collateral" role `ReserveConfig` plays in
+/// `borrow-lending`, just sized for a single-collateral vault instead of a
+/// shared liquidity pool.
+#[account]
+pub struct ComponentConfig {
+    pub owner: Pubkey,
+    pub collateral_mint: Pubkey,
+    /// Custody token account `deposit_collateral`/`withdraw_collateral`/
+    /// `start_auction`/`take_auction` move the collateral mint through.
+    pub collateral_vault: Pubkey,
+    pub usp_mint: Pubkey,
+    /// Pyth price account for `collateral_mint`, denominated in USP's unit
+    /// of account. Read and validated on-chain by `borrow_stable_coin`,
+    /// `withdraw_collateral` and `start_auction` instead of trusting a
+    /// client-supplied price.
+    pub oracle: Pubkey,
+    /// When set, `collateral_mint` is a `borrow-lending` reserve's cToken
+    /// and this is that reserve's address: `oracle` is ignored and
+    /// collateral is priced instead via `blp::read_blp_collateral_price`
+    /// against the reserve's own oracle-priced `market_price` divided by its
+    /// `collateral_exchange_rate` (synth-853), so a receipt backed by BLp
+    /// collateral earns the underlying reserve's lending yield on top of
+    /// whatever USP it borrows against it.
+    pub blp_reserve: Option<Pubkey>,
+    /// Minimum collateral-to-debt ratio, as a percentage, a receipt must
+    /// stay above to borrow or withdraw collateral. 150 means 150%, i.e.
+    /// $1.50 of collateral per $1 of debt.
+    pub min_collateral_ratio_pct: u16,
+    /// Collateral-to-debt ratio, as a percentage, at or below which a
+    /// receipt becomes eligible for `start_auction`. Starts below
+    /// `min_collateral_ratio_pct` (130 vs 150), leaving a buffer between
+    /// "can't borrow more" and "can be liquidated"; a queued
+    /// `min_collateral_ratio_pct` is applied without being checked against
+    /// it.
+    pub liquidation_threshold_pct: u16,
+    /// Percentage of an eligible receipt's collateral and debt
+    /// `start_auction` moves into a single auction, the rest staying on the
+    /// receipt (synth-855). Bounds how much of a position a single health
+    /// breach puts up for sale at once — a borrower who dips just below
+    /// `liquidation_threshold_pct` only loses `close_factor_pct` of their
+    /// position instead of all of it, and can recover the remainder before
+    /// a second `start_auction` is needed.
+    pub close_factor_pct: u16,
+    /// Annualized stability fee charged on outstanding debt, in basis
+    /// points.
+    pub interest_rate_bps: u32,
+    /// Debt ceiling: `total_debt` may never exceed this by a new borrow.
+    pub mint_allowance: u64,
+    pub total_debt: Decimal,
+    /// Compounded forward by `accrue`, the same continuously-compounding-
+    /// by-slot idiom `Reserve::accrue_interest` uses for borrow interest.
+    pub cumulative_borrow_rate: Decimal,
+    pub last_update_slot: u64,
+    /// Only consulted when `accrual_mode == CompoundingByTimestamp`; left
+    /// at `0` otherwise.
+    pub last_update_timestamp: i64,
+    pub accrual_mode: AccrualMode,
+    pub bump_seed: u8,
+    pub version: u8,
+    /// Operational state (synth-859). Checked by every handler that opens
+    /// new exposure or moves money; see [`ComponentStatus`] for exactly
+    /// what each state blocks.
+    pub status: ComponentStatus,
+    /// Queued `min_collateral_ratio_pct`, set by
+    /// `queue_component_config_update` and applied by
+    /// `execute_component_config_update` once `config_change_queued_at_slot
+    /// + config_timelock_slots` has passed. `None` means nothing queued for
+    /// this field.
+    pub pending_min_collateral_ratio_pct: Option<u16>,
+    /// Queued `interest_rate_bps`, same semantics as
+    /// `pending_min_collateral_ratio_pct`.
+    pub pending_interest_rate_bps: Option<u32>,
+    /// Slot `queue_component_config_update` was last called at, or `None`
+    /// if nothing is currently queued — mirrors
+    /// `LendingMarket::sunset_announced_at_slot`'s
+    /// announce-now/apply-later idiom, just for risk parameters instead of
+    /// market sunsetting.
+    pub config_change_queued_at_slot: Option<u64>,
+    /// How many slots `execute_component_config_update` must wait after
+    /// `config_change_queued_at_slot` before applying the queued change.
+    pub config_timelock_slots: u64,
+    /// When set, `recompute_allowance` derives `mint_allowance` as this
+    /// percentage of `collateral_vault`'s current value (synth-860) instead
+    /// of leaving it at whatever `owner` last set manually. `None` (the
+    /// default) leaves `mint_allowance` purely manual.
+    pub tvl_allowance_pct: Option<u16>,
+    /// Extra collateral, as a percentage of the close factor's liquidated
+    /// slice, `start_auction` carves out of the receipt on top of what
+    /// moves into the auction and routes straight to
+    /// `platform_fee_destination`/`insurance_pool` (synth-861) — the
+    /// protocol's cut of a liquidation, the same role `liquidation_bonus`
+    /// plays for `borrow-lending`, except paid to the protocol instead of
+    /// the liquidator. Zero (the default) takes no penalty.
+    pub liquidation_penalty_bps: u16,
+    /// Collateral-mint token account `start_auction` routes its share of
+    /// the liquidation penalty into. Must be set if `liquidation_penalty_bps`
+    /// is nonzero.
+    pub platform_fee_destination: Option<Pubkey>,
+    /// Collateral-mint token account backstopping receipts left with debt
+    /// after their collateral has been fully liquidated
+    /// (`cover_bad_debt_from_insurance`), funded by its own share of the
+    /// liquidation penalty. Must be set if `liquidation_penalty_bps` is
+    /// nonzero.
+    pub insurance_pool: Option<Pubkey>,
+    /// Share of the liquidation penalty routed to `insurance_pool`, out of
+    /// 10_000; the remainder goes to `platform_fee_destination`.
+    pub insurance_fee_split_bps: u16,
+    /// Share, out of 10_000, of the collateral `redeem_stable_coin` would
+    /// otherwise pay out that's instead left behind in `collateral_vault`
+    /// (synth-862) — USP's hard price floor comes from redemption being
+    /// available at all, not from it being free, the same role Liquity's
+    /// redemption fee plays there.
+    pub redemption_fee_bps: u16,
+    /// Maximum `Receipt::borrowed_amount` a single receipt may reach via
+    /// `borrow_stable_coin`, set by `set_borrow_limits` (synth-864). `None`
+    /// leaves borrows bounded only by `mint_allowance` and the receipt's
+    /// own collateral ratio — meant for newly listed, thinly traded
+    /// collateral, where an attacker could otherwise mint a large amount of
+    /// USP against a single receipt and dump it before the oracle price or
+    /// governance can react.
+    pub max_borrow_per_receipt: Option<u64>,
+    /// Minimum slots `borrow_stable_coin` requires between two borrows
+    /// against the same receipt, tracked via `Receipt::last_borrow_slot`.
+    /// Zero (the default) disables the cooldown. Raises the cost of a
+    /// mint-and-dump attack by forcing it to spread across multiple slots
+    /// instead of a single transaction loop.
+    pub borrow_cooldown_slots: u64,
+}
+
+impl ComponentConfig {
+    // Field-by-field, matching declaration order above, so a future field
+    // addition is a one-line diff instead of a re-derivation from scratch
+    // (synth-850 shipped this 32 bytes short by miscounting the leading
+    // Pubkeys; recomputed here term-by-term to make the next diff obvious).
+    pub const LEN: usize = 8 // discriminator
+        + 32 // owner
+        + 32 // collateral_mint
+        + 32 // collateral_vault
+        + 32 // usp_mint
+        + 32 // oracle
+        + (1 + 32) // blp_reserve
+        + 2 // min_collateral_ratio_pct
+        + 2 // liquidation_threshold_pct
+        + 2 // close_factor_pct
+        + 4 // interest_rate_bps
+        + 8 // mint_allowance
+        + 16 // total_debt
+        + 16 // cumulative_borrow_rate
+        + 8 // last_update_slot
+        + 8 // last_update_timestamp
+        + 1 // accrual_mode
+        + 1 // bump_seed
+        + 1 // version
+        + 1 // status
+        + (1 + 2) // pending_min_collateral_ratio_pct
+        + (1 + 4) // pending_interest_rate_bps
+        + (1 + 8) // config_change_queued_at_slot
+        + 8 // config_timelock_slots
+        + (1 + 2) // tvl_allowance_pct
+        + 2 // liquidation_penalty_bps
+        + (1 + 32) // platform_fee_destination
+        + (1 + 32) // insurance_pool
+        + 2 // insurance_fee_split_bps
+        + 2 // redemption_fee_bps
+        + (1 + 8) // max_borrow_per_receipt
+        + 8; // borrow_cooldown_slots
+
+    /// Returns an error if `status` blocks opening new exposure —
+    /// `open_receipt`, `deposit_collateral`, `borrow_stable_coin` and
+    /// `leverage_via_jupiter` call this first.
+    pub fn check_not_frozen(&self) -> Result<()> {
+        require!(self.status == ComponentStatus::Active, ErrorCode::ComponentFrozen);
+        Ok(())
+    }
+
+    /// Returns an error if `status` is `Paused` — everything else
+    /// (withdrawals, repays, transfers, liquidations) calls this first,
+    /// since `check_not_frozen` would also block actions a frozen
+    /// component is meant to still allow.
+    pub fn check_not_paused(&self) -> Result<()> {
+        require!(self.status != ComponentStatus::Paused, ErrorCode::ComponentPaused);
+        Ok(())
+    }
+
+    /// Compounds `cumulative_borrow_rate` and `total_debt` forward to the
+    /// current slot/timestamp at `interest_rate_bps`, using whichever
+    /// formula `accrual_mode` selects.
+    pub fn accrue(&mut self, clock: &Clock) -> Result<()> {
+        let annual_rate = Decimal::from(self.interest_rate_bps as u64).try_div(Decimal::from(10_000u64))?;
+        let rate_per_period = match self.accrual_mode {
+            AccrualMode::SimpleInterestBySlot | AccrualMode::CompoundingBySlot => {
+                annual_rate.try_div(Decimal::from(SLOTS_PER_YEAR))?
+            }
+            AccrualMode::CompoundingByTimestamp => annual_rate.try_div(Decimal::from(SECONDS_PER_YEAR as u64))?,
+        };
+
+        let compounded_rate = match self.accrual_mode {
+            AccrualMode::SimpleInterestBySlot => {
+                let elapsed_slots = clock.slot.saturating_sub(self.last_update_slot);
+                if elapsed_slots == 0 {
+                    return Ok(());
+                }
+                self.last_update_slot = clock.slot;
+                Decimal::one().try_add(rate_per_period.try_mul(Decimal::from(elapsed_slots))?)?
+            }
+            AccrualMode::CompoundingBySlot => {
+                let elapsed_slots = clock.slot.saturating_sub(self.last_update_slot);
+                if elapsed_slots == 0 {
+                    return Ok(());
+                }
+                self.last_update_slot = clock.slot;
+                Decimal::one().try_add(rate_per_period)?.try_pow(elapsed_slots)?
+            }
+            AccrualMode::CompoundingByTimestamp => {
+                let elapsed_seconds = clock.unix_timestamp.saturating_sub(self.last_update_timestamp).max(0) as u64;
+                if elapsed_seconds == 0 {
+                    return Ok(());
+                }
+                self.last_update_timestamp = clock.unix_timestamp;
+                Decimal::one().try_add(rate_per_period)?.try_pow(elapsed_seconds)?
+            }
+        };
+
+        self.cumulative_borrow_rate = self.cumulative_borrow_rate.try_mul(compounded_rate)?;
+        self.total_debt = self.total_debt.try_mul(compounded_rate)?;
+
+        Ok(())
+    }
+}
+
+impl Default for ComponentConfig {
+    fn default() -> Self {
+        Self {
+            owner: Pubkey::default(),
+            collateral_mint: Pubkey::default(),
+            collateral_vault: Pubkey::default(),
+            usp_mint: Pubkey::default(),
+            oracle: Pubkey::default(),
+            blp_reserve: None,
+            min_collateral_ratio_pct: 150,
+            liquidation_threshold_pct: 130,
+            close_factor_pct: 50,
+            interest_rate_bps: 0,
+            mint_allowance: 0,
+            total_debt: Decimal::zero(),
+            cumulative_borrow_rate: Decimal::one(),
+            last_update_slot: 0,
+            last_update_timestamp: 0,
+            accrual_mode: AccrualMode::default(),
+            bump_seed: 0,
+            version: 0,
+            status: ComponentStatus::default(),
+            pending_min_collateral_ratio_pct: None,
+            pending_interest_rate_bps: None,
+            config_change_queued_at_slot: None,
+            config_timelock_slots: DEFAULT_CONFIG_TIMELOCK_SLOTS,
+            tvl_allowance_pct: None,
+            liquidation_penalty_bps: 0,
+            platform_fee_destination: None,
+            insurance_pool: None,
+            insurance_fee_split_bps: 0,
+            redemption_fee_bps: 50,
+            max_borrow_per_receipt: None,
+            borrow_cooldown_slots: 0,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::math::Decimal;
+
+/// An unhealthy receipt mid-liquidation, sold off in chunks at a price that
+/// decays linearly from `starting_price` to `floor_price` over
+/// `duration_slots` — replaces the old fixed-discount
+/// `liquidate_position` sale (synth-851) with a Dutch auction, so large
+/// positions aren't dumped on the first keeper to show up at a single
+/// hard-coded bonus.
+#[account]
+pub struct LiquidationAuction {
+    pub component: Pubkey,
+    pub receipt: Pubkey,
+    pub borrower: Pubkey,
+    pub collateral_remaining: u64,
+    pub debt_remaining: Decimal,
+    /// USP per unit of collateral at `start_slot`.
+    pub starting_price: Decimal,
+    /// USP per unit of collateral once `duration_slots` have elapsed; the
+    /// price never decays past this floor.
+    pub floor_price: Decimal,
+    pub start_slot: u64,
+    pub duration_slots: u64,
+    pub bump_seed: u8,
+    pub version: u8,
+}
+
+impl LiquidationAuction {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 8 + 16 + 16 + 16 + 8 + 8 + 1 + 1;
+
+    /// Current clearing price: `starting_price` decayed linearly towards
+    /// `floor_price` as `slot` advances past `start_slot`, clamped to
+    /// `floor_price` once `duration_slots` have fully elapsed.
+    pub fn current_price(&self, slot: u64) -> Result<Decimal> {
+        let elapsed = slot.saturating_sub(self.start_slot);
+        if elapsed >= self.duration_slots {
+            return Ok(self.floor_price);
+        }
+
+        let decayed = self
+            .starting_price
+            .try_sub(self.floor_price)?
+            .try_mul(Decimal::from(elapsed))?
+            .try_div(Decimal::from(self.duration_slots))?;
+
+        self.starting_price.try_sub(decayed)
+    }
+}
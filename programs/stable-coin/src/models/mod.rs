@@ -0,0 +1,21 @@
+//! On-chain account layouts and the business logic that operates purely on
+//! their fields (no CPI, no `Context`). Endpoints in `endpoints/` stay thin
+//! wrappers around these methods so the math has exactly one home.
+
+/// Current on-chain layout version stamped onto newly-`init`ed accounts via
+/// their `version` field.
+pub const CURRENT_ACCOUNT_VERSION: u8 = 1;
+
+mod component;
+mod liquidation_auction;
+mod receipt;
+mod savings_deposit;
+mod stable_pool;
+mod surplus_buffer;
+
+pub use component::{AccrualMode, ComponentConfig, ComponentStatus, DEFAULT_CONFIG_TIMELOCK_SLOTS};
+pub use liquidation_auction::LiquidationAuction;
+pub use receipt::Receipt;
+pub use savings_deposit::SavingsDeposit;
+pub use stable_pool::{StablePool, SLOTS_PER_YEAR};
+pub use surplus_buffer::SurplusBuffer;
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::math::Decimal;
+
+/// One vault against a single `ComponentConfig`. Tracks collateral and
+/// debt, plus the component's `cumulative_borrow_rate` as of the last time
+/// this receipt's debt was settled — the same snapshot-and-diff idiom
+/// `ObligationLiquidity::cumulative_borrow_rate` uses in `borrow-lending`,
+/// so accrual doesn't need to iterate every receipt.
+///
+/// A plain account rather than a PDA, the same way `Obligation` is: a
+/// borrower can `open_receipt` as many of these against one component as
+/// they like (merging duplicates later with `merge_receipts`), and
+/// `transfer_receipt` can hand one to a different `borrower` outright,
+/// neither of which a borrower-derived PDA address would allow.
+#[account]
+#[derive(Default)]
+pub struct Receipt {
+    pub component: Pubkey,
+    pub borrower: Pubkey,
+    pub collateral_amount: u64,
+    pub borrowed_amount: Decimal,
+    pub cumulative_borrow_rate: Decimal,
+    pub version: u8,
+    /// Slot of this receipt's last successful `borrow_stable_coin` call, or
+    /// `0` if it has never borrowed. Compared against
+    /// `ComponentConfig::borrow_cooldown_slots` (synth-864) to throttle
+    /// repeated borrows against the same receipt.
+    pub last_borrow_slot: u64,
+}
+
+impl Receipt {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 16 + 16 + 1 + 8;
+
+    /// Rolls any stability fee accrued since `cumulative_borrow_rate` into
+    /// `borrowed_amount` and re-snapshots it to the component's current
+    /// index. Must be called before changing `borrowed_amount` or
+    /// `collateral_amount`, and before any collateral-ratio check, so
+    /// neither operates on a stale debt figure.
+    pub fn settle(&mut self, component_cumulative_borrow_rate: Decimal) -> Result<()> {
+        if self.cumulative_borrow_rate.to_scaled_val() == 0 {
+            self.cumulative_borrow_rate = component_cumulative_borrow_rate;
+            return Ok(());
+        }
+
+        self.borrowed_amount = self
+            .borrowed_amount
+            .try_mul(component_cumulative_borrow_rate)?
+            .try_div(self.cumulative_borrow_rate)?;
+        self.cumulative_borrow_rate = component_cumulative_borrow_rate;
+
+        Ok(())
+    }
+
+    /// Collateral value divided by debt value, as a percentage. `None` when
+    /// there's no outstanding debt — the ratio is undefined (conventionally
+    /// "infinitely healthy") rather than a divide-by-zero.
+    pub fn collateral_ratio_pct(&self, collateral_price: Decimal) -> Result<Option<Decimal>> {
+        if self.borrowed_amount.to_scaled_val() == 0 {
+            return Ok(None);
+        }
+
+        let collateral_value = Decimal::from(self.collateral_amount).try_mul(collateral_price)?;
+        let ratio = collateral_value.try_div(self.borrowed_amount)?.try_mul(Decimal::from(100u64))?;
+
+        Ok(Some(ratio))
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::math::Decimal;
+
+/// One user's stake in `StablePool`'s savings module. Tracks principal and
+/// the pool's `cumulative_savings_index` as of the last time this deposit
+/// was settled — the same snapshot-and-diff idiom
+/// `ObligationLiquidity::cumulative_borrow_rate` uses for per-borrow
+/// interest in `borrow-lending`, so accrual doesn't need to iterate every
+/// depositor.
+#[account]
+#[derive(Default)]
+pub struct SavingsDeposit {
+    pub stable_pool: Pubkey,
+    pub owner: Pubkey,
+    pub principal_amount: u64,
+    pub deposit_index: Decimal,
+    pub bump_seed: u8,
+    pub version: u8,
+}
+
+impl SavingsDeposit {
+    pub const LEN: usize = 8 + 32 + 32 + 8 + 16 + 1 + 1;
+
+    /// Rolls any interest earned since `deposit_index` into
+    /// `principal_amount` and re-snapshots `deposit_index` to
+    /// `cumulative_savings_index`. Must be called before changing
+    /// `principal_amount` so a deposit or withdrawal never clobbers
+    /// interest that accrued earlier at a now-stale index.
+    pub fn settle(&mut self, cumulative_savings_index: Decimal) -> Result<()> {
+        if self.deposit_index.to_scaled_val() == 0 {
+            self.deposit_index = cumulative_savings_index;
+            return Ok(());
+        }
+
+        self.principal_amount = Decimal::from(self.principal_amount)
+            .try_mul(cumulative_savings_index)?
+            .try_div(self.deposit_index)?
+            .try_floor_u64()?;
+        self.deposit_index = cumulative_savings_index;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::math::Decimal;
+
+/// Slots per year, Solana's ~400ms slot time assumption — same figure
+/// `borrow-lending`'s `models::SLOTS_PER_YEAR` uses.
+pub const SLOTS_PER_YEAR: u64 = 63_072_000;
+
+/// Global, one-per-mint state for USP's native savings module: a
+/// governance-set rate giving USP holders a demand sink distinct from just
+/// holding the idle token, funded out of the CDP core's stability fees via
+/// `SurplusBuffer` and `route_surplus_to_savings`.
+#[account]
+pub struct StablePool {
+    pub owner: Pubkey,
+    pub usp_mint: Pubkey,
+    /// Token account `deposit_to_savings`/`withdraw_from_savings` move USP
+    /// through — the pool's own custody, not a per-user account.
+    pub savings_vault: Pubkey,
+    /// Annualized savings rate, in basis points, set by `owner`
+    /// (governance) via `set_savings_rate`. Independent of whatever
+    /// stability fee rate borrowers are actually paying — funding this
+    /// rate out of fee revenue is a governance/treasury concern, not
+    /// something this account enforces.
+    pub savings_rate_bps: u32,
+    /// Cumulative accrual index, compounded forward by `accrue` the same
+    /// way `Reserve::cumulative_borrow_rate` compounds borrow interest: a
+    /// deposit's current balance is its settled `principal_amount` scaled
+    /// by how much this index has grown since its own snapshot.
+    pub cumulative_savings_index: Decimal,
+    pub total_principal_deposited: u64,
+    pub last_update_slot: u64,
+    /// Set once, permanently, by `trigger_shutdown`. Freezes
+    /// `cumulative_savings_index` as this module's settlement price and
+    /// blocks new deposits; existing depositors redeem via
+    /// `redeem_after_shutdown` instead of `withdraw_from_savings`.
+    pub shutdown: bool,
+    pub bump_seed: u8,
+    pub version: u8,
+}
+
+impl StablePool {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 4 + 16 + 8 + 8 + 1 + 1 + 1;
+
+    /// Compounds `cumulative_savings_index` forward to `slot` at
+    /// `savings_rate_bps`, the same continuously-compounding-by-slot idiom
+    /// `Reserve::accrue_interest` uses for borrow interest.
+    pub fn accrue(&mut self, slot: u64) -> Result<()> {
+        let elapsed_slots = slot.saturating_sub(self.last_update_slot);
+        if elapsed_slots == 0 {
+            return Ok(());
+        }
+
+        let rate = Decimal::from(self.savings_rate_bps as u64).try_div(Decimal::from(10_000u64))?;
+        let compounded_rate = Decimal::one()
+            .try_add(rate.try_mul(Decimal::from(elapsed_slots))?.try_div(Decimal::from(SLOTS_PER_YEAR))?)?;
+
+        self.cumulative_savings_index = self.cumulative_savings_index.try_mul(compounded_rate)?;
+        self.last_update_slot = slot;
+
+        Ok(())
+    }
+}
+
+impl Default for StablePool {
+    fn default() -> Self {
+        Self {
+            owner: Pubkey::default(),
+            usp_mint: Pubkey::default(),
+            savings_vault: Pubkey::default(),
+            savings_rate_bps: 0,
+            cumulative_savings_index: Decimal::one(),
+            total_principal_deposited: 0,
+            last_update_slot: 0,
+            shutdown: false,
+            bump_seed: 0,
+            version: 0,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+
+/// Treasury ledger for one component's stability fee revenue (synth-856):
+/// `accrue_component_interest_to_surplus` and [`SurplusBuffer::credit_if_present`]
+/// credit `total_accumulated` with the debt growth from compounding,
+/// `sweep_surplus` realizes it by minting that much USP to a destination
+/// the owner names, and `route_surplus_to_savings` does the same but
+/// straight into `StablePool::savings_vault` once the balance clears
+/// `auto_route_threshold` — the funding path `StablePool`'s own doc comment
+/// names.
+#[account]
+pub struct SurplusBuffer {
+    pub component: Pubkey,
+    pub owner: Pubkey,
+    pub usp_mint: Pubkey,
+    /// Stability fee revenue accrued but not yet minted out to a
+    /// destination. Denominated the same as `ComponentConfig::total_debt`.
+    pub total_accumulated: Decimal,
+    /// Minimum `total_accumulated` before `route_surplus_to_savings` will
+    /// move anything; zero disables auto-routing entirely.
+    pub auto_route_threshold: u64,
+    pub bump_seed: u8,
+    pub version: u8,
+}
+
+impl SurplusBuffer {
+    pub const LEN: usize = 8 + 32 + 32 + 32 + 16 + 8 + 1 + 1;
+
+    /// Credits `interest_accrued` onto `buffer`, if one was passed, after
+    /// checking it actually belongs to `component` (synth-856). Every
+    /// handler that calls `ComponentConfig::accrue` directly — not just
+    /// the dedicated `accrue_component_interest_to_surplus` crank — takes
+    /// an optional `surplus_buffer` account and routes its debt growth
+    /// through here, so `total_accumulated` can't silently fall behind
+    /// `total_debt` just because a borrower happened to trigger the
+    /// accrual instead of a keeper.
+    pub fn credit_if_present(
+        buffer: Option<&mut Account<SurplusBuffer>>,
+        component: Pubkey,
+        interest_accrued: Decimal,
+    ) -> Result<()> {
+        let Some(buffer) = buffer else {
+            return Ok(());
+        };
+        require_keys_eq!(buffer.component, component, ErrorCode::SurplusBufferMismatch);
+        buffer.total_accumulated = buffer.total_accumulated.try_add(interest_accrued)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+//! Thin wrapper around the Pyth price feed account format, mirroring
+//! `borrow-lending`'s `oracle::read_market_price` so both programs convert
+//! Pyth's price/exponent pair to a UAC [`Decimal`] the exact same way.
+
+use anchor_lang::prelude::*;
+use pyth_sdk_solana::load_price_feed_from_account_info;
+
+use crate::blp;
+use crate::err::ErrorCode;
+use crate::math::Decimal;
+use crate::models::ComponentConfig;
+
+/// Reads the current price out of a Pyth price account and converts it to
+/// a non-negative [`Decimal`]. Pyth exponents are negative for fractional
+/// prices, e.g. `price = 5123, expo = -2` means `$51.23`.
+pub fn read_market_price(price_account: &AccountInfo) -> Result<Decimal> {
+    let feed = load_price_feed_from_account_info(price_account)
+        .map_err(|_| error!(ErrorCode::InvalidOracle))?;
+    let price = feed.get_price_unchecked();
+
+    require!(price.price >= 0, ErrorCode::StalePrice);
+
+    let magnitude = price.price as u128;
+    let decimal = if price.expo >= 0 {
+        Decimal::from(magnitude).try_mul(Decimal::from(10u64.pow(price.expo as u32)))?
+    } else {
+        Decimal::from(magnitude).try_div(Decimal::from(10u64.pow((-price.expo) as u32)))?
+    };
+
+    Ok(decimal)
+}
+
+/// Prices `component`'s collateral off whichever source it's configured
+/// for: `component.blp_reserve`'s exchange rate (synth-853) if set,
+/// otherwise `price_account` read as a Pyth feed. Handlers pass the same
+/// account slot either way — what it's expected to be just depends on
+/// `component`'s own configuration.
+pub fn read_component_price(component: &ComponentConfig, price_account: &AccountInfo) -> Result<Decimal> {
+    match component.blp_reserve {
+        Some(reserve) => {
+            require_keys_eq!(price_account.key(), reserve, ErrorCode::InvalidOracle);
+            blp::read_blp_collateral_price(price_account)
+        }
+        None => {
+            require_keys_eq!(price_account.key(), component.oracle, ErrorCode::InvalidOracle);
+            read_market_price(price_account)
+        }
+    }
+}